@@ -0,0 +1,65 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+//! Heap allocations per frame for `emit_ansi` versus `write_ansi`.
+//!
+//! Kept apart from the timing benches because it replaces the global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tui_banner::emit::{emit_ansi, write_ansi};
+use tui_banner::grid::Grid;
+use tui_banner::{ColorMode, Gradient, Palette, Preset};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn main() {
+    let mut frame = Grid::from_char_rows(vec![vec!['█'; 240]; 12]);
+    Gradient::diagonal(Palette::preset(Preset::AuroraFlux)).apply(&mut frame);
+
+    let mut out = io::BufWriter::new(io::sink());
+    let buffered = allocations(|| {
+        let frame = emit_ansi(&frame, ColorMode::TrueColor);
+        out.write_all(frame.as_bytes()).unwrap();
+    });
+    let streamed = allocations(|| write_ansi(&frame, ColorMode::TrueColor, &mut out).unwrap());
+    println!("allocations per frame: emit_then_write {buffered}, write_ansi {streamed}");
+}
@@ -0,0 +1,67 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use std::io::{self, Write};
+
+use tui_banner::emit::{emit_ansi, write_ansi};
+use tui_banner::grid::Grid;
+use tui_banner::{ColorMode, Gradient, Palette, Preset};
+
+/// A 12 x 240 block colored with a diagonal gradient, like one frame of a wide banner.
+fn wide_frame() -> Grid {
+    let mut grid = Grid::from_char_rows(vec![vec!['█'; 240]; 12]);
+    Gradient::diagonal(Palette::preset(Preset::AuroraFlux)).apply(&mut grid);
+    grid
+}
+
+fn per_frame(c: &mut Criterion) {
+    let frame = wide_frame();
+    for mode in [ColorMode::Ansi256, ColorMode::Ansi16] {
+        let mut group = c.benchmark_group(format!("{mode:?}"));
+        group.bench_function("emit_rgb", |b| {
+            b.iter(|| emit_ansi(black_box(&frame), mode))
+        });
+        group.bench_function("quantize_then_emit", |b| {
+            b.iter(|| {
+                let mut grid = black_box(&frame).clone();
+                grid.quantize_colors(mode);
+                emit_ansi(&grid, mode)
+            })
+        });
+        let mut quantized = frame.clone();
+        quantized.quantize_colors(mode);
+        group.bench_function("emit_prequantized", |b| {
+            b.iter(|| emit_ansi(black_box(&quantized), mode))
+        });
+        group.finish();
+    }
+
+    // Streaming into a buffered writer skips the per-frame `String` for the whole banner
+    // (see the `allocations` bench for the counts).
+    let mut group = c.benchmark_group("TrueColor");
+    group.bench_function("emit_then_write", |b| {
+        let mut out = io::BufWriter::new(io::sink());
+        b.iter(|| {
+            let frame = emit_ansi(black_box(&frame), ColorMode::TrueColor);
+            out.write_all(frame.as_bytes()).unwrap();
+        })
+    });
+    group.bench_function("write_ansi", |b| {
+        let mut out = io::BufWriter::new(io::sink());
+        b.iter(|| write_ansi(black_box(&frame), ColorMode::TrueColor, &mut out).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, per_frame);
+criterion_main!(benches);
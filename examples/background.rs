@@ -0,0 +1,41 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use tui_banner::{Align, Banner, Color, ColorMode, Fill, Gradient, Palette};
+
+fn main() -> Result<(), tui_banner::BannerError> {
+    println!();
+    let banner = Banner::new("RUST CLI")? // text
+        .color_mode(ColorMode::TrueColor) // true color
+        .gradient(Gradient::vertical(Palette::from_hex(&[
+            "#FFFFFF", // white
+            "#D8E6FF", // pale blue
+        ]))) // light glyphs
+        .background(Gradient::diagonal(Palette::from_hex(&[
+            "#1B1F5C", // indigo
+            "#0B6E8A", // teal
+        ]))) // colored panel
+        .fill(Fill::Keep) // keep glyphs
+        .align(Align::Center) // center align
+        .padding(1) // uniform padding
+        .render();
+    println!("{banner}");
+
+    let badge = Banner::new("OK")? // text
+        .color_mode(ColorMode::TrueColor) // true color
+        .gradient(Gradient::vertical(Palette::from_hex(&["#FFFFFF"]))) // white glyphs
+        .background_color(Color::Rgb(24, 120, 60)) // solid panel
+        .padding(1) // uniform padding
+        .render();
+    println!("{badge}");
+    Ok(())
+}
@@ -0,0 +1,36 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use tui_banner::{Align, Banner, CanvasFill, Color, ColorMode, Frame, FrameStyle, Style};
+
+fn main() -> Result<(), tui_banner::BannerError> {
+    println!();
+    let banner = Banner::new("RUST CLI")? // text
+        .style(Style::CrtAmber) // amber phosphor
+        .color_mode(ColorMode::TrueColor) // true color
+        .canvas_color(Color::Rgb(26, 14, 0)) // faint glow behind the glyphs
+        .align(Align::Center) // center align
+        .padding(1) // uniform padding
+        .frame(Frame::new(FrameStyle::Rounded).color(Color::Rgb(122, 62, 0))) // bezel
+        .render();
+    println!("{banner}");
+
+    let scanlines = Banner::new("READY")? // text
+        .style(Style::CrtAmber) // amber phosphor
+        .color_mode(ColorMode::TrueColor) // true color
+        .canvas_color(Color::Rgb(60, 30, 0)) // dim phosphor dots
+        .canvas_fill(CanvasFill::Char('░')) // textured canvas
+        .padding(1) // uniform padding
+        .render();
+    println!("{scanlines}");
+    Ok(())
+}
@@ -0,0 +1,45 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use ratatui::crossterm::event::{self, Event};
+use ratatui::layout::{Constraint, Layout};
+use tui_banner::{Align, Banner, ColorMode, Style};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let widget = Banner::new("RATATUI")? // text
+        .style(Style::NeonCyber) // preset style
+        .color_mode(ColorMode::TrueColor) // true color
+        .align(Align::Center) // centered in the area
+        .padding(1) // uniform padding
+        .widget(); // render once
+
+    let (_, height) = widget.size();
+    let mut terminal = ratatui::init();
+    let result = loop {
+        if let Err(err) = terminal.draw(|frame| {
+            // Vertically center; the widget clips itself if the terminal is too small.
+            let [area] = Layout::vertical([Constraint::Length(height as u16)])
+                .flex(ratatui::layout::Flex::Center)
+                .areas(frame.area());
+            frame.render_widget(&widget, area);
+        }) {
+            break Err(err);
+        }
+        match event::read() {
+            Ok(Event::Key(_)) => break Ok(()),
+            Ok(_) => {}
+            Err(err) => break Err(err),
+        }
+    };
+    ratatui::restore();
+    Ok(result?)
+}
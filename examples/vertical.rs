@@ -0,0 +1,26 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use tui_banner::{Align, Banner, Orientation, Style};
+
+fn main() -> Result<(), tui_banner::BannerError> {
+    let banner = Banner::new("RUST")?
+        .style(Style::NeonCyber)
+        .orientation(Orientation::Vertical)
+        .align(Align::Center)
+        .trim_vertical(true)
+        .padding(1)
+        .render();
+
+    println!("{banner}");
+    Ok(())
+}
@@ -0,0 +1,419 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use crate::color::{Color, ColorMode};
+use crate::emit::emit_ansi;
+use crate::grid::{Grid, Quantizer};
+
+/// Built-in banner animations.
+#[derive(Clone, Copy, Debug)]
+pub enum Animation {
+    /// Light sweep across the banner.
+    Sweep {
+        /// Sweep color (`None` for white).
+        highlight: Option<Color>,
+    },
+    /// Wave-like breathing that keeps glyphs in place.
+    Wave {
+        /// Low-brightness strength (`None` for the default).
+        dim: Option<f32>,
+        /// High-brightness strength (`None` for the default).
+        bright: Option<f32>,
+    },
+    /// Rolling wave with a heavy crest.
+    Roll,
+    /// Cycle every foreground color once around the hue wheel.
+    Hue,
+    /// Alternate between the full render and a dimmed one.
+    ///
+    /// One pass is a single on/off cycle; set [`AnimationOptions::frames`] to `on + off`
+    /// so each frame maps to one step.
+    Blink {
+        /// Frames shown at full brightness.
+        on: usize,
+        /// Frames shown dimmed.
+        off: usize,
+        /// Brightness multiplier for the dimmed frames (`0.0` blanks the glyphs).
+        brightness: f32,
+    },
+    /// Flow the gradient colors along its axis.
+    Gradient {
+        /// Full trips through the gradient per pass.
+        cycles: f32,
+    },
+    /// Reveal the banner column by column.
+    Typewriter {
+        /// Where the reveal starts.
+        reveal: Reveal,
+    },
+    /// Marquee a fixed-width window across the banner, wrapping around.
+    Scroll {
+        /// Window width in cells.
+        width: usize,
+        /// Direction the text travels.
+        direction: ScrollDirection,
+        /// Blank columns between the tail and the head when wrapping.
+        gap: usize,
+    },
+    /// Bursts of glitching between clean frames, with a new seed every frame.
+    Glitch {
+        /// Glitch strength during bursts (0.0..1.0).
+        intensity: f32,
+    },
+    /// Green glyph rain that locks cell by cell into the banner, ending on the static
+    /// render for the last fifth of the pass.
+    MatrixReveal {
+        /// Seed for the column delays and rain glyphs.
+        seed: u32,
+        /// Glyphs the rain is drawn from (empty falls back to [`MATRIX_CHARSET`]).
+        charset: &'static str,
+    },
+    /// Fade the colors in from black, out to black, or both, keeping glyphs in place.
+    Fade {
+        /// Which way the colors travel.
+        direction: FadeDirection,
+    },
+}
+
+impl Animation {
+    /// Whether a pass ends on its final look (`t = 1.0`) rather than cycling back to the start.
+    pub(crate) fn settles(self) -> bool {
+        matches!(
+            self,
+            Animation::Fade { .. } | Animation::MatrixReveal { .. }
+        )
+    }
+}
+
+/// Default rain glyphs for [`Animation::MatrixReveal`].
+pub const MATRIX_CHARSET: &str = "ｱｲｳｴｵｶｷｸｹｺｻｼｽｾｿﾀﾁﾂﾃﾄﾅﾆﾇﾈﾉ0123456789:=*+-<>";
+
+/// Direction for the fade animation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FadeDirection {
+    /// From black to the final colors, holding them for the last fifth.
+    In,
+    /// From the final colors to black, holding black for the last fifth.
+    Out,
+    /// In over the first 40%, hold, then out over the last 40%.
+    InOut,
+}
+
+impl FadeDirection {
+    /// Color level (`0.0` black, `1.0` full) at progress `t`.
+    pub(crate) fn level(self, t: f32) -> f32 {
+        match self {
+            FadeDirection::In => (t / 0.8).min(1.0),
+            FadeDirection::Out => 1.0 - (t / 0.8).min(1.0),
+            FadeDirection::InOut => (t / 0.4).min((1.0 - t) / 0.4).clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// Travel direction for the scroll animation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScrollDirection {
+    /// Text moves towards the left edge.
+    Left,
+    /// Text moves towards the right edge.
+    Right,
+}
+
+/// Reveal order for the typewriter animation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reveal {
+    /// Left edge first.
+    LeftToRight,
+    /// Right edge first.
+    RightToLeft,
+    /// Middle columns first, growing outwards.
+    CenterOut,
+}
+
+/// How many passes an animation plays.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoopMode {
+    /// A single pass.
+    Once,
+    /// A fixed number of passes (at least one).
+    Count(u32),
+    /// Loop until interrupted or the duration runs out.
+    Forever,
+}
+
+/// Playback options for animations.
+#[derive(Clone, Debug)]
+pub struct AnimationOptions {
+    /// Frames per pass.
+    pub frames: usize,
+    /// Number of passes (`0` loops until interrupted).
+    pub repeat: usize,
+    /// Play each pass forward and then backward.
+    pub ping_pong: bool,
+    /// Stop playback after this much wall-clock time, even mid-pass.
+    pub duration: Option<Duration>,
+    /// Play on the alternate screen, leaving the scrollback untouched.
+    pub alternate_screen: bool,
+    /// Redraw below the cursor without clearing the screen (ignored on the alternate screen).
+    pub in_place: bool,
+    /// Stop playback once this flag is set, instead of catching Ctrl-C.
+    pub stop: Option<Arc<AtomicBool>>,
+}
+
+impl Default for AnimationOptions {
+    fn default() -> Self {
+        Self {
+            frames: 180,
+            repeat: 1,
+            ping_pong: false,
+            duration: None,
+            alternate_screen: false,
+            in_place: false,
+            stop: None,
+        }
+    }
+}
+
+impl AnimationOptions {
+    /// Create options with the defaults (180 frames, one pass).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of frames per pass.
+    pub fn frames(mut self, frames: usize) -> Self {
+        self.frames = frames;
+        self
+    }
+
+    /// Set the number of passes (`0` loops until interrupted).
+    pub fn repeat(mut self, repeat: usize) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Set the number of passes from a [`LoopMode`].
+    pub fn loop_mode(mut self, mode: LoopMode) -> Self {
+        self.repeat = match mode {
+            LoopMode::Once => 1,
+            LoopMode::Count(count) => count.max(1) as usize,
+            LoopMode::Forever => 0,
+        };
+        self
+    }
+
+    /// Stop playback after `duration` of wall-clock time.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Play on the alternate screen instead of clearing the current one.
+    pub fn alternate_screen(mut self, enabled: bool) -> Self {
+        self.alternate_screen = enabled;
+        self
+    }
+
+    /// Animate inline at the cursor, moving back up over the previous frame each time.
+    pub fn in_place(mut self, enabled: bool) -> Self {
+        self.in_place = enabled;
+        self
+    }
+
+    /// Stop playback (restoring the terminal) once `flag` is set.
+    ///
+    /// With a stop flag, playback to stdout installs no Ctrl-C handler, leaving signals
+    /// entirely to the caller.
+    pub fn stop_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.stop = Some(flag);
+        self
+    }
+
+    /// Bounce back and forth instead of restarting each pass.
+    ///
+    /// Ignored by animations that end on a final look (fades and the matrix reveal), so
+    /// they still finish on it.
+    pub fn ping_pong(mut self, enabled: bool) -> Self {
+        self.ping_pong = enabled;
+        self
+    }
+
+    /// Progress values (`0.0..1.0`) for a single pass.
+    ///
+    /// With `settles`, the steps span `0.0..=1.0` instead and ping-pong is ignored, so the
+    /// last frame is the final look (a single frame is just that look).
+    pub(crate) fn pass(&self, settles: bool) -> Vec<f32> {
+        let frames = self.frames.max(1);
+        let mut steps: Vec<usize> = (0..frames).collect();
+        if self.ping_pong && !settles {
+            steps.extend((1..frames.saturating_sub(1)).rev());
+        }
+        let span = match (settles, frames) {
+            (true, 1) => return vec![1.0],
+            (true, _) => frames - 1,
+            (false, _) => frames,
+        };
+        steps
+            .into_iter()
+            .map(|step| step as f32 / span as f32)
+            .collect()
+    }
+}
+
+/// Lazily rendered animation frames, with no terminal I/O and no sleeping.
+///
+/// Iterating yields each frame as ANSI text; [`AnimationFrames::next_grid`] and
+/// [`AnimationFrames::grids`] yield the frame [`Grid`]s instead, for drawing into your
+/// own UI on your own schedule. Build one with
+/// [`Banner::frames`](crate::Banner::frames) or the `*_frames` shortcuts.
+pub struct AnimationFrames<'a> {
+    render: Box<dyn Fn(f32) -> Grid + 'a>,
+    mode: ColorMode,
+    quantizer: Quantizer,
+    pass: Vec<f32>,
+    /// Passes left to play (`None` loops forever).
+    passes: Option<usize>,
+    index: usize,
+    range: Range<f32>,
+}
+
+impl<'a> AnimationFrames<'a> {
+    pub(crate) fn new(
+        render: Box<dyn Fn(f32) -> Grid + 'a>,
+        mode: ColorMode,
+        options: &AnimationOptions,
+        settles: bool,
+    ) -> Self {
+        Self {
+            render,
+            mode,
+            quantizer: Quantizer::new(mode),
+            pass: options.pass(settles),
+            passes: (options.repeat > 0).then_some(options.repeat),
+            index: 0,
+            range: 0.0..1.0,
+        }
+    }
+
+    /// Sample progress from `range` instead of the full `0.0..1.0`.
+    ///
+    /// For a sweep, `0.0` and `1.0` are the far sides of the banner (the center ±0.75);
+    /// for the wave, one full breath. The frame count stays the same.
+    pub fn phase_range(mut self, range: Range<f32>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Render the next frame as a grid (`None` once every pass has played).
+    pub fn next_grid(&mut self) -> Option<Grid> {
+        let t = self.next_progress()?;
+        Some((self.render)(t))
+    }
+
+    /// Iterate over the remaining frames as grids.
+    pub fn grids(mut self) -> impl Iterator<Item = Grid> + 'a {
+        std::iter::from_fn(move || self.next_grid())
+    }
+
+    fn next_progress(&mut self) -> Option<f32> {
+        if self.index == self.pass.len() {
+            match &mut self.passes {
+                Some(1) => return None,
+                Some(passes) => *passes -= 1,
+                None => {}
+            }
+            self.index = 0;
+        }
+        let t = self.pass[self.index];
+        self.index += 1;
+        Some(self.range.start + t * (self.range.end - self.range.start))
+    }
+}
+
+impl Iterator for AnimationFrames<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let mut grid = self.next_grid()?;
+        self.quantizer.apply(&mut grid);
+        Some(emit_ansi(&grid, self.mode))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.passes {
+            Some(passes) => {
+                let left = (passes - 1) * self.pass.len() + self.pass.len() - self.index;
+                (left, Some(left))
+            }
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+/// Escape a string for inclusion in a JSON document.
+pub(crate) fn json_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ping_pong_pass_returns_without_repeating_ends() {
+        let pass = AnimationOptions::new()
+            .frames(4)
+            .ping_pong(true)
+            .pass(false);
+        assert_eq!(pass, [0.0, 0.25, 0.5, 0.75, 0.5, 0.25]);
+        let settling = AnimationOptions::new().frames(3).ping_pong(true).pass(true);
+        assert_eq!(settling, [0.0, 0.5, 1.0]);
+        assert_eq!(AnimationOptions::new().frames(1).pass(true), [1.0]);
+    }
+
+    #[test]
+    fn loop_mode_sets_the_number_of_passes() {
+        let options = AnimationOptions::new().repeat(5);
+        assert_eq!(options.clone().loop_mode(LoopMode::Once).repeat, 1);
+        assert_eq!(options.clone().loop_mode(LoopMode::Count(3)).repeat, 3);
+        assert_eq!(options.clone().loop_mode(LoopMode::Count(0)).repeat, 1);
+        assert_eq!(options.loop_mode(LoopMode::Forever).repeat, 0);
+
+        let render =
+            || -> Box<dyn Fn(f32) -> Grid> { Box::new(|_| Grid::from_char_rows(vec![vec!['x']])) };
+        let count = AnimationOptions::new()
+            .frames(3)
+            .loop_mode(LoopMode::Count(2));
+        let frames = AnimationFrames::new(render(), ColorMode::NoColor, &count, false);
+        assert_eq!(frames.count(), 6);
+        let forever = count.loop_mode(LoopMode::Forever);
+        let frames = AnimationFrames::new(render(), ColorMode::NoColor, &forever, false);
+        assert_eq!(frames.take(10).count(), 10);
+    }
+}
@@ -11,45 +11,108 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 
 use std::io::{self, Write};
-use std::thread;
-use std::time::Duration;
-
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+use crate::animation::{
+    Animation, AnimationFrames, AnimationOptions, FadeDirection, MATRIX_CHARSET, Reveal,
+    ScrollDirection, json_escape,
+};
 use crate::color::Palette;
 use crate::color::{Color, ColorMode};
+use crate::effects::contrast::{apply_cell_contrast, apply_terminal_readability};
 use crate::effects::dither::apply_dot_dither;
+use crate::effects::glitch::{GlitchConfig, apply_glitch};
 use crate::effects::light_sweep::{LightSweep, SweepDirection, apply_light_sweep_tint};
-use crate::effects::outline::{EdgeShade, apply_edge_shade};
-use crate::effects::shadow::{Shadow, apply_shadow};
-use crate::emit::emit_ansi;
-use crate::fill::{Dither, Fill, apply_fill};
-use crate::font::{self, Font, render_text};
+use crate::effects::outline::{EdgeShade, Outline, apply_edge_shade, apply_outline};
+use crate::effects::shadow::{Shadow, apply_soft_shadow};
+use crate::emit::{
+    EmitOptions, HtmlOptions, RasterError, RasterOptions, SvgOptions, emit_ansi, emit_ansi_lines,
+    emit_ansi_with, emit_html, emit_kitty, emit_png, emit_svg, emit_tagged, push_ansi, write_ansi,
+    write_ansi_with,
+};
+use crate::fill::{CanvasFill, Dither, Fill, apply_canvas, apply_fill, mix};
+use crate::font::{
+    self, Font, Orientation, TextDirection, TextLayout, render_text_spans, render_text_with,
+    wrap_text,
+};
 use crate::frame::{Frame, apply_frame};
-use crate::gradient::Gradient;
-use crate::grid::{Align, Grid, Padding};
+use crate::gradient::{Gradient, GradientDirection, GradientTable};
+use crate::grid::{Align, Cell, Grid, Padding, Quantizer};
 use crate::style::Style;
-use crate::terminal::detect_color_mode;
+use crate::terminal::{
+    CursorGuard, InterruptHook, detect_background, detect_color_mode, enable_ansi_support,
+    sleep_unless, terminal_width,
+};
 
 /// High-level banner builder.
 #[derive(Clone, Debug)]
 pub struct Banner {
     text: String,
+    messages: Vec<String>,
+    message_separator: char,
+    segments: Vec<(String, Color)>,
     font: Font,
-    gradient: Option<Gradient>,
+    gradients: Vec<Gradient>,
+    per_glyph: Option<Gradient>,
     fill: Fill,
     light_sweep: Option<LightSweep>,
     shadow: Option<Shadow>,
+    glitch: Option<GlitchConfig>,
+    shadow_steps: usize,
     edge_shade: Option<EdgeShade>,
+    outline: Option<Outline>,
     dot_dither: Option<Dither>,
     dot_dither_targets: Option<Vec<char>>,
+    caption: Option<String>,
+    caption_align: Align,
+    caption_color: Option<Color>,
+    caption_link: Option<Arc<str>>,
+    hyperlink: Option<Arc<str>>,
     align: Align,
+    line_align: Align,
     padding: Padding,
+    padding_char: Option<char>,
+    padding_color: Option<Color>,
+    background: Option<Gradient>,
+    canvas_color: Option<Color>,
+    canvas_fill: CanvasFill,
     frame: Option<Frame>,
     width: Option<usize>,
     max_width: Option<usize>,
+    fit_terminal: bool,
     kerning: usize,
+    overlap: usize,
     line_gap: usize,
+    wrap: bool,
+    direction: Option<TextDirection>,
+    orientation: Orientation,
     trim_vertical: bool,
+    trimmed_font: Option<Font>,
+    numeric: bool,
+    digits: Option<usize>,
+    zero_pad: bool,
     color_mode: ColorMode,
+    compact_output: bool,
+    readable_on_terminal: bool,
+    auto_contrast: bool,
+    grayscale: bool,
+    tint: Option<Color>,
+    font_fallback: Option<fn() -> Result<Font, font::figlet::FigletError>>,
+}
+
+/// Colored and plain renderings of one banner, from a single render pass.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderedBanner {
+    /// Output with ANSI escapes, as [`Banner::render`] returns it.
+    pub ansi: String,
+    /// The same text without escapes.
+    pub plain: String,
+    /// Width in cells.
+    pub width: usize,
+    /// Height in rows.
+    pub height: usize,
 }
 
 /// Errors returned when building a banner.
@@ -62,12 +125,25 @@ pub enum BannerError {
 impl std::fmt::Display for BannerError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            BannerError::Font(err) => write!(f, "font parse error: {err:?}"),
+            BannerError::Font(err) => write!(f, "font parse error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for BannerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BannerError::Font(err) => Some(err),
         }
     }
 }
 
-impl std::error::Error for BannerError {}
+impl std::fmt::Display for Banner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let grid = self.render_grid_with_sweep(None, None);
+        push_ansi(f, &grid, self.resolved_color_mode(), &self.emit_options())
+    }
+}
 
 impl From<font::figlet::FigletError> for BannerError {
     fn from(err: font::figlet::FigletError) -> Self {
@@ -78,47 +154,152 @@ impl From<font::figlet::FigletError> for BannerError {
 impl Banner {
     /// Create a banner from text.
     ///
-    /// Returns an error if the bundled font cannot be parsed.
+    /// Falls back to [`Font::minimal`] if the bundled font cannot be parsed;
+    /// use [`Banner::strict`] to turn that into an error.
     pub fn new(text: impl Into<String>) -> Result<Self, BannerError> {
-        Ok(Self {
+        Ok(Self::with_bundled_font(text, Font::dos_rebel))
+    }
+
+    fn with_bundled_font(
+        text: impl Into<String>,
+        load: fn() -> Result<Font, font::figlet::FigletError>,
+    ) -> Self {
+        match load() {
+            Ok(font) => Self::new_with(text, font),
+            Err(_) => {
+                let mut banner = Self::new_with(text, Font::minimal());
+                banner.font_fallback = Some(load);
+                banner
+            }
+        }
+    }
+
+    /// Create a banner from text with an explicit font (never fails).
+    pub fn new_with(text: impl Into<String>, font: Font) -> Self {
+        Self {
             text: text.into(),
-            font: Font::dos_rebel()?,
-            gradient: None,
+            messages: Vec::new(),
+            message_separator: '*',
+            segments: Vec::new(),
+            font,
+            gradients: Vec::new(),
+            per_glyph: None,
             fill: Fill::Blocks,
             light_sweep: None,
             shadow: None,
+            glitch: None,
+            shadow_steps: 1,
             edge_shade: None,
+            outline: None,
             dot_dither: None,
             dot_dither_targets: None,
+            caption: None,
+            caption_align: Align::Left,
+            caption_color: None,
+            caption_link: None,
+            hyperlink: None,
             align: Align::Left,
+            line_align: Align::Left,
             padding: Padding::uniform(0),
+            padding_char: None,
+            padding_color: None,
+            background: None,
+            canvas_color: None,
+            canvas_fill: CanvasFill::Bg,
             frame: None,
             width: None,
             max_width: None,
+            fit_terminal: false,
             kerning: 1,
+            overlap: 0,
             line_gap: 0,
+            wrap: false,
+            direction: None,
+            orientation: Orientation::Horizontal,
             trim_vertical: false,
+            trimmed_font: None,
+            numeric: false,
+            digits: None,
+            zero_pad: false,
             color_mode: ColorMode::Auto,
-        })
+            compact_output: false,
+            readable_on_terminal: false,
+            auto_contrast: false,
+            grayscale: false,
+            tint: None,
+            font_fallback: None,
+        }
+    }
+
+    /// Require the bundled font instead of the degraded fallback.
+    ///
+    /// Returns the font parse error if [`Banner::new`] fell back to [`Font::minimal`].
+    pub fn strict(self, enabled: bool) -> Result<Self, BannerError> {
+        if let (true, Some(load)) = (enabled, self.font_fallback) {
+            load()?;
+        }
+        Ok(self)
+    }
+
+    /// Render several messages as one long line joined by a separator glyph.
+    ///
+    /// Replaces the banner text; handy as marquee content.
+    pub fn messages(mut self, messages: Vec<String>, separator: char) -> Self {
+        self.messages = messages;
+        self.message_separator = separator;
+        self
+    }
+
+    /// Append a text segment drawn in its own color.
+    ///
+    /// Segments are joined left to right and replace the banner text; their colors
+    /// take precedence over any gradient.
+    pub fn segment(mut self, text: impl Into<String>, color: Color) -> Self {
+        self.segments.push((text.into(), color));
+        self
     }
 
     /// Set the font.
     pub fn font(mut self, font: Font) -> Self {
+        if self.trimmed_font.is_some() {
+            self.trimmed_font = Some(font.clone().trim_glyphs());
+        }
         self.font = font;
+        self.font_fallback = None;
         self
     }
 
     /// Apply a named style preset.
     pub fn style(mut self, style: Style) -> Self {
         self.color_mode = ColorMode::TrueColor;
-        self.gradient = Some(Gradient::vertical(Palette::preset(style.preset())));
+        self.gradients = vec![Gradient::vertical(Palette::preset(style.preset()))];
         self.fill = Fill::Keep;
         self
     }
 
     /// Apply a gradient across the glyph grid.
     pub fn gradient(mut self, gradient: Gradient) -> Self {
-        self.gradient = Some(gradient);
+        self.gradients = vec![gradient];
+        self
+    }
+
+    /// Layer several gradients in order; later ones win where their masks overlap.
+    ///
+    /// Combine with [`Gradient::masked`] for banded logos. Cells no gradient covers
+    /// keep their existing color.
+    pub fn gradients(mut self, gradients: Vec<Gradient>) -> Self {
+        self.gradients = gradients;
+        self
+    }
+
+    /// Color each glyph uniformly with the next color along `palette`, instead of a
+    /// spatial gradient.
+    ///
+    /// Glyph `i` of `n` (spaces skipped) takes the palette color at `i / (n - 1)`; the
+    /// kerning gap after a glyph shares its color. Takes precedence over
+    /// [`Banner::gradient`].
+    pub fn per_glyph_gradient(mut self, palette: Palette) -> Self {
+        self.per_glyph = Some(Gradient::horizontal(palette));
         self
     }
 
@@ -128,9 +309,41 @@ impl Banner {
         self
     }
 
+    /// Glitch the banner once: shifted row segments, scrambled glyphs and red/cyan tints.
+    ///
+    /// The same config always gives the same result.
+    pub fn glitch(mut self, config: GlitchConfig) -> Self {
+        self.glitch = Some(config);
+        self
+    }
+
     /// Add a drop shadow.
     pub fn shadow(mut self, offset: (i32, i32), alpha: f32) -> Self {
-        self.shadow = Some(Shadow { offset, alpha });
+        self.shadow = Some(Shadow {
+            offset,
+            alpha,
+            color: None,
+        });
+        self.shadow_steps = 1;
+        self
+    }
+
+    /// Add a soft shadow: `steps` copies at multiples of `offset`, each darker than the last.
+    pub fn soft_shadow(mut self, offset: (i32, i32), alpha: f32, steps: usize) -> Self {
+        self.shadow = Some(Shadow {
+            offset,
+            alpha,
+            color: None,
+        });
+        self.shadow_steps = steps;
+        self
+    }
+
+    /// Paint the shadow in a fixed color (requires [`Banner::shadow`] or [`Banner::soft_shadow`]).
+    pub fn shadow_color(mut self, color: Color) -> Self {
+        if let Some(shadow) = &mut self.shadow {
+            shadow.color = Some(color);
+        }
         self
     }
 
@@ -146,6 +359,24 @@ impl Banner {
         self
     }
 
+    /// Draw a solid outline of `ch` in `color` around the glyphs (diagonals included).
+    pub fn outline(mut self, color: Color, ch: char) -> Self {
+        self.outline = Some(Outline {
+            color,
+            ch,
+            diagonals: true,
+        });
+        self
+    }
+
+    /// Include diagonal neighbors in the outline (requires [`Banner::outline`]).
+    pub fn outline_diagonals(mut self, enabled: bool) -> Self {
+        if let Some(outline) = &mut self.outline {
+            outline.diagonals = enabled;
+        }
+        self
+    }
+
     /// Enable dot dithering using a custom configuration.
     pub fn dot_dither(mut self, dither: Dither) -> Self {
         self.dot_dither = Some(dither);
@@ -169,18 +400,97 @@ impl Banner {
         DotDitherBuilder::new(self)
     }
 
+    /// Add a one-row plain-text caption below the glyphs (inside padding and frame).
+    ///
+    /// Captions wider than the banner are truncated with an ellipsis.
+    pub fn caption(mut self, text: impl Into<String>) -> Self {
+        self.caption = Some(text.into());
+        self
+    }
+
+    /// Align the caption under the glyphs (default: left).
+    pub fn caption_align(mut self, align: Align) -> Self {
+        self.caption_align = align;
+        self
+    }
+
+    /// Set the caption color.
+    pub fn caption_color(mut self, color: Color) -> Self {
+        self.caption_color = Some(color);
+        self
+    }
+
+    /// Make the caption text an OSC 8 hyperlink to `url` (overrides [`Banner::hyperlink`]).
+    pub fn caption_link(mut self, url: impl Into<String>) -> Self {
+        self.caption_link = Some(url.into().into());
+        self
+    }
+
+    /// Make the glyph area an OSC 8 hyperlink to `url` in supporting terminals.
+    ///
+    /// Padding and frame stay unlinked. Links are closed at the end of every line and are
+    /// never emitted in `NoColor` mode or with [`EmitOptions::hyperlinks`] turned off.
+    pub fn hyperlink(mut self, url: impl Into<String>) -> Self {
+        self.hyperlink = Some(url.into().into());
+        self
+    }
+
     /// Align within the target width.
     pub fn align(mut self, align: Align) -> Self {
         self.align = align;
         self
     }
 
+    /// Align each text line within the widest line (independent of `align`).
+    pub fn line_align(mut self, align: Align) -> Self {
+        self.line_align = align;
+        self
+    }
+
     /// Add padding around the banner.
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
         self
     }
 
+    /// Draw padding (and alignment fill) with a visible character instead of blanks.
+    pub fn padding_char(mut self, ch: Option<char>) -> Self {
+        self.padding_char = ch;
+        self
+    }
+
+    /// Foreground color for padding drawn with [`Banner::padding_char`].
+    pub fn padding_color(mut self, color: Color) -> Self {
+        self.padding_color = Some(color);
+        self
+    }
+
+    /// Paint cell backgrounds with a gradient, padding and blank cells included.
+    pub fn background(mut self, gradient: Gradient) -> Self {
+        self.background = Some(gradient);
+        self
+    }
+
+    /// Paint every cell background with a solid color.
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background = Some(Gradient::new(vec![color], GradientDirection::Vertical));
+        self
+    }
+
+    /// Tint the blank cells inside the banner (including padding) with a solid color.
+    ///
+    /// Unlike [`Banner::background_color`], glyph cells keep their own background.
+    pub fn canvas_color(mut self, color: Color) -> Self {
+        self.canvas_color = Some(color);
+        self
+    }
+
+    /// Choose how [`Banner::canvas_color`] paints blank cells (default: background).
+    pub fn canvas_fill(mut self, fill: CanvasFill) -> Self {
+        self.canvas_fill = fill;
+        self
+    }
+
     /// Add a frame around the banner.
     pub fn frame(mut self, frame: Frame) -> Self {
         self.frame = Some(frame);
@@ -199,9 +509,29 @@ impl Banner {
         self
     }
 
+    /// Clamp output width to the terminal, leaving room for the frame.
+    ///
+    /// The width is detected at render time; nothing is clamped when it cannot be
+    /// determined. Combines with [`Banner::max_width`] (the smaller limit wins).
+    pub fn fit_terminal(mut self) -> Self {
+        self.fit_terminal = true;
+        self
+    }
+
     /// Space between characters.
     pub fn kerning(mut self, kerning: usize) -> Self {
         self.kerning = kerning;
+        self.overlap = 0;
+        self
+    }
+
+    /// Space between characters; negative values overlap adjacent glyphs.
+    ///
+    /// Overlap only consumes blank columns shared by both glyphs (checked row by row),
+    /// so visible cells are never covered. Width measurement for wrapping ignores it.
+    pub fn kerning_signed(mut self, kerning: i32) -> Self {
+        self.kerning = kerning.max(0) as usize;
+        self.overlap = kerning.min(0).unsigned_abs() as usize;
         self
     }
 
@@ -211,21 +541,249 @@ impl Banner {
         self
     }
 
+    /// Wrap text at word boundaries instead of clipping to the width limit.
+    pub fn wrap(mut self, enabled: bool) -> Self {
+        self.wrap = enabled;
+        self
+    }
+
+    /// Override the font's print direction.
+    pub fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Lay glyphs out side by side (default) or stacked top to bottom.
+    ///
+    /// In vertical mode `line_gap` separates glyphs and `align` positions each glyph
+    /// within the widest one.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
     /// Trim blank rows from the top and bottom of the rendered grid.
     pub fn trim_vertical(mut self, enabled: bool) -> Self {
         self.trim_vertical = enabled;
         self
     }
 
+    /// Strip blank side margins from each glyph (see [`Font::trim_glyphs`]).
+    pub fn trim_glyphs(mut self, enabled: bool) -> Self {
+        self.trimmed_font = enabled.then(|| self.font.clone().trim_glyphs());
+        self
+    }
+
+    /// Right-align the text in a fixed-width numeric field so counters don't shift.
+    ///
+    /// The field is `digits` wide, or `max_width` (minus padding) when no digit count is set.
+    pub fn numeric(mut self, enabled: bool) -> Self {
+        self.numeric = enabled;
+        self
+    }
+
+    /// Field width in digits for [`Banner::numeric`].
+    pub fn digits(mut self, digits: usize) -> Self {
+        self.digits = Some(digits);
+        self
+    }
+
+    /// Pad numeric fields with `0` glyphs instead of blanks.
+    pub fn zero_pad(mut self, enabled: bool) -> Self {
+        self.zero_pad = enabled;
+        self
+    }
+
+    /// Clone the banner configuration with different text (cheap re-render of counters).
+    pub fn clone_with_text(&self, text: impl Into<String>) -> Self {
+        let mut banner = self.clone();
+        banner.text = text.into();
+        banner
+    }
+
     /// Override color mode.
     pub fn color_mode(mut self, mode: ColorMode) -> Self {
         self.color_mode = mode;
         self
     }
 
+    /// Keep colors readable against the detected terminal background.
+    ///
+    /// Low-contrast colors are darkened on light terminals and lightened on dark ones;
+    /// nothing changes when the background cannot be detected.
+    pub fn readable_on_terminal(mut self, enabled: bool) -> Self {
+        self.readable_on_terminal = enabled;
+        self
+    }
+
+    /// Keep glyphs readable on their own background (see [`Banner::background`]).
+    ///
+    /// Foreground colors whose contrast ratio against the cell background is below 3.0
+    /// are nudged toward black or white until they reach it.
+    pub fn auto_contrast(mut self, enabled: bool) -> Self {
+        self.auto_contrast = enabled;
+        self
+    }
+
+    /// Convert every color to the gray of the same luminance, after all effects.
+    pub fn grayscale(mut self, enabled: bool) -> Self {
+        self.grayscale = enabled;
+        self
+    }
+
+    /// Recolor everything in a single hue: each color takes the hue and saturation of
+    /// `tint` and keeps its own lightness. Runs after all effects (and after
+    /// [`Banner::grayscale`]).
+    pub fn tint(mut self, tint: Color) -> Self {
+        self.tint = Some(tint);
+        self
+    }
+
+    /// Emit smaller ANSI output: colors carry across rows instead of being reset per line,
+    /// and trailing spaces without a background are dropped. Affects `render`, `render_to`
+    /// and `Display`; `render_lines` keeps independent full-width rows.
+    pub fn compact_output(mut self, enabled: bool) -> Self {
+        self.compact_output = enabled;
+        self
+    }
+
     /// Render to a `String` (ANSI escapes included if enabled).
     pub fn render(&self) -> String {
-        self.render_with_sweep(None, None)
+        self.to_string()
+    }
+
+    /// Render to one `String` per row, each ending with its own color reset.
+    pub fn render_lines(&self) -> Vec<String> {
+        let grid = self.render_grid_with_sweep(None, None);
+        emit_ansi_lines(&grid, self.resolved_color_mode())
+    }
+
+    /// Render for snapshot tests: always truecolor, whatever the environment says.
+    ///
+    /// Terminal detection is skipped entirely: `ColorMode::Auto` becomes truecolor,
+    /// [`Banner::fit_terminal`] and [`Banner::readable_on_terminal`] are ignored. Rendering
+    /// has no time- or random-dependent steps, so equal banners give equal bytes.
+    pub fn render_deterministic(&self) -> String {
+        let grid = self.deterministic_grid();
+        emit_ansi_with(&grid, ColorMode::TrueColor, &self.emit_options())
+    }
+
+    /// Render like [`Banner::render_deterministic`], with readable tags instead of escape
+    /// codes (see [`emit_tagged`]).
+    pub fn render_tagged(&self) -> String {
+        emit_tagged(&self.deterministic_grid())
+    }
+
+    /// Render once and emit both the colored and the plain version, plus the size.
+    pub fn render_parts(&self) -> RenderedBanner {
+        let grid = self.render_grid_with_sweep(None, None);
+        let options = self.emit_options();
+        RenderedBanner {
+            ansi: emit_ansi_with(&grid, self.resolved_color_mode(), &options),
+            plain: emit_ansi_with(&grid, ColorMode::NoColor, &options),
+            width: grid.width(),
+            height: grid.height(),
+        }
+    }
+
+    /// Render without any escape codes, regardless of `color_mode`.
+    ///
+    /// With `trim_trailing`, trailing spaces are removed from every row.
+    pub fn render_plain(&self, trim_trailing: bool) -> String {
+        let grid = self.render_grid_with_sweep(None, None);
+        let plain = emit_ansi(&grid, ColorMode::NoColor);
+        if !trim_trailing {
+            return plain;
+        }
+        plain
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Render to an HTML `<pre>` block with inline color styles.
+    pub fn render_html(&self) -> String {
+        let grid = self.render_grid_with_sweep(None, None);
+        emit_html(&grid, &HtmlOptions::new().color_mode(self.color_mode))
+    }
+
+    /// Render to HTML with explicit [`HtmlOptions`] (CSS classes, background wrapper).
+    pub fn render_html_with(&self, options: &HtmlOptions) -> String {
+        let grid = self.render_grid_with_sweep(None, None);
+        emit_html(&grid, options)
+    }
+
+    /// Render to an SVG document with the default [`SvgOptions`].
+    pub fn render_svg(&self) -> String {
+        self.render_svg_with(&SvgOptions::default())
+    }
+
+    /// Render to an SVG document with explicit [`SvgOptions`] (cell size, font, background).
+    pub fn render_svg_with(&self, options: &SvgOptions) -> String {
+        let grid = self.render_grid_with_sweep(None, None);
+        emit_svg(&grid, options)
+    }
+
+    /// Rasterize to PNG bytes with explicit [`RasterOptions`] (see [`emit_png`]).
+    pub fn render_png(&self, options: &RasterOptions) -> Result<Vec<u8>, RasterError> {
+        let grid = self.render_grid_with_sweep(None, None);
+        emit_png(&grid, options)
+    }
+
+    /// Render as an inline image for terminals speaking the kitty graphics protocol.
+    pub fn render_kitty(&self, options: &RasterOptions) -> Result<String, RasterError> {
+        let grid = self.render_grid_with_sweep(None, None);
+        emit_kitty(&grid, options)
+    }
+
+    /// Render directly into a writer (same output as `render`), without building the
+    /// whole banner as a `String` first. Pass a buffered writer.
+    pub fn render_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let grid = self.render_grid_with_sweep(None, None);
+        write_ansi_with(&grid, self.resolved_color_mode(), &self.emit_options(), w)
+    }
+
+    /// Measure the final `(width, height)` in cells without emitting ANSI.
+    ///
+    /// Includes fill, effects, padding, frame and trimming, so the result matches `render`.
+    pub fn measure(&self) -> (usize, usize) {
+        let grid = self.render_grid_with_sweep(None, None);
+        (grid.width(), grid.height())
+    }
+
+    /// Final width in cells (see [`Banner::measure`]; [`Banner::width`] sets the target).
+    pub fn rendered_width(&self) -> usize {
+        self.measure().0
+    }
+
+    /// Final height in rows (see [`Banner::measure`]).
+    pub fn rendered_height(&self) -> usize {
+        self.measure().1
+    }
+
+    /// Render into ratatui [`Text`](ratatui::text::Text), one line per row.
+    ///
+    /// Runs of equal color are merged into a single styled span.
+    #[cfg(feature = "ratatui")]
+    pub fn to_ratatui_text(&self) -> ratatui::text::Text<'static> {
+        let grid = self.render_grid_with_sweep(None, None);
+        crate::widget::grid_to_text(&grid, self.resolved_color_mode())
+    }
+
+    /// Render into a ratatui [`Paragraph`](ratatui::widgets::Paragraph) with per-cell colors,
+    /// aligned like the banner.
+    #[cfg(feature = "ratatui")]
+    pub fn to_paragraph(&self) -> ratatui::widgets::Paragraph<'static> {
+        ratatui::widgets::Paragraph::new(self.to_ratatui_text())
+            .alignment(crate::widget::alignment(self.align))
+    }
+
+    /// Render once into a [`BannerWidget`](crate::BannerWidget) for ratatui apps.
+    #[cfg(feature = "ratatui")]
+    pub fn widget(&self) -> crate::widget::BannerWidget {
+        let grid = self.render_grid_with_sweep(None, None);
+        crate::widget::BannerWidget::new(grid, self.resolved_color_mode(), self.align)
     }
 
     /// Animate a light sweep over the banner.
@@ -233,34 +791,28 @@ impl Banner {
     /// `speed_ms` controls the delay between frames in milliseconds.
     /// `highlight` overrides the sweep color (use `None` for white).
     pub fn animate_sweep(&self, speed_ms: u64, highlight: Option<Color>) -> io::Result<()> {
-        let mut stdout = io::stdout();
-        write!(stdout, "\x1b[2J\x1b[?25l")?;
-        stdout.flush()?;
-
-        let frames = 180;
-        let frame_time = Duration::from_millis(speed_ms);
-        let highlight = highlight.unwrap_or(Color::Rgb(255, 255, 255));
-        let base = self.light_sweep.unwrap_or_else(|| {
-            LightSweep::new(SweepDirection::DiagonalDown)
-                .width(0.25)
-                .intensity(0.9)
-                .softness(2.5)
-        });
-        let start = base.center - 0.75;
-        let end = base.center + 0.75;
-        for frame in 0..frames {
-            let t = frame as f32 / frames as f32;
-            let center = start + t * (end - start);
-            let sweep = base.center(center);
+        self.animate_sweep_with(speed_ms, highlight, &AnimationOptions::default())
+    }
 
-            let banner = self.render_with_sweep(Some(sweep), Some(highlight));
-            write!(stdout, "\x1b[H{banner}")?;
-            stdout.flush()?;
-            thread::sleep(frame_time);
-        }
+    /// Animate the light sweep with explicit frame count, looping, and duration.
+    pub fn animate_sweep_with(
+        &self,
+        speed_ms: u64,
+        highlight: Option<Color>,
+        options: &AnimationOptions,
+    ) -> io::Result<()> {
+        self.play(Animation::Sweep { highlight }, speed_ms, options)
+    }
 
-        writeln!(stdout, "\x1b[?25h")?;
-        Ok(())
+    /// Animate the light sweep into `writer` instead of stdout.
+    pub fn animate_sweep_to<W: Write>(
+        &self,
+        writer: W,
+        speed_ms: u64,
+        highlight: Option<Color>,
+        options: &AnimationOptions,
+    ) -> io::Result<()> {
+        self.animate_to(writer, Animation::Sweep { highlight }, speed_ms, options)
     }
 
     /// Animate a wave-like breathing effect over the banner without moving glyphs.
@@ -273,85 +825,621 @@ impl Banner {
         dim_strength: Option<f32>,
         bright_strength: Option<f32>,
     ) -> io::Result<()> {
-        let mut stdout = io::stdout();
-        write!(stdout, "\x1b[2J\x1b[?25l")?;
-        stdout.flush()?;
+        self.animate_wave_with(
+            speed_ms,
+            dim_strength,
+            bright_strength,
+            &AnimationOptions::default(),
+        )
+    }
 
-        let frames = 180;
-        let frame_time = Duration::from_millis(speed_ms);
-        let base = self.render_grid_with_sweep(None, None);
-        let dim_strength = dim_strength.unwrap_or(0.35).clamp(0.0, 1.0);
-        let bright_strength = bright_strength.unwrap_or(0.2).clamp(0.0, 1.0);
-        let mode = match self.color_mode {
-            ColorMode::Auto => detect_color_mode(),
-            other => other,
+    /// Animate the breathing wave with explicit frame count, looping, and duration.
+    pub fn animate_wave_with(
+        &self,
+        speed_ms: u64,
+        dim_strength: Option<f32>,
+        bright_strength: Option<f32>,
+        options: &AnimationOptions,
+    ) -> io::Result<()> {
+        let wave = Animation::Wave {
+            dim: dim_strength,
+            bright: bright_strength,
         };
+        self.play(wave, speed_ms, options)
+    }
 
-        for frame in 0..frames {
-            let t = frame as f32 / frames as f32;
-            let phase = t * std::f32::consts::TAU;
-            let waved = apply_wave_breathe(&base, phase, dim_strength, bright_strength);
-            let banner = emit_ansi(&waved, mode);
-            write!(stdout, "\x1b[H{banner}")?;
-            stdout.flush()?;
-            thread::sleep(frame_time);
-        }
-
-        writeln!(stdout, "\x1b[?25h")?;
-        Ok(())
+    /// Animate the breathing wave into `writer` instead of stdout.
+    pub fn animate_wave_to<W: Write>(
+        &self,
+        writer: W,
+        speed_ms: u64,
+        dim_strength: Option<f32>,
+        bright_strength: Option<f32>,
+        options: &AnimationOptions,
+    ) -> io::Result<()> {
+        let wave = Animation::Wave {
+            dim: dim_strength,
+            bright: bright_strength,
+        };
+        self.animate_to(writer, wave, speed_ms, options)
     }
 
     /// Animate a rolling wave (tsunami roll) that advances with a heavy crest.
     ///
     /// `speed_ms` controls the delay between frames in milliseconds.
     pub fn animate_roll(&self, speed_ms: u64) -> io::Result<()> {
-        let mut stdout = io::stdout();
-        write!(stdout, "\x1b[2J\x1b[?25l")?;
-        stdout.flush()?;
+        self.animate_roll_with(speed_ms, &AnimationOptions::default())
+    }
+
+    /// Animate the rolling wave with explicit frame count, looping, and duration.
+    pub fn animate_roll_with(&self, speed_ms: u64, options: &AnimationOptions) -> io::Result<()> {
+        self.play(Animation::Roll, speed_ms, options)
+    }
+
+    /// Animate the rolling wave into `writer` instead of stdout.
+    pub fn animate_roll_to<W: Write>(
+        &self,
+        writer: W,
+        speed_ms: u64,
+        options: &AnimationOptions,
+    ) -> io::Result<()> {
+        self.animate_to(writer, Animation::Roll, speed_ms, options)
+    }
+
+    /// Animate a continuous hue cycle ("rainbow") over every colored glyph.
+    ///
+    /// `speed_ms` controls the delay between frames in milliseconds; each pass turns
+    /// the colors once around the hue wheel.
+    pub fn animate_hue(&self, speed_ms: u64) -> io::Result<()> {
+        self.play(Animation::Hue, speed_ms, &AnimationOptions::default())
+    }
+
+    /// Blink the banner: `on_frames` at full brightness, then `off_frames` dimmed.
+    ///
+    /// `speed_ms` controls the delay between frames in milliseconds. The cycle repeats
+    /// for roughly as many frames as the other animations; with
+    /// [`ColorMode::NoColor`] the off frames are blank.
+    pub fn animate_blink(
+        &self,
+        speed_ms: u64,
+        on_frames: usize,
+        off_frames: usize,
+    ) -> io::Result<()> {
+        let period = (on_frames + off_frames).max(1);
+        let options = AnimationOptions::new().frames(period);
+        let options = options.repeat((AnimationOptions::default().frames / period).max(1));
+        self.play(
+            Animation::Blink {
+                on: on_frames,
+                off: off_frames,
+                brightness: 0.25,
+            },
+            speed_ms,
+            &options,
+        )
+    }
+
+    /// Animate the gradient flowing along its axis like a marquee.
+    ///
+    /// `speed_ms` controls the delay between frames in milliseconds; `cycles` is how many
+    /// times the colors travel the full gradient during the animation.
+    pub fn animate_gradient(&self, speed_ms: u64, cycles: f32) -> io::Result<()> {
+        self.play(
+            Animation::Gradient { cycles },
+            speed_ms,
+            &AnimationOptions::default(),
+        )
+    }
+
+    /// Animate the banner typing in column by column.
+    ///
+    /// `speed_ms` controls the delay between frames in milliseconds; the finished
+    /// banner holds for the last fifth of the animation.
+    pub fn animate_typewriter(&self, speed_ms: u64, reveal: Reveal) -> io::Result<()> {
+        self.play(
+            Animation::Typewriter { reveal },
+            speed_ms,
+            &AnimationOptions::default(),
+        )
+    }
+
+    /// Animate short glitch bursts, with clean frames between them so the text stays readable.
+    ///
+    /// `speed_ms` controls the delay between frames in milliseconds.
+    pub fn animate_glitch(&self, speed_ms: u64) -> io::Result<()> {
+        self.play(
+            Animation::Glitch { intensity: 0.6 },
+            speed_ms,
+            &AnimationOptions::default(),
+        )
+    }
+
+    /// Rain green glyphs over the banner's box until every cell locks into place.
+    ///
+    /// `speed_ms` controls the delay between frames in milliseconds. Each column settles
+    /// top to bottom after its own random delay; the last fifth of each pass holds the
+    /// finished banner.
+    pub fn animate_matrix_reveal(
+        &self,
+        speed_ms: u64,
+        options: &AnimationOptions,
+    ) -> io::Result<()> {
+        let matrix = Animation::MatrixReveal {
+            seed: 0,
+            charset: MATRIX_CHARSET,
+        };
+        self.play(matrix, speed_ms, options)
+    }
+
+    /// Fade the banner's colors in from black, out to black, or both.
+    ///
+    /// `speed_ms` controls the delay between frames in milliseconds. Colors are blended in
+    /// RGB and quantized per frame, so fades stay smooth in 256-color mode too.
+    pub fn animate_fade(&self, speed_ms: u64, direction: FadeDirection) -> io::Result<()> {
+        self.play(
+            Animation::Fade { direction },
+            speed_ms,
+            &AnimationOptions::default(),
+        )
+    }
+
+    /// Scroll the banner through a `width`-wide window as a looping marquee.
+    ///
+    /// `speed_ms` controls the delay between frames in milliseconds. Head and tail are
+    /// separated by 4 blank columns; use [`Animation::Scroll`] with
+    /// [`Banner::animate`] for a different gap.
+    pub fn animate_scroll(
+        &self,
+        width: usize,
+        speed_ms: u64,
+        direction: ScrollDirection,
+    ) -> io::Result<()> {
+        self.play(
+            Animation::Scroll {
+                width,
+                direction,
+                gap: 4,
+            },
+            speed_ms,
+            &AnimationOptions::default(),
+        )
+    }
+
+    /// Play any built-in animation with explicit playback options.
+    ///
+    /// Ctrl-C (or the end of `options.duration`, or the stop flag) stops playback early; the
+    /// cursor is restored either way. After Ctrl-C this returns an
+    /// [`io::ErrorKind::Interrupted`] error. Ctrl-C is only caught with the `terminal`
+    /// feature and without [`AnimationOptions::stop_flag`].
+    pub fn animate(
+        &self,
+        animation: Animation,
+        speed_ms: u64,
+        options: &AnimationOptions,
+    ) -> io::Result<()> {
+        self.play(animation, speed_ms, options)
+    }
+
+    /// Play any built-in animation into `writer` instead of stdout.
+    ///
+    /// The cursor (and the alternate screen, if entered) is restored on every exit,
+    /// including write errors. Ctrl-C is left alone, since `writer` need not be a terminal.
+    pub fn animate_to<W: Write>(
+        &self,
+        writer: W,
+        animation: Animation,
+        speed_ms: u64,
+        options: &AnimationOptions,
+    ) -> io::Result<()> {
+        self.play_to(writer, animation, speed_ms, options, InterruptHook::none())
+    }
+
+    /// Render the sweep animation at progress `t` (`0.0..1.0`), without terminal control codes.
+    pub fn render_sweep_at(&self, t: f32) -> String {
+        self.render_frame_at(Animation::Sweep { highlight: None }, t)
+    }
+
+    /// Render the breathing wave at `phase` radians, without terminal control codes.
+    pub fn render_wave_at(&self, phase: f32) -> String {
+        let t = phase / std::f32::consts::TAU;
+        self.render_frame_at(
+            Animation::Wave {
+                dim: None,
+                bright: None,
+            },
+            t,
+        )
+    }
+
+    /// Render the rolling wave at progress `t` (`0.0..1.0`), without terminal control codes.
+    pub fn render_roll_at(&self, t: f32) -> String {
+        self.render_frame_at(Animation::Roll, t)
+    }
+
+    /// Render the hue cycle at progress `t` (`0.0..1.0`), without terminal control codes.
+    pub fn render_hue_at(&self, t: f32) -> String {
+        self.render_frame_at(Animation::Hue, t)
+    }
+
+    /// Iterate over the rendered frames of an animation, without terminal control codes.
+    ///
+    /// Yields `options.frames` frames per pass; a `repeat` of `0` never ends.
+    pub fn frames(&self, animation: Animation, options: &AnimationOptions) -> AnimationFrames<'_> {
+        AnimationFrames::new(
+            self.frame_renderer(animation),
+            self.resolved_color_mode(),
+            options,
+            animation.settles(),
+        )
+    }
+
+    /// The `frames` light sweep frames [`Banner::animate_sweep`] plays, without I/O.
+    pub fn sweep_frames(&self, frames: usize) -> AnimationFrames<'_> {
+        self.frames(
+            Animation::Sweep { highlight: None },
+            &AnimationOptions::new().frames(frames),
+        )
+    }
+
+    /// The `frames` breathing frames [`Banner::animate_wave`] plays, without I/O.
+    pub fn wave_frames(
+        &self,
+        frames: usize,
+        dim_strength: Option<f32>,
+        bright_strength: Option<f32>,
+    ) -> AnimationFrames<'_> {
+        let wave = Animation::Wave {
+            dim: dim_strength,
+            bright: bright_strength,
+        };
+        self.frames(wave, &AnimationOptions::new().frames(frames))
+    }
+
+    /// The `frames` rain frames [`Banner::animate_matrix_reveal`] plays, without I/O.
+    pub fn matrix_frames(&self, frames: usize, seed: u32) -> AnimationFrames<'_> {
+        let matrix = Animation::MatrixReveal {
+            seed,
+            charset: MATRIX_CHARSET,
+        };
+        self.frames(matrix, &AnimationOptions::new().frames(frames))
+    }
+
+    /// The `frames` fade frames [`Banner::animate_fade`] plays, without I/O.
+    pub fn fade_frames(&self, frames: usize, direction: FadeDirection) -> AnimationFrames<'_> {
+        self.frames(
+            Animation::Fade { direction },
+            &AnimationOptions::new().frames(frames),
+        )
+    }
+
+    /// The `frames` rolling wave frames [`Banner::animate_roll`] plays, without I/O.
+    pub fn roll_frames(&self, frames: usize) -> AnimationFrames<'_> {
+        self.frames(Animation::Roll, &AnimationOptions::new().frames(frames))
+    }
+
+    /// Record an animation as an asciinema v2 `.cast` document.
+    ///
+    /// Each frame becomes one output event, spaced `speed_ms` apart.
+    pub fn record_cast(
+        &self,
+        animation: Animation,
+        speed_ms: u64,
+        options: &AnimationOptions,
+    ) -> String {
+        let (width, height) = self.measure();
+        let mode = self.resolved_color_mode();
+        let render = self.frame_renderer(animation);
+        let pass = options.pass(animation.settles());
+        let steps: Vec<f32> = (0..options.repeat.max(1))
+            .flat_map(|_| pass.iter().copied())
+            .collect();
+
+        let mut quantizer = Quantizer::new(mode);
+        let mut out = format!("{{\"version\": 2, \"width\": {width}, \"height\": {height}}}\n");
+        for (frame, t) in steps.iter().enumerate() {
+            let mut grid = render(*t);
+            quantizer.apply(&mut grid);
+            let mut data = String::new();
+            if frame == 0 {
+                data.push_str("\x1b[2J\x1b[?25l");
+            }
+            data.push_str("\x1b[H");
+            data.push_str(&emit_ansi(&grid, mode));
+            if frame + 1 == steps.len() {
+                data.push_str("\x1b[?25h\r\n");
+            }
+            let time = frame as f64 * speed_ms as f64 / 1000.0;
+            out.push_str(&format!("[{time:.6}, \"o\", \"{}\"]\n", json_escape(&data)));
+        }
+        out
+    }
+
+    fn render_frame_at(&self, animation: Animation, t: f32) -> String {
+        let grid = self.frame_renderer(animation)(t);
+        emit_ansi(&grid, self.resolved_color_mode())
+    }
+
+    fn play(
+        &self,
+        animation: Animation,
+        speed_ms: u64,
+        options: &AnimationOptions,
+    ) -> io::Result<()> {
+        // Best effort: without it old Windows consoles show raw escapes, but playback can go on.
+        let _ = enable_ansi_support();
+        let stdout = io::BufWriter::with_capacity(64 * 1024, io::stdout().lock());
+        // A caller-supplied stop flag replaces the Ctrl-C handler.
+        let interrupt = match options.stop {
+            Some(_) => InterruptHook::none(),
+            None => InterruptHook::install(),
+        };
+        self.play_to(stdout, animation, speed_ms, options, interrupt)
+    }
+
+    fn play_to<W: Write>(
+        &self,
+        writer: W,
+        animation: Animation,
+        speed_ms: u64,
+        options: &AnimationOptions,
+        interrupt: InterruptHook,
+    ) -> io::Result<()> {
+        let mut out = CursorGuard::enter(writer, options.alternate_screen)?;
+        let in_place = options.in_place && !options.alternate_screen;
+        if !in_place {
+            out.write_all(b"\x1b[2J")?;
+        }
 
-        let frames = 180;
         let frame_time = Duration::from_millis(speed_ms);
-        let base = self.render_grid_with_sweep(None, None);
-        let mode = match self.color_mode {
-            ColorMode::Auto => detect_color_mode(),
-            other => other,
+        let mode = self.resolved_color_mode();
+        let mut frames = self.frames(animation, options);
+        let mut quantizer = Quantizer::new(mode);
+        let deadline = options.duration.map(|duration| Instant::now() + duration);
+        let remaining =
+            || deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+        let stopped = || {
+            interrupt.interrupted()
+                || options
+                    .stop
+                    .as_ref()
+                    .is_some_and(|stop| stop.load(Ordering::SeqCst))
         };
 
-        for frame in 0..frames {
-            let t = frame as f32 / frames as f32;
-            let rolled = apply_roll(&base, t);
-            let banner = emit_ansi(&rolled, mode);
-            write!(stdout, "\x1b[H{banner}")?;
-            stdout.flush()?;
-            thread::sleep(frame_time);
+        // Rows already drawn by the previous frame, for moving back over them in place.
+        let mut drawn = 0;
+        while !stopped() && remaining() != Some(Duration::ZERO) {
+            let Some(mut grid) = frames.next_grid() else {
+                break;
+            };
+            quantizer.apply(&mut grid);
+            if !in_place {
+                out.write_all(b"\x1b[H")?;
+            } else if drawn > 0 {
+                // Rows are newline-separated, so the cursor sits on the last one.
+                out.write_all(b"\r")?;
+                if drawn > 1 {
+                    write!(out, "\x1b[{}A", drawn - 1)?;
+                }
+                out.write_all(b"\x1b[J")?;
+            }
+            drawn = grid.height();
+            write_ansi(&grid, mode, &mut out)?;
+            out.flush()?;
+            sleep_unless(
+                remaining().map_or(frame_time, |left| left.min(frame_time)),
+                stopped,
+            );
         }
 
-        writeln!(stdout, "\x1b[?25h")?;
-        Ok(())
+        interrupt.check()
     }
 
-    fn render_with_sweep(
+    /// Build a renderer mapping animation progress (`0.0..1.0`) to a frame grid.
+    fn frame_renderer(&self, animation: Animation) -> Box<dyn Fn(f32) -> Grid + '_> {
+        match animation {
+            Animation::Sweep { highlight } => {
+                let highlight = highlight.unwrap_or(Color::Rgb(255, 255, 255));
+                let base = self.light_sweep.unwrap_or_else(|| {
+                    LightSweep::new(SweepDirection::DiagonalDown)
+                        .width(0.25)
+                        .intensity(0.9)
+                        .softness(2.5)
+                });
+                let start = base.center - 0.75;
+                let end = base.center + 0.75;
+                let max_width = self.max_width_limit();
+                let glyphs = self.render_glyphs(max_width);
+                let tables = self.gradient_tables(&glyphs);
+                Box::new(move |t| {
+                    let sweep = base.center(start + t * (end - start));
+                    let glyphs = glyphs.clone();
+                    self.finish_grid(
+                        glyphs,
+                        &tables,
+                        max_width,
+                        0.0,
+                        Some(sweep),
+                        Some(highlight),
+                    )
+                })
+            }
+            Animation::Gradient { cycles } => {
+                let max_width = self.max_width_limit();
+                let glyphs = self.render_glyphs(max_width);
+                let tables = self.gradient_tables(&glyphs);
+                Box::new(move |t| {
+                    let phase = (t * cycles).rem_euclid(1.0);
+                    self.finish_grid(glyphs.clone(), &tables, max_width, phase, None, None)
+                })
+            }
+            Animation::Wave { dim, bright } => {
+                let base = self.render_grid_with_sweep(None, None);
+                let dim = dim.unwrap_or(0.35).clamp(0.0, 1.0);
+                let bright = bright.unwrap_or(0.2).clamp(0.0, 1.0);
+                Box::new(move |t| apply_wave_breathe(&base, t * std::f32::consts::TAU, dim, bright))
+            }
+            Animation::Roll => {
+                let base = self.render_grid_with_sweep(None, None);
+                Box::new(move |t| apply_roll(&base, t))
+            }
+            Animation::Blink {
+                on,
+                off,
+                brightness,
+            } => {
+                let base = self.render_grid_with_sweep(None, None);
+                let blank = brightness <= 0.0 || self.resolved_color_mode() == ColorMode::NoColor;
+                let dimmed = apply_blink_dim(&base, brightness, blank);
+                let period = (on + off).max(1) as f32;
+                Box::new(move |t| {
+                    let frame = (t.rem_euclid(1.0) * period + 1e-3) as usize;
+                    if frame < on {
+                        base.clone()
+                    } else {
+                        dimmed.clone()
+                    }
+                })
+            }
+            Animation::Hue => {
+                let base = self.render_grid_with_sweep(None, None);
+                Box::new(move |t| apply_hue_shift(&base, t * 360.0))
+            }
+            Animation::Scroll {
+                width,
+                direction,
+                gap,
+            } => {
+                let base = self.render_grid_with_sweep(None, None);
+                Box::new(move |t| apply_scroll(&base, width, direction, gap, t))
+            }
+            Animation::Typewriter { reveal } => {
+                let base = self.render_grid_with_sweep(None, None);
+                Box::new(move |t| apply_reveal(&base, reveal, (t / 0.8).min(1.0)))
+            }
+            Animation::Glitch { intensity } => {
+                let base = self.render_grid_with_sweep(None, None);
+                Box::new(move |t| {
+                    // Eight bursts per pass, each glitching only the last 40% of its slot.
+                    if (t * 8.0).fract() < 0.6 {
+                        return base.clone();
+                    }
+                    let seed = (t * 1e4) as u32;
+                    apply_glitch(&base, &GlitchConfig::new(seed, intensity))
+                })
+            }
+            Animation::MatrixReveal { seed, charset } => {
+                let base = self.render_grid_with_sweep(None, None);
+                let settle = matrix_settle_times(&base, seed);
+                let charset: Vec<char> = if charset.is_empty() {
+                    MATRIX_CHARSET.chars().collect()
+                } else {
+                    charset.chars().collect()
+                };
+                Box::new(move |t| apply_matrix_rain(&base, &settle, seed, &charset, t))
+            }
+            Animation::Fade { direction } => {
+                let base = self.render_grid_with_sweep(None, None);
+                Box::new(move |t| apply_fade(&base, direction.level(t)))
+            }
+        }
+    }
+
+    fn render_grid_with_sweep(
         &self,
         sweep_override: Option<LightSweep>,
         highlight: Option<Color>,
-    ) -> String {
-        let grid = self.render_grid_with_sweep(sweep_override, highlight);
-        let mode = match self.color_mode {
-            ColorMode::Auto => detect_color_mode(),
-            other => other,
+    ) -> Grid {
+        // Resolved once per render: it may query the terminal.
+        let max_width = self.max_width_limit();
+        let glyphs = self.render_glyphs(max_width);
+        let tables = self.gradient_tables(&glyphs);
+        self.finish_grid(glyphs, &tables, max_width, 0.0, sweep_override, highlight)
+    }
+
+    /// Lay out the text and apply the fill (everything before coloring).
+    fn render_glyphs(&self, max_width: Option<usize>) -> Grid {
+        let font = self.layout_font();
+        let text = self.layout_text(font, max_width);
+        let layout = TextLayout {
+            kerning: self.kerning,
+            overlap: self.overlap,
+            line_gap: self.line_gap,
+            direction: self.direction.unwrap_or_else(|| font.direction()),
+            orientation: self.orientation,
+            align: self.align,
+            line_align: self.line_align,
+        };
+        let mut grid = if !self.segments.is_empty() {
+            self.render_segments(font, &layout)
+        } else if !self.messages.is_empty() {
+            self.render_messages(font, &layout)
+        } else if self.numeric {
+            self.render_numeric(&text, font, &layout, max_width)
+        } else if let Some(gradient) = &self.per_glyph {
+            render_per_glyph(&text, font, &layout, gradient)
+        } else {
+            render_text_with(&text, font, &layout)
         };
-        emit_ansi(&grid, mode)
+        apply_fill(&mut grid, self.fill);
+        grid
+    }
+
+    fn render_numeric(
+        &self,
+        text: &str,
+        font: &Font,
+        layout: &TextLayout,
+        max_width: Option<usize>,
+    ) -> Grid {
+        let digit_width = ('0'..='9')
+            .map(|ch| font.glyph(ch).width())
+            .max()
+            .unwrap_or(0);
+        let field_width = match (self.digits, max_width) {
+            (Some(digits), _) => digits * digit_width + layout.kerning * digits.saturating_sub(1),
+            (None, Some(max_width)) => {
+                max_width.saturating_sub(self.padding.left + self.padding.right)
+            }
+            (None, None) => 0,
+        };
+
+        let mut text = text.to_string();
+        if self.zero_pad {
+            let count = text.chars().count();
+            let pad = self.digits.map_or(0, |digits| digits.saturating_sub(count));
+            text.insert_str(0, &"0".repeat(pad));
+        }
+        let grid = render_text_with(&text, font, layout);
+        if grid.width() >= field_width {
+            return grid;
+        }
+        let mut field = Grid::new(grid.height(), field_width);
+        field.blit(&grid, 0, field_width - grid.width());
+        field
     }
 
-    fn render_grid_with_sweep(
+    fn gradient_tables(&self, glyphs: &Grid) -> Vec<GradientTable> {
+        if !self.segments.is_empty() || self.per_glyph.is_some() {
+            return Vec::new();
+        }
+        self.gradients
+            .iter()
+            .map(|gradient| GradientTable::new(gradient, glyphs.height(), glyphs.width()))
+            .collect()
+    }
+
+    /// Color, decorate and lay out rendered glyphs.
+    fn finish_grid(
         &self,
+        mut grid: Grid,
+        gradients: &[GradientTable],
+        max_width: Option<usize>,
+        phase: f32,
         sweep_override: Option<LightSweep>,
         highlight: Option<Color>,
     ) -> Grid {
-        let mut grid = render_text(&self.text, &self.font, self.kerning, self.line_gap);
-        apply_fill(&mut grid, self.fill);
-        if let Some(gradient) = &self.gradient {
-            gradient.apply(&mut grid);
+        for table in gradients {
+            table.apply(&mut grid, phase);
         }
         if let Some(sweep) = sweep_override.or(self.light_sweep) {
             let highlight = highlight.unwrap_or(Color::Rgb(255, 255, 255));
@@ -368,19 +1456,145 @@ impl Banner {
         if let Some(shade) = self.edge_shade {
             grid = apply_edge_shade(&grid, shade);
         }
+        if let Some(outline) = self.outline {
+            grid = apply_outline(&grid, outline);
+        }
         if let Some(shadow) = self.shadow {
-            grid = apply_shadow(&grid, shadow);
+            grid = apply_soft_shadow(&grid, shadow, self.shadow_steps);
+        }
+        if let Some(glitch) = &self.glitch {
+            grid = apply_glitch(&grid, glitch);
         }
         if self.trim_vertical {
             grid = grid.trim_vertical();
         }
-        let grid = apply_layout(grid, self.padding, self.width, self.max_width, self.align);
-        if let Some(frame) = &self.frame {
+        if let Some(url) = &self.hyperlink {
+            for cell in grid.rows_mut().iter_mut().flatten() {
+                cell.link = Some(url.clone());
+            }
+        }
+        if let Some(caption) = &self.caption {
+            let link = self.caption_link.as_ref().or(self.hyperlink.as_ref());
+            grid = append_caption(grid, caption, self.caption_align, self.caption_color, link);
+        }
+        let pad_cell = self.padding_char.map(|ch| Cell {
+            fg: self.padding_color,
+            visible: true,
+            ..Cell::new(ch)
+        });
+        let mut grid = apply_layout(
+            grid,
+            self.padding,
+            self.width,
+            max_width,
+            self.align,
+            pad_cell.as_ref(),
+        );
+        if let Some(background) = &self.background {
+            background.apply_background(&mut grid);
+        }
+        if let Some(color) = self.canvas_color {
+            apply_canvas(&mut grid, color, self.canvas_fill);
+        }
+        let mut grid = if let Some(frame) = &self.frame {
             apply_frame(grid, frame)
         } else {
             grid
+        };
+        if self.grayscale || self.tint.is_some() {
+            apply_color_filter(&mut grid, self.grayscale, self.tint);
+        }
+        if self.auto_contrast {
+            apply_cell_contrast(&mut grid);
+        }
+        if self.readable_on_terminal {
+            apply_terminal_readability(&mut grid, detect_background());
+        }
+        grid
+    }
+
+    fn render_messages(&self, font: &Font, layout: &TextLayout) -> Grid {
+        let separator = render_text_with(&format!(" {} ", self.message_separator), font, layout);
+        let mut parts = Vec::with_capacity(self.messages.len() * 2);
+        for (idx, message) in self.messages.iter().enumerate() {
+            if idx > 0 {
+                parts.push(separator.clone());
+            }
+            parts.push(render_text_with(message, font, layout));
+        }
+        Grid::concat(&parts, layout.kerning)
+    }
+
+    fn render_segments(&self, font: &Font, layout: &TextLayout) -> Grid {
+        let parts: Vec<Grid> = self
+            .segments
+            .iter()
+            .map(|(text, color)| {
+                let mut grid = render_text_with(text, font, layout);
+                for cell in grid.rows_mut().iter_mut().flatten() {
+                    if cell.visible {
+                        cell.fg = Some(*color);
+                    }
+                }
+                grid
+            })
+            .collect();
+        Grid::concat(&parts, layout.kerning)
+    }
+
+    fn deterministic_grid(&self) -> Grid {
+        let mut banner = self.clone();
+        banner.color_mode = ColorMode::TrueColor;
+        banner.fit_terminal = false;
+        banner.readable_on_terminal = false;
+        banner.render_grid_with_sweep(None, None)
+    }
+
+    /// `max_width`, tightened to the terminal width when fitting is enabled.
+    fn max_width_limit(&self) -> Option<usize> {
+        let terminal = self
+            .fit_terminal
+            .then(terminal_width)
+            .flatten()
+            .map(|columns| {
+                let frame = self.frame.as_ref().map_or(0, Frame::horizontal_size);
+                columns.saturating_sub(frame)
+            });
+        [self.max_width, terminal].into_iter().flatten().min()
+    }
+
+    fn emit_options(&self) -> EmitOptions {
+        if self.compact_output {
+            EmitOptions::compact()
+        } else {
+            EmitOptions::default()
         }
     }
+
+    fn resolved_color_mode(&self) -> ColorMode {
+        match self.color_mode {
+            ColorMode::Auto => detect_color_mode(),
+            other => other,
+        }
+    }
+
+    fn layout_font(&self) -> &Font {
+        self.trimmed_font.as_ref().unwrap_or(&self.font)
+    }
+
+    fn layout_text(&self, font: &Font, max_width: Option<usize>) -> String {
+        if !self.wrap || self.orientation == Orientation::Vertical {
+            return self.text.clone();
+        }
+        let Some(limit) = [self.width, max_width].into_iter().flatten().min() else {
+            return self.text.clone();
+        };
+        let limit = limit.saturating_sub(self.padding.left + self.padding.right);
+        if limit == 0 {
+            return self.text.clone();
+        }
+        wrap_text(&self.text, font, self.kerning, limit)
+    }
 }
 
 /// Builder for dot dithering over selected glyph targets.
@@ -423,6 +1637,7 @@ impl DotDitherBuilder {
             mode: crate::fill::DitherMode::Checker { period },
             dot: self.dots.0,
             alt: self.dots.1,
+            jitter: 0.0,
         };
         self.banner = self
             .banner
@@ -437,6 +1652,37 @@ impl DotDitherBuilder {
             mode: crate::fill::DitherMode::Noise { seed, threshold },
             dot: self.dots.0,
             alt: self.dots.1,
+            jitter: 0.0,
+        };
+        self.banner = self
+            .banner
+            .dot_dither(dither)
+            .dot_dither_targets(&self.targets);
+        self.banner
+    }
+
+    /// Apply a 4x4 Bayer ordered dither (`levels` of every 16 cells, 0..=16).
+    pub fn bayer(mut self, levels: u8) -> Banner {
+        let dither = Dither {
+            mode: crate::fill::DitherMode::Bayer { levels },
+            dot: self.dots.0,
+            alt: self.dots.1,
+            jitter: 0.0,
+        };
+        self.banner = self
+            .banner
+            .dot_dither(dither)
+            .dot_dither_targets(&self.targets);
+        self.banner
+    }
+
+    /// Apply a halftone whose dot density follows each cell's color luminance.
+    pub fn luminance(mut self, gamma: f32) -> Banner {
+        let dither = Dither {
+            mode: crate::fill::DitherMode::Luminance { gamma },
+            dot: self.dots.0,
+            alt: self.dots.1,
+            jitter: 0.0,
         };
         self.banner = self
             .banner
@@ -459,15 +1705,9 @@ fn apply_layout(
     width: Option<usize>,
     max_width: Option<usize>,
     align: Align,
+    pad_cell: Option<&Cell>,
 ) -> Grid {
-    let height = grid.height();
-    let width_now = grid.width();
-    let padded_width = width_now + padding.left + padding.right;
-    let padded_height = height + padding.top + padding.bottom;
-
-    let mut padded = Grid::new(padded_height, padded_width);
-    padded.blit(&grid, padding.top, padding.left);
-    grid = padded;
+    grid = pad_layout(&grid, padding, pad_cell);
 
     let mut target_width = width;
     if let Some(max_width) = max_width {
@@ -483,43 +1723,77 @@ fn apply_layout(
                 Align::Center => extra / 2,
                 Align::Right => extra,
             };
-            let right_extra = extra - left_extra;
-            let mut expanded = Grid::new(grid.height(), target);
-            expanded.blit(&grid, 0, left_extra);
-            if right_extra > 0 {
-                // already blank by default
-            }
-            grid = expanded;
+            let sides = Padding::from((0, extra - left_extra, 0, left_extra));
+            grid = pad_layout(&grid, sides, pad_cell);
         } else if target < grid.width() {
-            grid = clip_width(&grid, target, align);
+            let start = match align {
+                Align::Left => 0,
+                Align::Center => (grid.width() - target) / 2,
+                Align::Right => grid.width() - target,
+            };
+            grid = grid.crop(0, start, grid.height(), target);
         }
     }
 
     grid
 }
 
-fn clip_width(grid: &Grid, target: usize, align: Align) -> Grid {
-    if target == 0 {
-        return Grid::new(grid.height(), 0);
+fn append_caption(
+    grid: Grid,
+    caption: &str,
+    align: Align,
+    color: Option<Color>,
+    link: Option<&Arc<str>>,
+) -> Grid {
+    let mut chars: Vec<char> = caption.chars().collect();
+    let width = if grid.width() == 0 {
+        chars.len()
+    } else {
+        grid.width()
+    };
+    if chars.len() > width {
+        chars.truncate(width.saturating_sub(1));
+        if width > 0 {
+            chars.push('…');
+        }
     }
-
-    let start = match align {
+    let extra = width - chars.len();
+    let left = match align {
         Align::Left => 0,
-        Align::Center => (grid.width().saturating_sub(target)) / 2,
-        Align::Right => grid.width().saturating_sub(target),
+        Align::Center => extra / 2,
+        Align::Right => extra,
     };
 
-    let mut out = Grid::new(grid.height(), target);
-    for r in 0..grid.height() {
-        for c in 0..target {
-            if let (Some(cell), Some(target_cell)) = (grid.cell(r, start + c), out.cell_mut(r, c)) {
-                *target_cell = cell.clone();
-            }
+    let mut out = Grid::new(grid.height() + 1, width);
+    out.blit(&grid, 0, 0);
+    let row = grid.height();
+    for (idx, ch) in chars.into_iter().enumerate() {
+        if let Some(cell) = out.cell_mut(row, left + idx) {
+            cell.ch = ch;
+            cell.fg = color;
+            cell.visible = ch != ' ';
+            cell.link = link.cloned();
         }
     }
     out
 }
 
+// Visible padding must not bleed into blank content cells, so those are copied as is;
+// without it, blank content cells stay transparent as with `Grid::blit`.
+fn pad_layout(grid: &Grid, padding: Padding, pad_cell: Option<&Cell>) -> Grid {
+    match pad_cell {
+        Some(pad) => grid.pad_with(padding, pad),
+        None => {
+            let mut padded = Grid::new(
+                padding.top + grid.height() + padding.bottom,
+                padding.left + grid.width() + padding.right,
+            );
+            padded.blit(grid, padding.top, padding.left);
+            padded
+        }
+    }
+}
+
 fn apply_wave_breathe(grid: &Grid, phase: f32, dim_strength: f32, bright_strength: f32) -> Grid {
     let height = grid.height();
     let width = grid.width();
@@ -554,6 +1828,105 @@ fn apply_wave_breathe(grid: &Grid, phase: f32, dim_strength: f32, bright_strengt
     out
 }
 
+fn apply_scroll(grid: &Grid, width: usize, direction: ScrollDirection, gap: usize, t: f32) -> Grid {
+    let period = grid.width() + gap;
+    let mut out = Grid::new(grid.height(), width);
+    if period == 0 {
+        return out;
+    }
+    let step = (t.clamp(0.0, 1.0) * period as f32) as usize % period;
+    let offset = match direction {
+        ScrollDirection::Left => step,
+        ScrollDirection::Right => (period - step) % period,
+    };
+    for (r, row) in grid.rows().iter().enumerate() {
+        for c in 0..width {
+            if let (Some(cell), Some(target)) = (row.get((offset + c) % period), out.cell_mut(r, c))
+            {
+                *target = cell.clone();
+            }
+        }
+    }
+    out
+}
+
+fn apply_reveal(grid: &Grid, reveal: Reveal, t: f32) -> Grid {
+    let width = grid.width();
+    let shown = (t * width as f32).ceil() as usize;
+    let hidden = width - shown.min(width);
+    let (start, end) = match reveal {
+        Reveal::LeftToRight => (0, width - hidden),
+        Reveal::RightToLeft => (hidden, width),
+        Reveal::CenterOut => (hidden / 2, width - (hidden - hidden / 2)),
+    };
+
+    let mut out = grid.clone();
+    for row in out.rows_mut() {
+        for (c, cell) in row.iter_mut().enumerate() {
+            if c < start || c >= end {
+                cell.ch = ' ';
+                cell.fg = None;
+                cell.bg = None;
+                cell.visible = false;
+            }
+        }
+    }
+    out
+}
+
+/// Progress at which each cell locks into place: per-column random delays, then top to
+/// bottom, always before `0.8` so the end of a pass shows the finished banner.
+fn matrix_settle_times(grid: &Grid, seed: u32) -> Vec<Vec<f32>> {
+    let height = grid.height().max(1) as f32;
+    let delays: Vec<f32> = (0..grid.width())
+        .map(|c| (mix(seed, c as u32, 0) & 0xFFFF) as f32 / 65536.0 * 0.4)
+        .collect();
+    (0..grid.height())
+        .map(|r| {
+            let fall = (r + 1) as f32 / height * 0.39;
+            delays.iter().map(|delay| delay + fall).collect()
+        })
+        .collect()
+}
+
+/// Show settled cells as-is and green rain trails over the rest.
+fn apply_matrix_rain(
+    grid: &Grid,
+    settle: &[Vec<f32>],
+    seed: u32,
+    charset: &[char],
+    t: f32,
+) -> Grid {
+    const TRAIL: usize = 6;
+    let cycle = grid.height() + TRAIL;
+    let tick = (t * 1000.0) as u32;
+    let mut out = grid.clone();
+    out.map_cells(|r, c, cell| {
+        if t >= settle[r][c] {
+            return;
+        }
+        // Each column's drop falls twice per pass from its own starting offset.
+        let offset = mix(seed, c as u32, 1) as usize % cycle;
+        let head = ((t * 2.0 * cycle as f32) as usize + offset) % cycle;
+        let behind = (head + cycle - r) % cycle;
+        cell.ch = ' ';
+        cell.fg = None;
+        cell.visible = false;
+        cell.link = None;
+        if behind < TRAIL {
+            let pick = mix(seed ^ tick, r as u32, c as u32) as usize % charset.len();
+            cell.ch = charset[pick];
+            cell.visible = true;
+            cell.fg = Some(if behind == 0 {
+                Color::Rgb(200, 255, 200)
+            } else {
+                Color::Rgb(0, 255, 70).darken(behind as f32 / TRAIL as f32 * 0.8)
+            });
+        }
+    });
+    out
+}
+
 fn apply_roll(grid: &Grid, t: f32) -> Grid {
     let height = grid.height();
     let width = grid.width();
@@ -632,6 +2005,82 @@ fn apply_roll(grid: &Grid, t: f32) -> Grid {
     out
 }
 
+fn apply_blink_dim(grid: &Grid, brightness: f32, blank: bool) -> Grid {
+    let mut out = grid.clone();
+    let amount = 1.0 - brightness.clamp(0.0, 1.0);
+    out.map_cells(|_, _, cell| {
+        if blank {
+            cell.ch = ' ';
+            cell.fg = None;
+            cell.visible = false;
+        } else {
+            cell.fg = cell.fg.map(|fg| fg.darken(amount));
+        }
+        cell.bg = cell.bg.map(|bg| bg.darken(amount));
+    });
+    out
+}
+
+/// Render text with every glyph span painted in its own color along `gradient`.
+fn render_per_glyph(text: &str, font: &Font, layout: &TextLayout, gradient: &Gradient) -> Grid {
+    let (mut grid, spans) = render_text_spans(text, font, layout);
+    let glyphs: Vec<_> = spans
+        .iter()
+        .filter(|span| !span.ch.is_whitespace())
+        .collect();
+    let last = glyphs.len().saturating_sub(1).max(1) as f32;
+    for (idx, span) in glyphs.into_iter().enumerate() {
+        let color = gradient.sample(idx as f32 / last);
+        for row in &mut grid.rows_mut()[span.rows.clone()] {
+            for cell in &mut row[span.cols.clone()] {
+                cell.fg = Some(color);
+            }
+        }
+    }
+    grid
+}
+
+fn apply_color_filter(grid: &mut Grid, grayscale: bool, tint: Option<Color>) {
+    let filter = |mut color: Color| {
+        if grayscale {
+            color = color.grayscale();
+        }
+        if let Some(tint) = tint {
+            color = color.tint(tint);
+        }
+        color
+    };
+    grid.map_cells(|_, _, cell| {
+        cell.fg = cell.fg.map(filter);
+        cell.bg = cell.bg.map(filter);
+    });
+}
+
+/// Blend every visible foreground from black towards its color by `level`.
+fn apply_fade(grid: &Grid, level: f32) -> Grid {
+    let mut out = grid.clone();
+    // Leave full-level frames untouched so they match the static render exactly.
+    if level >= 1.0 {
+        return out;
+    }
+    out.map_cells(|_, _, cell| {
+        if cell.visible {
+            cell.fg = cell.fg.map(|fg| Color::Rgb(0, 0, 0).lerp(fg, level));
+        }
+    });
+    out
+}
+
+fn apply_hue_shift(grid: &Grid, degrees: f32) -> Grid {
+    let mut out = grid.clone();
+    out.map_cells(|_, _, cell| {
+        if cell.visible {
+            cell.fg = cell.fg.map(|fg| fg.rotate_hue(degrees));
+        }
+    });
+    out
+}
+
 fn scale_wave(phase: f32, row: usize, col: usize, width: usize, height: usize) -> f32 {
     let fx = if width > 1 {
         col as f32 / (width - 1) as f32
@@ -662,3 +2111,898 @@ fn apply_breathe_color(color: Color, dim: f32, bright: f32) -> Color {
         dimmed
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::FrameStyle;
+    use crate::gradient::GradientMask;
+
+    #[test]
+    fn strict_rejects_the_fallback_font_only() {
+        let bundled = Banner::new("HI").unwrap();
+        assert!(bundled.font_fallback.is_none());
+        assert!(bundled.strict(true).is_ok());
+
+        let broken = || Err(font::figlet::FigletError::InvalidHeader);
+        let fallback = Banner::with_bundled_font("HI", broken).color_mode(ColorMode::NoColor);
+        assert_eq!(
+            fallback.render(),
+            Banner::new_with("HI", Font::minimal())
+                .color_mode(ColorMode::NoColor)
+                .render()
+        );
+        assert!(fallback.clone().strict(false).is_ok());
+        assert!(matches!(
+            fallback.clone().strict(true),
+            Err(BannerError::Font(font::figlet::FigletError::InvalidHeader))
+        ));
+        assert!(fallback.font(Font::minimal()).strict(true).is_ok());
+    }
+
+    #[test]
+    fn measure_accounts_for_padding_and_frame() {
+        let banner = Banner::new_with("HI", Font::minimal()).color_mode(ColorMode::NoColor);
+        let (width, height) = banner.measure();
+        assert_eq!((width, height), (7, 5));
+
+        let inner = Frame::new(FrameStyle::Single).inner_padding(1);
+        assert_eq!(
+            banner.clone().frame(inner).measure(),
+            (width + 4, height + 4)
+        );
+
+        let framed = banner.padding(1).frame(Frame::new(FrameStyle::Single));
+        assert_eq!(framed.measure(), (width + 4, height + 4));
+
+        let rendered = framed.render();
+        assert_eq!(rendered.lines().count(), height + 4);
+        assert!(
+            rendered
+                .lines()
+                .all(|line| line.chars().count() == width + 4)
+        );
+    }
+
+    #[cfg(feature = "ratatui")]
+    #[test]
+    fn paragraph_has_one_line_per_row() {
+        use ratatui::buffer::Buffer;
+        use ratatui::layout::Rect;
+        use ratatui::widgets::Widget;
+
+        let banner = Banner::new_with("HI", Font::minimal())
+            .color_mode(ColorMode::TrueColor)
+            .frame(Frame::new(FrameStyle::Single));
+        let (width, height) = banner.measure();
+        let area = Rect::new(0, 0, width as u16, height as u16 + 1);
+        let mut buffer = Buffer::empty(area);
+        banner.to_paragraph().render(area, &mut buffer);
+
+        assert_eq!(buffer[(0, height as u16 - 1)].symbol(), "└");
+        assert_eq!(buffer[(0, height as u16)].symbol(), " ");
+    }
+
+    #[cfg(feature = "ratatui")]
+    #[test]
+    fn ratatui_text_coalesces_color_runs() {
+        let text = Banner::new_with("I", Font::minimal())
+            .fill(Fill::Keep)
+            .segment("I", Color::Rgb(255, 0, 0))
+            .color_mode(ColorMode::TrueColor)
+            .to_ratatui_text();
+
+        let top = &text.lines[0];
+        assert_eq!(top.spans.len(), 1);
+        assert_eq!(top.spans[0].content, "███");
+        assert_eq!(
+            top.spans[0].style.fg,
+            Some(ratatui::style::Color::Rgb(255, 0, 0))
+        );
+    }
+
+    #[test]
+    fn cast_has_header_and_one_event_per_frame() {
+        let banner = Banner::new_with("HI", Font::minimal()).color_mode(ColorMode::TrueColor);
+        let cast = banner.record_cast(Animation::Roll, 50, &AnimationOptions::new().frames(4));
+        let lines: Vec<&str> = cast.lines().collect();
+
+        assert_eq!(lines[0], "{\"version\": 2, \"width\": 7, \"height\": 5}");
+        assert_eq!(lines.len(), 5);
+        assert!(lines[1].starts_with("[0.000000, \"o\", \"\\u001b[2J"));
+        assert!(lines[4].starts_with("[0.150000, \"o\", "));
+    }
+
+    #[test]
+    fn display_and_render_to_match_render() {
+        let banner = Banner::new_with("HI", Font::minimal()).color_mode(ColorMode::TrueColor);
+        let mut written = Vec::new();
+        banner.render_to(&mut written).unwrap();
+
+        assert_eq!(banner.to_string(), banner.render());
+        assert_eq!(String::from_utf8(written).unwrap(), banner.render());
+    }
+
+    #[test]
+    fn trimmed_glyphs_keep_word_gap() {
+        let banner = Banner::new_with("A B", Font::dos_rebel().unwrap())
+            .kerning(0)
+            .trim_glyphs(true)
+            .trim_vertical(true)
+            .color_mode(ColorMode::NoColor);
+        let expected = [
+            "   #########      ########### ",
+            "  ###########    #############",
+            " ####    ####     ####    ####",
+            " ############     ########### ",
+            " ############     ############",
+            " ####    ####     ####    ####",
+            " #####   #####    ########### ",
+            "#####   #####    ###########  ",
+        ];
+        assert_eq!(banner.render(), expected.join("\n"));
+
+        // The trimmed font follows later font changes and is dropped when disabled.
+        let swapped = Banner::new_with("A B", Font::minimal())
+            .trim_glyphs(true)
+            .font(Font::dos_rebel().unwrap())
+            .kerning(0)
+            .trim_vertical(true)
+            .color_mode(ColorMode::NoColor);
+        assert_eq!(swapped.render(), banner.render());
+        assert!(swapped.trim_glyphs(false).trimmed_font.is_none());
+    }
+
+    #[test]
+    fn padding_char_fills_only_padding_cells() {
+        let color = Color::Rgb(90, 90, 90);
+        let plain = Banner::new_with("I", Font::minimal())
+            .color_mode(ColorMode::NoColor)
+            .render_grid_with_sweep(None, None);
+        let grid = Banner::new_with("I", Font::minimal())
+            .padding(Padding::uniform(1))
+            .padding_char(Some('·'))
+            .padding_color(color)
+            .color_mode(ColorMode::NoColor)
+            .render_grid_with_sweep(None, None);
+
+        assert_eq!(grid.height(), plain.height() + 2);
+        for (r, row) in grid.rows().iter().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                let inner = r >= 1 && r <= plain.height() && c >= 1 && c <= plain.width();
+                if inner {
+                    assert_eq!(cell.ch, plain.cell(r - 1, c - 1).unwrap().ch);
+                } else {
+                    assert_eq!(cell.ch, '·');
+                    assert_eq!(cell.fg, Some(color));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn frame_at_is_deterministic_and_free_of_cursor_codes() {
+        let banner = Banner::new_with("HI", Font::minimal())
+            .gradient(Gradient::horizontal(Palette::from_hex(&[
+                "#ff0000", "#0000ff",
+            ])))
+            .color_mode(ColorMode::TrueColor);
+
+        for frame in [
+            banner.render_sweep_at(0.5),
+            banner.render_wave_at(std::f32::consts::PI),
+            banner.render_roll_at(0.5),
+        ] {
+            assert!(!frame.contains("\x1b[H") && !frame.contains("\x1b[?25"));
+        }
+        assert_eq!(banner.render_roll_at(0.5), banner.render_roll_at(0.5));
+        assert_ne!(banner.render_sweep_at(0.0), banner.render_sweep_at(0.5));
+        assert_ne!(banner.render_wave_at(0.0), banner.render_wave_at(1.0));
+    }
+
+    #[test]
+    fn messages_are_joined_with_separator_glyphs() {
+        let font = Font::minimal();
+        let messages = vec!["AB".to_string(), "CD".to_string(), "EF".to_string()];
+        let grid = Banner::new_with("", font.clone())
+            .messages(messages.clone(), '-')
+            .fill(Fill::Keep)
+            .color_mode(ColorMode::NoColor)
+            .render_grid_with_sweep(None, None);
+
+        let message_width: usize = messages.iter().map(|m| font.measure(m, 1)).sum();
+        let separator_width = font.measure(" - ", 1);
+        assert_eq!(grid.width(), message_width + 2 * separator_width + 4);
+
+        let separator = Banner::new_with(" - ", font.clone())
+            .fill(Fill::Keep)
+            .render_grid_with_sweep(None, None);
+        assert!(separator.rows().iter().flatten().any(|cell| cell.visible));
+        let mut start = font.measure("AB", 1) + 1;
+        for _ in 0..2 {
+            for (r, row) in separator.rows().iter().enumerate() {
+                for (c, cell) in row.iter().enumerate() {
+                    assert_eq!(grid.cell(r, start + c).unwrap().ch, cell.ch);
+                }
+            }
+            start += separator_width + 1 + font.measure("CD", 1) + 1;
+        }
+    }
+
+    #[test]
+    fn caption_is_truncated_and_framed_with_glyphs() {
+        let banner = Banner::new_with("HI", Font::minimal())
+            .caption("a very long tagline")
+            .caption_color(Color::Rgb(200, 200, 200))
+            .frame(Frame::new(crate::frame::FrameStyle::Single));
+        let glyphs = Banner::new_with("HI", Font::minimal()).measure();
+        assert_eq!(banner.measure(), (glyphs.0 + 2, glyphs.1 + 3));
+
+        let tagged = banner.render_tagged();
+        let rows: Vec<&str> = tagged.lines().collect();
+        assert_eq!(
+            rows[glyphs.1 + 1],
+            "│{fg=C8C8C8}a{/fg} {fg=C8C8C8}very…{/fg}│"
+        );
+    }
+
+    #[test]
+    fn reveal_grows_from_the_requested_side() {
+        let grid = Grid::from_char_rows(vec![vec!['#'; 6]]);
+        let shown = |reveal, t| -> String {
+            apply_reveal(&grid, reveal, t).rows()[0]
+                .iter()
+                .map(|cell| cell.ch)
+                .collect()
+        };
+
+        assert_eq!(shown(Reveal::LeftToRight, 0.0), "      ");
+        assert_eq!(shown(Reveal::LeftToRight, 0.5), "###   ");
+        assert_eq!(shown(Reveal::RightToLeft, 0.5), "   ###");
+        assert_eq!(shown(Reveal::CenterOut, 0.3), "  ##  ");
+        assert_eq!(shown(Reveal::CenterOut, 1.0), "######");
+    }
+
+    #[test]
+    fn scroll_wraps_with_a_blank_seam() {
+        let grid = Grid::from_char_rows(vec!["ABCD".chars().collect()]);
+        let window = |direction, t| -> String {
+            apply_scroll(&grid, 5, direction, 2, t).rows()[0]
+                .iter()
+                .map(|cell| cell.ch)
+                .collect()
+        };
+
+        assert_eq!(window(ScrollDirection::Left, 0.0), "ABCD ");
+        assert_eq!(window(ScrollDirection::Left, 0.5), "D  AB");
+        assert_eq!(window(ScrollDirection::Right, 1.0 / 6.0), " ABCD");
+    }
+
+    #[test]
+    fn numeric_field_keeps_width_as_digits_grow() {
+        let counter = Banner::new_with("9", Font::minimal())
+            .numeric(true)
+            .digits(3);
+        let nine = counter.render_glyphs(None);
+        let ten = counter.clone_with_text("10").render_glyphs(None);
+
+        assert_eq!(nine.width(), ten.width());
+        assert!(nine.rows().iter().all(|row| !row[0].visible));
+        assert_eq!(
+            counter
+                .zero_pad(true)
+                .clone_with_text("10")
+                .render_glyphs(None)
+                .width(),
+            ten.width()
+        );
+    }
+
+    #[test]
+    fn render_plain_ignores_color_mode() {
+        let banner = Banner::new_with("HI", Font::minimal())
+            .gradient(Gradient::vertical(Palette::from_hex(&[
+                "#ff0000", "#0000ff",
+            ])))
+            .padding((0, 2, 0, 0))
+            .color_mode(ColorMode::TrueColor);
+
+        let plain = banner.render_plain(false);
+        assert!(!plain.contains('\x1b'));
+        assert_eq!(
+            plain,
+            banner.clone().color_mode(ColorMode::NoColor).render()
+        );
+        assert!(
+            banner
+                .render_plain(true)
+                .lines()
+                .all(|line| !line.ends_with(' '))
+        );
+    }
+
+    #[test]
+    fn background_fills_blank_and_padding_cells() {
+        let grid = Banner::new("I")
+            .unwrap()
+            .padding(1)
+            .background_color(Color::Rgb(0, 0, 80))
+            .render_grid_with_sweep(None, None);
+
+        assert!(grid.rows().iter().flatten().any(|cell| !cell.visible));
+        assert!(
+            grid.rows()
+                .iter()
+                .flatten()
+                .all(|cell| cell.bg == Some(Color::Rgb(0, 0, 80)))
+        );
+        assert!(
+            !Banner::new("I")
+                .unwrap()
+                .background_color(Color::Rgb(0, 0, 80))
+                .color_mode(ColorMode::NoColor)
+                .render()
+                .contains('\x1b')
+        );
+    }
+
+    #[test]
+    fn later_gradient_owns_the_seam_row() {
+        let top = Color::Rgb(255, 0, 0);
+        let bottom = Color::Rgb(0, 0, 255);
+        let banner = Banner::new("I").unwrap().fill(Fill::Solid('#'));
+        let height = banner.render_glyphs(None).height();
+        let seam = height / 2;
+        let banner = banner.gradients(vec![
+            Gradient::new(vec![top], GradientDirection::Horizontal)
+                .masked(GradientMask::Rows(0..seam + 1)),
+            Gradient::new(vec![bottom], GradientDirection::Horizontal)
+                .masked(GradientMask::Rows(seam..height)),
+        ]);
+        let mut grid = banner.render_glyphs(None);
+        for table in banner.gradient_tables(&grid) {
+            table.apply(&mut grid, 0.0);
+        }
+
+        let row_colors = |r: usize| -> Vec<Option<Color>> {
+            grid.rows()[r]
+                .iter()
+                .filter(|cell| cell.visible)
+                .map(|cell| cell.fg)
+                .collect()
+        };
+        assert!(row_colors(0).iter().all(|fg| *fg == Some(top)));
+        assert!(!row_colors(seam).is_empty());
+        assert!(row_colors(seam).iter().all(|fg| *fg == Some(bottom)));
+    }
+
+    #[test]
+    fn gradient_animation_shifts_colors_and_wraps() {
+        let banner = Banner::new("HI")
+            .unwrap()
+            .gradient(Gradient::horizontal(Palette::from_hex(&[
+                "#ff0000", "#0000ff",
+            ])))
+            .color_mode(ColorMode::TrueColor);
+        let render = banner.frame_renderer(Animation::Gradient { cycles: 2.0 });
+        let fgs = |grid: Grid| -> Vec<Option<Color>> {
+            grid.rows().iter().flatten().map(|cell| cell.fg).collect()
+        };
+
+        let still = fgs(banner.render_grid_with_sweep(None, None));
+        assert_eq!(fgs(render(0.0)), still);
+        assert_ne!(fgs(render(0.2)), still);
+        assert_eq!(fgs(render(0.5)), still);
+    }
+
+    #[test]
+    fn sweep_brightens_palette_colors() {
+        let base = Color::Ansi256(45);
+        let banner = Banner::new("HI")
+            .unwrap()
+            .gradient(Gradient::new(vec![base], GradientDirection::Horizontal))
+            .color_mode(ColorMode::Ansi256);
+        let frame = banner.frame_renderer(Animation::Sweep { highlight: None })(0.5);
+
+        let brightened = frame
+            .rows()
+            .iter()
+            .flatten()
+            .filter_map(|cell| cell.fg)
+            .filter(|fg| fg.luminance() > base.luminance() + 0.05)
+            .count();
+        assert!(brightened > 0);
+    }
+
+    #[test]
+    fn hue_frames_cycle_foreground_colors() {
+        let banner = Banner::new("HI")
+            .unwrap()
+            .gradient(Gradient::new(
+                vec![Color::Rgb(255, 0, 0)],
+                GradientDirection::Horizontal,
+            ))
+            .caption("v1")
+            .color_mode(ColorMode::TrueColor);
+        let frames: Vec<String> = banner
+            .frames(Animation::Hue, &AnimationOptions::new().frames(3))
+            .collect();
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0], banner.render());
+        assert_eq!(frames[1], banner.render_hue_at(1.0 / 3.0));
+        assert!(frames[1].contains("\x1b[38;2;0;255;0m"));
+        assert!(frames[2].contains("\x1b[38;2;0;0;255m"));
+        assert!(!frames[1].contains("\x1b[38;2;255;0;0m"));
+        assert!(frames[1].contains("v1"));
+    }
+
+    #[test]
+    fn frame_iterators_match_static_renders() {
+        let sweep = LightSweep::new(SweepDirection::Horizontal).center(0.5);
+        let banner = Banner::new("HI")
+            .unwrap()
+            .style(Style::NeonCyber)
+            .light_sweep(sweep)
+            .color_mode(ColorMode::TrueColor);
+
+        assert_eq!(banner.sweep_frames(12).count(), 12);
+        assert_eq!(banner.wave_frames(5, None, None).grids().count(), 5);
+        assert_eq!(banner.roll_frames(7).size_hint(), (7, Some(7)));
+
+        let start = banner.clone().light_sweep(sweep.center(-0.25)).render();
+        assert_eq!(banner.sweep_frames(12).next().unwrap(), start);
+        let mut frames = banner.sweep_frames(4).phase_range(0.5..1.0);
+        assert_eq!(frames.next().unwrap(), banner.render_sweep_at(0.5));
+        assert_eq!(frames.count(), 3);
+    }
+
+    #[test]
+    fn animate_to_redraws_in_place_without_clearing() {
+        let banner = Banner::new("HI").unwrap().color_mode(ColorMode::NoColor);
+        let frames: Vec<String> = banner.roll_frames(2).collect();
+        let height = frames[0].lines().count();
+        let options = AnimationOptions::new().frames(2).in_place(true);
+
+        let mut out = Vec::new();
+        banner.animate_roll_to(&mut out, 0, &options).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let rewind = format!("\r\x1b[{}A\x1b[J", height - 1);
+        assert_eq!(
+            out,
+            format!(
+                "\x1b[?25l{}{rewind}{}\x1b[?25h\x1b[0m\n",
+                frames[0], frames[1]
+            )
+        );
+
+        let mut alt = Vec::new();
+        let options = options.in_place(false).alternate_screen(true);
+        banner.animate_roll_to(&mut alt, 0, &options).unwrap();
+        let alt = String::from_utf8(alt).unwrap();
+        assert!(alt.starts_with("\x1b[?1049h\x1b[?25l\x1b[2J\x1b[H"));
+        assert!(alt.ends_with("\x1b[?1049l"));
+    }
+
+    #[test]
+    fn stop_flag_ends_an_endless_animation() {
+        let banner = Banner::new("HI").unwrap().color_mode(ColorMode::NoColor);
+        let stop = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let options = AnimationOptions::new()
+            .frames(4)
+            .repeat(0)
+            .stop_flag(Arc::clone(&stop));
+        let setter = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            stop.store(true, Ordering::SeqCst);
+        });
+
+        let mut out = Vec::new();
+        banner.animate_roll_to(&mut out, 5, &options).unwrap();
+        setter.join().unwrap();
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .ends_with("\x1b[?25h\x1b[0m\n")
+        );
+    }
+
+    #[test]
+    fn glitch_is_deterministic_and_animation_has_clean_frames() {
+        let banner = Banner::new("HI")
+            .unwrap()
+            .style(Style::NeonCyber)
+            .color_mode(ColorMode::TrueColor);
+        let glitched = banner.clone().glitch(GlitchConfig::new(42, 0.9));
+        assert_eq!(glitched.render(), glitched.render());
+        assert_ne!(glitched.render(), banner.render());
+
+        let frames: Vec<String> = banner
+            .frames(
+                Animation::Glitch { intensity: 0.9 },
+                &AnimationOptions::new().frames(24),
+            )
+            .collect();
+        let clean = frames.iter().filter(|frame| **frame == banner.render());
+        assert_eq!(clean.count(), 16);
+        assert_ne!(frames[2], frames[5]);
+    }
+
+    #[test]
+    fn matrix_reveal_rains_then_settles_on_the_banner() {
+        let banner = Banner::new("HI")
+            .unwrap()
+            .style(Style::Matrix)
+            .color_mode(ColorMode::TrueColor);
+        let frames: Vec<String> = banner.matrix_frames(20, 9).collect();
+
+        assert_eq!(frames.len(), 20);
+        assert_eq!(frames, banner.matrix_frames(20, 9).collect::<Vec<_>>());
+        assert_ne!(frames, banner.matrix_frames(20, 10).collect::<Vec<_>>());
+        assert_ne!(frames[0], banner.render());
+        assert!(frames[0].contains("\x1b[38;2;200;255;200m"));
+        assert!(frames[16..].iter().all(|frame| *frame == banner.render()));
+
+        for count in [1, 3, 4] {
+            let short: Vec<String> = banner.matrix_frames(count, 9).collect();
+            assert_eq!(short.len(), count);
+            assert_eq!(short[count - 1], banner.render(), "{count} frames");
+        }
+    }
+
+    #[test]
+    fn fade_in_ends_on_the_static_render() {
+        let banner = Banner::new("HI")
+            .unwrap()
+            .style(Style::FireWarning)
+            .color_mode(ColorMode::Ansi256);
+        let fade_in: Vec<String> = banner.fade_frames(10, FadeDirection::In).collect();
+
+        assert_eq!(fade_in.len(), 10);
+        assert_eq!(fade_in[9], banner.render());
+        assert_ne!(fade_in[0], banner.render());
+
+        let fade_out: Vec<String> = banner.fade_frames(10, FadeDirection::Out).collect();
+        assert_eq!(fade_out[0], banner.render());
+        assert_eq!(fade_out[9], fade_in[0]);
+        let in_out: Vec<String> = banner.fade_frames(10, FadeDirection::InOut).collect();
+        assert_eq!(in_out[5], banner.render());
+
+        for frames in 1..=4 {
+            let short: Vec<String> = banner.fade_frames(frames, FadeDirection::In).collect();
+            assert_eq!(short.len(), frames);
+            assert_eq!(short[frames - 1], banner.render(), "{frames} frames");
+        }
+        let fade_in = Animation::Fade {
+            direction: FadeDirection::In,
+        };
+        let bounced = AnimationOptions::new().frames(4).ping_pong(true);
+        let bounced: Vec<String> = banner.frames(fade_in, &bounced).collect();
+        assert_eq!(bounced.last(), Some(&banner.render()));
+    }
+
+    #[test]
+    fn blink_alternates_full_and_dimmed_frames() {
+        let banner = Banner::new("HI")
+            .unwrap()
+            .style(Style::FireWarning)
+            .color_mode(ColorMode::TrueColor);
+        let blink = Animation::Blink {
+            on: 3,
+            off: 1,
+            brightness: 0.25,
+        };
+        let frames: Vec<String> = banner
+            .frames(blink, &AnimationOptions::new().frames(4).repeat(2))
+            .collect();
+
+        assert_eq!(frames.len(), 8);
+        let full = banner.render();
+        let on: Vec<bool> = frames.iter().map(|frame| *frame == full).collect();
+        assert_eq!(on, [true, true, true, false, true, true, true, false]);
+        assert!(frames[3].contains("\x1b[38;2;"));
+
+        let plain = banner.color_mode(ColorMode::NoColor);
+        let off = plain
+            .frames(blink, &AnimationOptions::new().frames(4))
+            .last();
+        assert!(off.unwrap().trim().is_empty());
+    }
+
+    #[test]
+    fn auto_contrast_lifts_glyphs_off_the_background() {
+        let bg = Color::Rgb(255, 255, 255);
+        let build = |enabled: bool| {
+            Banner::new("HI")
+                .unwrap()
+                .gradient(Gradient::horizontal(Palette::from_hex(&[
+                    "#FFF7D6", "#E6F6FF",
+                ])))
+                .background_color(bg)
+                .auto_contrast(enabled)
+                .color_mode(ColorMode::TrueColor)
+        };
+        let min_ratio = |banner: &Banner| {
+            banner
+                .render_grid_with_sweep(None, None)
+                .rows()
+                .iter()
+                .flatten()
+                .filter(|cell| cell.visible)
+                .filter_map(|cell| cell.fg)
+                .map(|fg| fg.contrast_ratio(bg))
+                .fold(f32::MAX, f32::min)
+        };
+        assert!(min_ratio(&build(false)) < 3.0);
+        assert!(min_ratio(&build(true)) >= 3.0);
+    }
+
+    #[test]
+    fn canvas_tints_blank_cells_inside_the_frame() {
+        let canvas = Color::Rgb(20, 12, 0);
+        let grid = Banner::new("I")
+            .unwrap()
+            .padding(1)
+            .trim_vertical(true)
+            .canvas_color(canvas)
+            .frame(Frame::new(FrameStyle::Single))
+            .render_grid_with_sweep(None, None);
+
+        let (height, width) = (grid.height(), grid.width());
+        for (r, row) in grid.rows().iter().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                let border = r == 0 || c == 0 || r + 1 == height || c + 1 == width;
+                let expected = (!border && !cell.visible).then_some(canvas);
+                assert_eq!(cell.bg, expected, "cell ({r}, {c})");
+            }
+        }
+
+        let glow = Banner::new("I")
+            .unwrap()
+            .padding(1)
+            .canvas_color(canvas)
+            .canvas_fill(CanvasFill::Char('░'))
+            .render_grid_with_sweep(None, None);
+        assert!(
+            glow.rows()
+                .iter()
+                .flatten()
+                .all(|cell| cell.visible || cell.ch == '░')
+        );
+    }
+
+    #[test]
+    fn grayscale_and_tint_cover_frames_and_shadows() {
+        let base = || {
+            Banner::new("HI")
+                .unwrap()
+                .style(Style::NeonCyber)
+                .shadow((1, 1), 0.5)
+                .frame(Frame::new(FrameStyle::Single).color(Color::Rgb(255, 0, 0)))
+        };
+        let colors = |banner: Banner| -> Vec<Color> {
+            banner
+                .render_grid_with_sweep(None, None)
+                .rows()
+                .iter()
+                .flatten()
+                .filter_map(|cell| cell.fg)
+                .collect()
+        };
+
+        let gray = colors(base().grayscale(true));
+        assert!(gray.contains(&Color::Rgb(127, 127, 127)));
+        assert!(gray.iter().all(|color| {
+            let (r, g, b) = color.rgb_components();
+            r == g && g == b
+        }));
+
+        let amber = Color::Rgb(255, 176, 0);
+        let (amber_hue, _, _) = amber.to_hsl();
+        let tinted = colors(base().tint(amber));
+        assert!(tinted.len() > 10);
+        assert!(tinted.iter().all(|color| {
+            let (h, s, l) = color.to_hsl();
+            s == 0.0 || l <= 0.01 || l >= 0.99 || (h - amber_hue).abs() < 3.0
+        }));
+    }
+
+    #[test]
+    fn compact_output_shrinks_padded_banners() {
+        let banner = Banner::new("HI")
+            .unwrap()
+            .gradient(Gradient::vertical(Palette::from_hex(&[
+                "#ff0000", "#0000ff",
+            ])))
+            .padding(Padding::uniform(4))
+            .color_mode(ColorMode::TrueColor);
+        let full = banner.render();
+        let compact = banner.clone().compact_output(true).render();
+
+        assert_eq!(full.len(), 1014);
+        assert_eq!(compact.len(), 657);
+        let mut visible = String::new();
+        let mut chars = compact.chars();
+        while let Some(ch) = chars.next() {
+            if ch == '\x1b' {
+                chars.by_ref().find(|&c| c == 'm');
+            } else {
+                visible.push(ch);
+            }
+        }
+        assert_eq!(visible, banner.render_plain(true));
+    }
+
+    #[test]
+    fn render_parts_match_in_visible_text() {
+        let banner = Banner::new("HI")
+            .unwrap()
+            .gradient(Gradient::vertical(Palette::from_hex(&[
+                "#ff0000", "#0000ff",
+            ])))
+            .color_mode(ColorMode::TrueColor);
+        let parts = banner.render_parts();
+
+        assert_eq!(parts.ansi, banner.render());
+        assert_eq!(parts.plain, crate::emit::strip_ansi(&parts.ansi));
+        assert_eq!(parts.plain.lines().count(), parts.height);
+        assert_eq!((parts.width, parts.height), banner.measure());
+    }
+
+    #[test]
+    fn render_lines_has_one_entry_per_rendered_row() {
+        let banner = Banner::new_with("HI", Font::minimal())
+            .padding(1)
+            .frame(Frame::new(FrameStyle::Single))
+            .color_mode(ColorMode::NoColor);
+        let lines = banner.render_lines();
+
+        assert_eq!(lines.len(), banner.measure().1);
+        assert_eq!(lines, banner.render().lines().collect::<Vec<_>>());
+        assert!(lines.iter().all(|line| !line.contains('\x1b')));
+    }
+
+    #[test]
+    fn render_lines_stand_alone() {
+        let banner = Banner::new("HI")
+            .unwrap()
+            .gradient(Gradient::horizontal(Palette::from_hex(&[
+                "#ff0000", "#0000ff",
+            ])))
+            .background_color(Color::Rgb(0, 0, 40))
+            .color_mode(ColorMode::TrueColor);
+        let lines = banner.render_lines();
+        let plain = banner.render_plain(false);
+
+        assert_eq!(lines.len(), banner.rendered_height());
+        for (line, plain_line) in lines.iter().zip(plain.lines()) {
+            assert!(line.starts_with("\x1b["), "{line:?}");
+            assert!(line.ends_with("\x1b[0m"), "{line:?}");
+            assert_eq!(crate::emit::strip_ansi(line), plain_line);
+            assert_eq!(plain_line.chars().count(), banner.rendered_width());
+        }
+        assert_eq!(lines.join("\n"), banner.render());
+
+        // Compact output drops the per-line resets but keeps the same visible text.
+        let compact = banner.clone().compact_output(true).render();
+        assert_eq!(crate::emit::strip_ansi(&compact), plain);
+    }
+
+    #[test]
+    fn per_glyph_gradient_colors_whole_glyphs() {
+        let palette = Palette::from_hex(&["#ff0000", "#00ff00", "#0000ff"]);
+        let banner = Banner::new("AB C")
+            .unwrap()
+            .kerning(2)
+            .padding(Padding::uniform(0))
+            .per_glyph_gradient(palette)
+            .gradient(Gradient::vertical(Palette::from_hex(&["#ffffff"])));
+        let grid = banner.render_grid_with_sweep(None, None);
+
+        let mut colors: Vec<Color> = Vec::new();
+        for cell in grid.rows().iter().flatten().filter(|cell| cell.visible) {
+            let color = cell.fg.unwrap();
+            if !colors.contains(&color) {
+                colors.push(color);
+            }
+        }
+        colors.sort_by_key(|color| color.rgb_components());
+        assert_eq!(
+            colors,
+            [
+                Color::Rgb(0, 0, 255),
+                Color::Rgb(0, 255, 0),
+                Color::Rgb(255, 0, 0)
+            ]
+        );
+
+        // The kerning gap after `A` keeps `A`'s color.
+        let font = banner.layout_font();
+        let a_width = font.glyph('A').width();
+        let glyphs = banner.render_glyphs(None);
+        let gap = &glyphs.rows()[0][a_width];
+        assert_eq!(gap.fg, Some(Color::Rgb(255, 0, 0)));
+    }
+
+    #[test]
+    fn negative_kerning_tightens_without_losing_cells() {
+        let render = |kerning| {
+            Banner::new("WAVE")
+                .unwrap()
+                .kerning_signed(kerning)
+                .padding(0)
+                .color_mode(ColorMode::NoColor)
+                .render()
+        };
+        let inked = |text: &str| text.chars().filter(|ch| !ch.is_whitespace()).count();
+        let width = |text: &str| text.lines().map(|line| line.chars().count()).max();
+
+        let tight = render(-3);
+        assert!(width(&tight) < width(&render(0)));
+        assert_eq!(inked(&tight), inked(&render(0)));
+        assert_eq!(
+            render(2),
+            Banner::new("WAVE")
+                .unwrap()
+                .kerning(2)
+                .padding(0)
+                .color_mode(ColorMode::NoColor)
+                .render()
+        );
+    }
+
+    #[test]
+    fn hyperlinks_cover_glyphs_and_caption() {
+        let base = Banner::new("HI")
+            .unwrap()
+            .caption("docs")
+            .padding(1)
+            .color_mode(ColorMode::TrueColor);
+        let plain = base.clone().render();
+        assert!(!plain.contains("\x1b]8"));
+
+        let linked = base
+            .hyperlink("https://example.com")
+            .caption_link("https://docs.rs")
+            .render();
+        assert_eq!(
+            crate::emit::strip_ansi(&linked),
+            crate::emit::strip_ansi(&plain)
+        );
+        let lines: Vec<&str> = linked.lines().collect();
+        let (first, last) = (lines[0], lines[lines.len() - 1]);
+        assert!(!first.contains("\x1b]8") && !last.contains("\x1b]8"));
+        // One link per line, covering the glyph area and closed before the padding.
+        let close = "\x1b]8;;\x1b\\ ";
+        for line in &lines[1..lines.len() - 2] {
+            assert!(line.starts_with(" \x1b]8;;https://example.com\x1b\\"));
+            assert!(line.ends_with(close), "{line:?}");
+            assert_eq!(line.matches("\x1b]8;;").count(), 2, "{line:?}");
+        }
+        let caption = lines[lines.len() - 2];
+        assert!(caption.starts_with(" \x1b]8;;https://docs.rs\x1b\\docs\x1b]8;;\x1b\\"));
+    }
+
+    #[test]
+    fn deterministic_render_ignores_the_environment() {
+        let banner = Banner::new("HI")
+            .unwrap()
+            .gradient(Gradient::vertical(Palette::from_hex(&[
+                "#00e5ff", "#3a7bff",
+            ])))
+            .color_mode(ColorMode::Auto);
+        let expected = banner.clone().color_mode(ColorMode::TrueColor).render();
+
+        assert_eq!(banner.render_deterministic(), expected);
+        let fitted = banner
+            .clone()
+            .fit_terminal()
+            .readable_on_terminal(true)
+            .render_deterministic();
+        assert_eq!(fitted, expected);
+        assert_eq!(
+            banner.render_tagged().lines().next(),
+            Some(" {fg=00E5FF}#####{/fg}   {fg=00E5FF}#####{/fg}  {fg=00E5FF}#####")
+        );
+    }
+}
@@ -10,8 +10,19 @@
 // distributed under the License is distributed on an "AS IS" BASIS,
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 
+mod names;
+mod registry;
+
+use std::fmt;
+
+pub use registry::{RegisterPaletteError, palette, register_palette};
+
 /// Supported color types.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///
+/// With the `serde` feature, RGB colors (de)serialize as `"#RRGGBB"` strings and
+/// palette indices as plain numbers; CSS color names are accepted when deserializing.
+/// Binary (non-human-readable) formats use a plain `Rgb`/`Ansi256` enum instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Color {
     /// 24-bit RGB color.
     Rgb(u8, u8, u8),
@@ -28,18 +39,38 @@ pub enum ColorMode {
     TrueColor,
     /// 256-color output.
     Ansi256,
+    /// Basic 16-color output (8 colors plus bright variants).
+    Ansi16,
     /// Disable color output.
     NoColor,
 }
 
+/// Color space used when interpolating between colors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ColorSpace {
+    /// Straight sRGB byte interpolation (default).
+    #[default]
+    Rgb,
+    /// Hue/saturation/lightness, taking the shorter hue arc.
+    Hsl,
+    /// Perceptual Oklab space (keeps midpoints vivid).
+    Oklab,
+}
+
 /// Palette of colors for gradients.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Palette {
     colors: Vec<Color>,
 }
 
 /// Named palette presets.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum Preset {
     /// Neon Cyber (cyan -> purple -> pink).
     NeonCyber,
@@ -78,56 +109,639 @@ impl Palette {
     }
 
     /// Create a palette from hex strings (invalid entries are ignored).
+    ///
+    /// Use [`Palette::try_from_hex`] to reject invalid entries instead.
     pub fn from_hex(hexes: &[&str]) -> Self {
-        let mut colors = Vec::with_capacity(hexes.len());
-        for hex in hexes {
-            if let Some(color) = parse_hex_color(hex) {
-                colors.push(color);
-            }
-        }
+        let colors = hexes
+            .iter()
+            .filter_map(|hex| Color::from_hex(hex))
+            .collect();
         Self { colors }
     }
 
+    /// Create a palette from hex strings, failing on the first invalid entry.
+    pub fn try_from_hex(hexes: &[&str]) -> Result<Self, PaletteError> {
+        let colors = hexes
+            .iter()
+            .enumerate()
+            .map(|(index, hex)| {
+                Color::from_hex(hex).ok_or_else(|| PaletteError {
+                    index,
+                    input: hex.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { colors })
+    }
+
     /// Create a palette from a named preset.
     pub fn preset(preset: Preset) -> Self {
         Self::from_hex(preset.hexes())
     }
 
+    /// `steps` evenly spaced RGB stops from `from` to `to`, both endpoints included.
+    pub fn ramp(from: Color, to: Color, steps: usize) -> Self {
+        Self::ramp_in(&[from, to], steps, ColorSpace::Rgb)
+    }
+
+    /// `steps` evenly spaced RGB stops from `from` through `via` to `to`.
+    pub fn ramp_via(from: Color, via: Color, to: Color, steps: usize) -> Self {
+        Self::ramp_in(&[from, via, to], steps, ColorSpace::Rgb)
+    }
+
+    /// `steps` evenly spaced stops through `anchors`, interpolated in `space`.
+    ///
+    /// The first and last anchors are kept exactly; every stop is RGB.
+    pub fn ramp_in(anchors: &[Color], steps: usize, space: ColorSpace) -> Self {
+        let (Some(first), Some(last)) = (anchors.first(), anchors.last()) else {
+            return Self::new(Vec::new());
+        };
+        let segments = anchors.len() - 1;
+        let colors = (0..steps)
+            .map(|step| {
+                if step == 0 {
+                    return first.to_rgb();
+                }
+                if step + 1 == steps || segments == 0 {
+                    return last.to_rgb();
+                }
+                let pos = step as f32 / (steps - 1) as f32 * segments as f32;
+                let index = (pos as usize).min(segments - 1);
+                anchors[index].lerp_in(anchors[index + 1], pos - index as f32, space)
+            })
+            .collect();
+        Self::new(colors)
+    }
+
+    /// Move every color's HSL lightness `amount` (0..=1) of the way toward white.
+    pub fn lighten(&self, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        self.map_hsl(|h, s, l| (h, s, l + (1.0 - l) * amount))
+    }
+
+    /// Move every color's HSL lightness `amount` (0..=1) of the way toward black.
+    pub fn darken(&self, amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        self.map_hsl(|h, s, l| (h, s, l * (1.0 - amount)))
+    }
+
+    /// Scale every color's HSL saturation by `1.0 + amount` (negative values desaturate).
+    pub fn saturate(&self, amount: f32) -> Self {
+        self.map_hsl(|h, s, l| (h, (s * (1.0 + amount)).clamp(0.0, 1.0), l))
+    }
+
+    fn map_hsl(&self, f: impl Fn(f32, f32, f32) -> (f32, f32, f32)) -> Self {
+        Self::new(
+            self.colors
+                .iter()
+                .map(|color| {
+                    let (h, s, l) = color.to_hsl();
+                    let (h, s, l) = f(h, s, l);
+                    Color::from_hsl(h, s, l)
+                })
+                .collect(),
+        )
+    }
+
+    /// Look up a palette by name in the registry (see [`register_palette`]).
+    pub fn named(name: &str) -> Option<Self> {
+        palette(name)
+    }
+
     /// Get palette colors.
     pub fn colors(&self) -> &[Color] {
         &self.colors
     }
+
+    /// Pre-convert colors to what `mode` can display (see [`Color::quantize`]).
+    pub fn quantize(&self, mode: ColorMode) -> Self {
+        Self::new(
+            self.colors
+                .iter()
+                .map(|color| color.quantize(mode))
+                .collect(),
+        )
+    }
+
+    /// The same colors in reverse order.
+    pub fn reversed(&self) -> Self {
+        Self::new(self.colors.iter().rev().copied().collect())
+    }
+
+    /// The same colors rotated left by `n` (wrapping).
+    pub fn rotated(&self, n: usize) -> Self {
+        let mut colors = self.colors.clone();
+        if !colors.is_empty() {
+            let len = colors.len();
+            colors.rotate_left(n % len);
+        }
+        Self::new(colors)
+    }
+}
+
+/// Invalid entry reported by [`Palette::try_from_hex`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaletteError {
+    /// Position of the entry in the input.
+    pub index: usize,
+    /// The rejected string.
+    pub input: String,
+}
+
+impl fmt::Display for PaletteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid color {:?} at position {}",
+            self.input,
+            self.index + 1
+        )
+    }
+}
+
+impl std::error::Error for PaletteError {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serde::Serialize::serialize(&ColorRepr::from(*self), serializer);
+        }
+        match *self {
+            Color::Rgb(r, g, b) => serializer.collect_str(&format_args!("#{r:02X}{g:02X}{b:02X}")),
+            Color::Ansi256(code) => serializer.serialize_u8(code),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ColorVisitor;
+
+        impl serde::de::Visitor<'_> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a \"#RRGGBB\" string, a CSS color name or a 0-255 palette index")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Color, E> {
+                Color::from_hex(value)
+                    .or_else(|| Color::from_name(value))
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Color, E> {
+                u8::try_from(value)
+                    .map(Color::Ansi256)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Color, E> {
+                u8::try_from(value)
+                    .map(Color::Ansi256)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(value), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ColorVisitor)
+        } else {
+            <ColorRepr as serde::Deserialize>::deserialize(deserializer).map(Color::from)
+        }
+    }
+}
+
+/// Layout for formats that cannot tell strings from numbers without a schema.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Color")]
+enum ColorRepr {
+    Rgb(u8, u8, u8),
+    Ansi256(u8),
+}
+
+#[cfg(feature = "serde")]
+impl From<Color> for ColorRepr {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Rgb(r, g, b) => ColorRepr::Rgb(r, g, b),
+            Color::Ansi256(code) => ColorRepr::Ansi256(code),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ColorRepr> for Color {
+    fn from(repr: ColorRepr) -> Self {
+        match repr {
+            ColorRepr::Rgb(r, g, b) => Color::Rgb(r, g, b),
+            ColorRepr::Ansi256(code) => Color::Ansi256(code),
+        }
+    }
 }
 
 impl Color {
+    /// Parse `#RGB`, `#RRGGBB` or `#RRGGBBAA` (the `#` is optional, alpha is ignored).
+    pub fn from_hex(input: &str) -> Option<Color> {
+        let hex = input.trim();
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !hex.is_ascii() {
+            return None;
+        }
+        let channel = |range: std::ops::Range<usize>| u8::from_str_radix(&hex[range], 16).ok();
+        match hex.len() {
+            3 => {
+                let short = |i: usize| channel(i..i + 1).map(|v| v * 17);
+                Some(Color::Rgb(short(0)?, short(1)?, short(2)?))
+            }
+            6 | 8 => {
+                if hex.len() == 8 {
+                    channel(6..8)?;
+                }
+                Some(Color::Rgb(channel(0..2)?, channel(2..4)?, channel(4..6)?))
+            }
+            _ => None,
+        }
+    }
+
+    /// Look up a CSS named color such as `"tomato"` or `"rebeccapurple"` (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Color> {
+        let name = name.trim().to_ascii_lowercase();
+        names::CSS_COLORS
+            .binary_search_by(|(candidate, _)| candidate.cmp(&name.as_str()))
+            .ok()
+            .map(|idx| {
+                let [r, g, b] = names::CSS_COLORS[idx].1;
+                Color::Rgb(r, g, b)
+            })
+    }
+
     /// Linear interpolation between colors.
+    ///
+    /// 256-color palette entries are promoted to RGB first, so the result is always RGB.
     pub fn lerp(self, other: Color, t: f32) -> Color {
-        match (self, other) {
-            (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
-                let t = t.clamp(0.0, 1.0);
-                let r = (r1 as f32 + (r2 as f32 - r1 as f32) * t).round() as u8;
-                let g = (g1 as f32 + (g2 as f32 - g1 as f32) * t).round() as u8;
-                let b = (b1 as f32 + (b2 as f32 - b1 as f32) * t).round() as u8;
-                Color::Rgb(r, g, b)
+        let (r1, g1, b1) = self.rgb_components();
+        let (r2, g2, b2) = other.rgb_components();
+        let t = t.clamp(0.0, 1.0);
+        let r = (r1 as f32 + (r2 as f32 - r1 as f32) * t).round() as u8;
+        let g = (g1 as f32 + (g2 as f32 - g1 as f32) * t).round() as u8;
+        let b = (b1 as f32 + (b2 as f32 - b1 as f32) * t).round() as u8;
+        Color::Rgb(r, g, b)
+    }
+
+    /// Linear interpolation in linear-light space: decode sRGB, blend, re-encode.
+    ///
+    /// Midpoints stay brighter than with [`Color::lerp`]; the result is always RGB.
+    pub fn lerp_linear(self, other: Color, t: f32) -> Color {
+        let (r1, g1, b1) = self.rgb_components();
+        let (r2, g2, b2) = other.rgb_components();
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| {
+            let (a, b) = (linear_channel(a), linear_channel(b));
+            encode_channel(a + (b - a) * t)
+        };
+        Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+    }
+
+    /// Nearest xterm 256-color index (palette colors return their own index).
+    ///
+    /// Picks the closer of the 6×6×6 cube and the grayscale ramp, rounding each channel
+    /// to the nearest cube level. The 16 basic colors are never chosen, since terminals
+    /// theme them: [`Color::from_ansi256`] followed by this is the identity for `16..=255`,
+    /// while a basic color maps to the fixed-palette entry nearest its xterm default.
+    pub fn to_ansi256(self) -> u8 {
+        let (r, g, b) = match self {
+            Color::Ansi256(code) => return code,
+            Color::Rgb(r, g, b) => (r, g, b),
+        };
+        let cube_level = |v: u8| match v {
+            0..48 => 0,
+            48..115 => 1,
+            _ => (v - 35) / 40,
+        };
+        let (rc, gc, bc) = (cube_level(r), cube_level(g), cube_level(b));
+        let cube = 16 + 36 * rc + 6 * gc + bc;
+
+        let average = (r as u16 + g as u16 + b as u16) / 3;
+        let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+        let distance = |code: u8| {
+            let (cr, cg, cb) = ansi256_rgb(code);
+            [(r, cr), (g, cg), (b, cb)]
+                .into_iter()
+                .map(|(a, b)| (a as i32 - b as i32).pow(2))
+                .sum::<i32>()
+        };
+        if distance(gray) < distance(cube) {
+            gray
+        } else {
+            cube
+        }
+    }
+
+    /// The RGB equivalent of an xterm 256-color index.
+    pub fn from_ansi256(code: u8) -> Color {
+        Color::Ansi256(code).to_rgb()
+    }
+
+    /// The RGB equivalent of this color (xterm's table for 256-color entries).
+    pub fn to_rgb(self) -> Color {
+        let (r, g, b) = self.rgb_components();
+        Color::Rgb(r, g, b)
+    }
+
+    /// Interpolate between colors in the given color space.
+    pub fn lerp_in(self, other: Color, t: f32, space: ColorSpace) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        match space {
+            ColorSpace::Rgb => self.lerp(other, t),
+            ColorSpace::Hsl => {
+                let (h1, s1, l1) = self.to_hsl();
+                let (h2, s2, l2) = other.to_hsl();
+                let mut dh = h2 - h1;
+                if dh > 180.0 {
+                    dh -= 360.0;
+                } else if dh < -180.0 {
+                    dh += 360.0;
+                }
+                Color::from_hsl(h1 + dh * t, s1 + (s2 - s1) * t, l1 + (l2 - l1) * t)
+            }
+            ColorSpace::Oklab => {
+                let (l1, a1, b1) = self.to_oklab();
+                let (l2, a2, b2) = other.to_oklab();
+                Color::from_oklab(l1 + (l2 - l1) * t, a1 + (a2 - a1) * t, b1 + (b2 - b1) * t)
             }
-            (left, _) => left,
         }
     }
+
+    /// Convert to HSL: hue in degrees (0..360), saturation and lightness in 0..=1.
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (r, g, b) = self.rgb_components();
+        let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let d = max - min;
+        if d == 0.0 {
+            return (0.0, 0.0, l);
+        }
+        let s = d / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == r {
+            60.0 * ((g - b) / d).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / d + 2.0)
+        } else {
+            60.0 * ((r - g) / d + 4.0)
+        };
+        (h, s, l)
+    }
+
+    /// Build an RGB color from HSL (hue in degrees, saturation and lightness in 0..=1).
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Color {
+        let h = h.rem_euclid(360.0);
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - ((h / 60.0).rem_euclid(2.0) - 1.0).abs());
+        let m = l - c / 2.0;
+        let (r, g, b) = match (h / 60.0) as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let to_u8 = |v: f32| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Color::Rgb(to_u8(r), to_u8(g), to_u8(b))
+    }
+
+    /// Gray with the same WCAG luminance.
+    pub fn grayscale(self) -> Color {
+        let level = encode_channel(self.luminance());
+        Color::Rgb(level, level, level)
+    }
+
+    /// Take the hue and saturation of `tint`, keeping this color's HSL lightness.
+    pub fn tint(self, tint: Color) -> Color {
+        let (h, s, _) = tint.to_hsl();
+        let (_, _, l) = self.to_hsl();
+        Color::from_hsl(h, s, l)
+    }
+
+    /// Rotate the hue by `degrees`, keeping saturation and lightness (the result is RGB).
+    pub fn rotate_hue(self, degrees: f32) -> Color {
+        let (h, s, l) = self.to_hsl();
+        Color::from_hsl((h + degrees).rem_euclid(360.0), s, l)
+    }
+
+    /// Convert to Oklab `(L, a, b)`.
+    pub fn to_oklab(self) -> (f32, f32, f32) {
+        let (r, g, b) = self.rgb_components();
+        let (r, g, b) = (linear_channel(r), linear_channel(g), linear_channel(b));
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        (
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        )
+    }
+
+    /// Build an RGB color from Oklab `(L, a, b)` (out-of-gamut values are clamped).
+    pub fn from_oklab(l: f32, a: f32, b: f32) -> Color {
+        let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+        let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+        let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+        let r = 4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_;
+        let g = -1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_;
+        let b = -0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_;
+        Color::Rgb(encode_channel(r), encode_channel(g), encode_channel(b))
+    }
+
+    /// WCAG relative luminance (0.0 = black, 1.0 = white).
+    pub fn luminance(self) -> f32 {
+        let (r, g, b) = self.rgb_components();
+        0.2126 * linear_channel(r) + 0.7152 * linear_channel(g) + 0.0722 * linear_channel(b)
+    }
+
+    /// WCAG contrast ratio against another color (1.0..=21.0).
+    pub fn contrast_ratio(self, other: Color) -> f32 {
+        let a = self.luminance();
+        let b = other.luminance();
+        let (light, dark) = if a >= b { (a, b) } else { (b, a) };
+        (light + 0.05) / (dark + 0.05)
+    }
+
+    /// Scale RGB channels toward black by `amount` (0..=1); palette colors are promoted to RGB.
+    pub(crate) fn darken(self, amount: f32) -> Color {
+        let factor = (1.0 - amount.clamp(0.0, 1.0)).clamp(0.0, 1.0);
+        let (r, g, b) = self.rgb_components();
+        Color::Rgb(
+            (r as f32 * factor).round() as u8,
+            (g as f32 * factor).round() as u8,
+            (b as f32 * factor).round() as u8,
+        )
+    }
+
+    /// Convert to the palette color `mode` would emit, so emitting skips the conversion.
+    ///
+    /// `Ansi256` and `Ansi16` map to [`Color::Ansi256`] indices; other modes return `self`.
+    pub fn quantize(self, mode: ColorMode) -> Color {
+        match (mode, self) {
+            (ColorMode::Ansi256, color) => Color::Ansi256(color.to_ansi256()),
+            (ColorMode::Ansi16, color) => Color::Ansi256(color.ansi16_index()),
+            (_, color) => color,
+        }
+    }
+
+    /// Index (0..16) of the perceptually nearest basic ANSI color, measured in Oklab.
+    ///
+    /// Chroma differences count double so grays don't snap to the dim hues.
+    pub(crate) fn ansi16_index(self) -> u8 {
+        if let Color::Ansi256(code @ 0..=15) = self {
+            return code;
+        }
+        let (l, a, b) = self.to_oklab();
+        (0..16u8)
+            .map(|code| {
+                let (l2, a2, b2) = Color::Ansi256(code).to_oklab();
+                let distance = (l - l2).powi(2) + 4.0 * ((a - a2).powi(2) + (b - b2).powi(2));
+                (code, distance)
+            })
+            .min_by(|x, y| x.1.total_cmp(&y.1))
+            .map_or(0, |(code, _)| code)
+    }
+
+    pub(crate) fn rgb_components(self) -> (u8, u8, u8) {
+        match self {
+            Color::Rgb(r, g, b) => (r, g, b),
+            Color::Ansi256(code) => ansi256_rgb(code),
+        }
+    }
+}
+
+/// Nudge `fg` toward black or white until it reaches `min_ratio` against `bg`.
+pub(crate) fn ensure_contrast(fg: Color, bg: Color, min_ratio: f32) -> Color {
+    if fg.contrast_ratio(bg) >= min_ratio {
+        return fg;
+    }
+    let target = if bg.luminance() > 0.5 {
+        Color::Rgb(0, 0, 0)
+    } else {
+        Color::Rgb(255, 255, 255)
+    };
+    let (r, g, b) = fg.rgb_components();
+    let base = Color::Rgb(r, g, b);
+    for step in 1..=20 {
+        let candidate = base.lerp(target, step as f32 / 20.0);
+        if candidate.contrast_ratio(bg) >= min_ratio {
+            return candidate;
+        }
+    }
+    target
 }
 
-fn parse_hex_color(input: &str) -> Option<Color> {
-    let hex = input.trim().trim_start_matches('#');
-    if hex.len() != 6 {
-        return None;
+fn linear_channel(value: u8) -> f32 {
+    let c = value as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn encode_channel(linear: f32) -> u8 {
+    let c = linear.clamp(0.0, 1.0);
+    let c = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+fn ansi256_rgb(code: u8) -> (u8, u8, u8) {
+    const BASE: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (128, 0, 0),
+        (0, 128, 0),
+        (128, 128, 0),
+        (0, 0, 128),
+        (128, 0, 128),
+        (0, 128, 128),
+        (192, 192, 192),
+        (128, 128, 128),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (0, 0, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    match code {
+        0..=15 => BASE[code as usize],
+        16..=231 => {
+            let idx = code - 16;
+            (
+                CUBE[(idx / 36) as usize],
+                CUBE[((idx / 6) % 6) as usize],
+                CUBE[(idx % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (code - 232) * 10;
+            (level, level, level)
+        }
     }
-    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
-    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
-    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
-    Some(Color::Rgb(r, g, b))
 }
 
 impl Preset {
-    fn hexes(self) -> &'static [&'static str] {
+    /// Every preset, in declaration order.
+    pub const ALL: &'static [Preset] = &[
+        Preset::NeonCyber,
+        Preset::ArcticTech,
+        Preset::SunsetNeon,
+        Preset::ForestSky,
+        Preset::Chrome,
+        Preset::CrtAmber,
+        Preset::OceanFlow,
+        Preset::DeepSpace,
+        Preset::FireWarning,
+        Preset::WarmLuxury,
+        Preset::EarthTone,
+        Preset::RoyalPurple,
+        Preset::Matrix,
+        Preset::AuroraFlux,
+    ];
+
+    /// Kebab-case name, e.g. `"neon-cyber"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Preset::NeonCyber => "neon-cyber",
+            Preset::ArcticTech => "arctic-tech",
+            Preset::SunsetNeon => "sunset-neon",
+            Preset::ForestSky => "forest-sky",
+            Preset::Chrome => "chrome",
+            Preset::CrtAmber => "crt-amber",
+            Preset::OceanFlow => "ocean-flow",
+            Preset::DeepSpace => "deep-space",
+            Preset::FireWarning => "fire-warning",
+            Preset::WarmLuxury => "warm-luxury",
+            Preset::EarthTone => "earth-tone",
+            Preset::RoyalPurple => "royal-purple",
+            Preset::Matrix => "matrix",
+            Preset::AuroraFlux => "aurora-flux",
+        }
+    }
+
+    pub(crate) fn hexes(self) -> &'static [&'static str] {
         match self {
             Preset::NeonCyber => &["#00E5FF", "#7B5CFF", "#FF5AD9"],
             Preset::ArcticTech => &["#00E5FF", "#3A7BFF", "#E6F6FF"],
@@ -146,3 +760,252 @@ impl Preset {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn binary_formats_use_an_enum_layout() {
+        use serde_test::{Configure, Token, assert_tokens};
+
+        assert_tokens(
+            &Color::Rgb(1, 2, 3).compact(),
+            &[
+                Token::TupleVariant {
+                    name: "Color",
+                    variant: "Rgb",
+                    len: 3,
+                },
+                Token::U8(1),
+                Token::U8(2),
+                Token::U8(3),
+                Token::TupleVariantEnd,
+            ],
+        );
+        assert_tokens(
+            &Color::Ansi256(42).compact(),
+            &[
+                Token::NewtypeVariant {
+                    name: "Color",
+                    variant: "Ansi256",
+                },
+                Token::U8(42),
+            ],
+        );
+        assert_tokens(&Color::Rgb(255, 0, 16).readable(), &[Token::Str("#FF0010")]);
+        assert_tokens(&Color::Ansi256(42).readable(), &[Token::U8(42)]);
+    }
+
+    #[test]
+    fn ansi256_round_trips_through_rgb() {
+        // Basic colors are theme-dependent, so they map to their fixed-palette neighbors.
+        const BASIC: [u8; 16] = [
+            16, 88, 28, 100, 18, 90, 30, 250, 244, 196, 46, 226, 21, 201, 51, 231,
+        ];
+        for code in 0..=255u8 {
+            let rgb = Color::from_ansi256(code);
+            let expected = BASIC.get(code as usize).copied().unwrap_or(code);
+            assert_eq!(rgb.to_ansi256(), expected, "{code}: {rgb:?}");
+        }
+    }
+
+    #[test]
+    fn to_ansi256_rounds_to_the_nearest_level() {
+        assert_eq!(Color::Rgb(0, 0, 0).to_ansi256(), 16);
+        assert_eq!(Color::Rgb(255, 255, 255).to_ansi256(), 231);
+        assert_eq!(Color::Rgb(255, 0, 0).to_ansi256(), 196);
+        // 80 sits closer to cube level 95 than to 0; truncating used to pick 0.
+        assert_eq!(Color::Rgb(80, 0, 0).to_ansi256(), 52);
+        assert_eq!(Color::Rgb(128, 128, 128).to_ansi256(), 244);
+        assert_eq!(Color::Ansi256(7).to_ansi256(), 7);
+    }
+
+    #[test]
+    fn palette_colors_promote_to_rgb() {
+        assert_eq!(Color::Ansi256(9).to_rgb(), Color::Rgb(255, 0, 0));
+        assert_eq!(Color::Ansi256(45).to_rgb(), Color::Rgb(0, 215, 255));
+        assert_eq!(Color::Ansi256(244).to_rgb(), Color::Rgb(128, 128, 128));
+        assert_eq!(
+            Color::Ansi256(45).lerp(Color::Rgb(255, 255, 255), 0.5),
+            Color::Rgb(128, 235, 255)
+        );
+        assert_eq!(Color::Ansi256(15).darken(0.5), Color::Rgb(128, 128, 128));
+    }
+
+    #[test]
+    fn quantize_matches_emit_conversion() {
+        let palette = Palette::from_hex(&["#ff0000", "#3a7bff"]).quantize(ColorMode::Ansi256);
+        assert_eq!(
+            palette.colors(),
+            [
+                Color::Ansi256(196),
+                Color::Ansi256(Color::Rgb(0x3a, 0x7b, 0xff).to_ansi256())
+            ]
+        );
+        assert_eq!(
+            Color::Rgb(255, 0, 0).quantize(ColorMode::Ansi16),
+            Color::Ansi256(9)
+        );
+        assert_eq!(
+            Color::Rgb(1, 2, 3).quantize(ColorMode::TrueColor),
+            Color::Rgb(1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn parses_hex_forms_and_names() {
+        assert_eq!(Color::from_hex("#f80"), Some(Color::Rgb(255, 136, 0)));
+        assert_eq!(Color::from_hex("FF8800"), Some(Color::Rgb(255, 136, 0)));
+        assert_eq!(Color::from_hex("#ff880080"), Some(Color::Rgb(255, 136, 0)));
+        for bad in ["", "#ff88", "#gg0000", "#ff88008", "#ff8800zz", "#é00"] {
+            assert_eq!(Color::from_hex(bad), None, "{bad}");
+        }
+
+        assert_eq!(
+            Color::from_name("RebeccaPurple"),
+            Some(Color::Rgb(102, 51, 153))
+        );
+        assert_eq!(Color::from_name("tomato"), Some(Color::Rgb(255, 99, 71)));
+        assert_eq!(Color::from_name("blurple"), None);
+        assert_eq!(names::CSS_COLORS.len(), 148);
+        assert!(names::CSS_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn try_from_hex_reports_the_bad_entry() {
+        let err = Palette::try_from_hex(&["#000", "nope", "#fff"]).unwrap_err();
+        assert_eq!(err.index, 1);
+        assert_eq!(err.to_string(), "invalid color \"nope\" at position 2");
+        assert_eq!(Palette::from_hex(&["#000", "nope"]).colors().len(), 1);
+    }
+
+    #[test]
+    fn palette_reverse_and_rotate() {
+        let palette = Palette::from_hex(&["#010101", "#020202", "#030303"]);
+        let gray = |v: u8| Color::Rgb(v, v, v);
+        assert_eq!(palette.reversed().colors(), [gray(3), gray(2), gray(1)]);
+        assert_eq!(palette.rotated(4).colors(), [gray(2), gray(3), gray(1)]);
+        assert!(Palette::new(Vec::new()).rotated(2).colors().is_empty());
+    }
+
+    #[test]
+    fn ramps_keep_step_count_and_endpoints() {
+        let from = Color::Rgb(0x11, 0x22, 0x33);
+        let to = Color::Rgb(0xEE, 0xFF, 0xEE);
+        let ramp = Palette::ramp(from, to, 8);
+        assert_eq!(ramp.colors().len(), 8);
+        assert_eq!(ramp.colors()[0], from);
+        assert_eq!(ramp.colors()[7], to);
+
+        let via = Palette::ramp_via(from, Color::Ansi256(196), to, 5);
+        assert_eq!(via.colors()[2], Color::Rgb(255, 0, 0));
+        assert_eq!(via.colors()[4], to);
+
+        let oklab = Palette::ramp_in(&[Color::Ansi256(21), to], 3, ColorSpace::Oklab);
+        assert_eq!(oklab.colors()[0], Color::Rgb(0, 0, 255));
+        assert_eq!(oklab.colors()[2], to);
+
+        assert_eq!(Palette::ramp(from, to, 1).colors(), [from]);
+        assert!(Palette::ramp(from, to, 0).colors().is_empty());
+    }
+
+    #[test]
+    fn palette_adjustments_return_variants() {
+        let palette = Palette::new(vec![Color::Rgb(200, 40, 40), Color::Ansi256(244)]);
+        let lighter = palette.lighten(0.5);
+        let darker = palette.darken(0.5);
+        for (index, color) in palette.colors().iter().enumerate() {
+            assert!(lighter.colors()[index].luminance() > color.luminance());
+            assert!(darker.colors()[index].luminance() < color.luminance());
+        }
+        assert_eq!(palette.lighten(1.0).colors()[0], Color::Rgb(255, 255, 255));
+        assert_eq!(palette.darken(1.0).colors()[1], Color::Rgb(0, 0, 0));
+
+        let (_, s, _) = palette.colors()[0].to_hsl();
+        let (_, less, _) = palette.saturate(-0.5).colors()[0].to_hsl();
+        assert!((less - s * 0.5).abs() < 0.01);
+        assert_eq!(
+            palette.saturate(-1.0).colors()[1],
+            Color::Rgb(128, 128, 128)
+        );
+    }
+
+    #[test]
+    fn luminance_follows_wcag() {
+        assert_eq!(Color::Rgb(255, 255, 255).luminance(), 1.0);
+        assert_eq!(Color::Rgb(0, 0, 0).luminance(), 0.0);
+        assert!((Color::Rgb(128, 128, 128).luminance() - 0.2159).abs() < 1e-3);
+        assert!(
+            (Color::Rgb(255, 255, 255).contrast_ratio(Color::Rgb(0, 0, 0)) - 21.0).abs() < 1e-3
+        );
+        assert_eq!(Color::Rgb(9, 9, 9).contrast_ratio(Color::Rgb(9, 9, 9)), 1.0);
+    }
+
+    #[test]
+    fn grayscale_and_tint_keep_brightness() {
+        assert_eq!(Color::Rgb(255, 0, 0).grayscale(), Color::Rgb(127, 127, 127));
+        assert_eq!(Color::Rgb(0, 255, 0).grayscale(), Color::Rgb(220, 220, 220));
+        assert_eq!(Color::Rgb(90, 90, 90).grayscale(), Color::Rgb(90, 90, 90));
+
+        let amber = Color::Rgb(255, 176, 0);
+        assert_eq!(Color::Rgb(0, 0, 255).tint(amber), amber);
+        assert_eq!(
+            Color::Rgb(255, 255, 255).tint(amber),
+            Color::Rgb(255, 255, 255)
+        );
+        let (h, s, l) = Color::Rgb(40, 80, 120).tint(amber).to_hsl();
+        let (amber_h, amber_s, _) = amber.to_hsl();
+        let (_, _, source_l) = Color::Rgb(40, 80, 120).to_hsl();
+        assert!((h - amber_h).abs() < 1.0 && (s - amber_s).abs() < 0.02);
+        assert!((l - source_l).abs() < 0.01);
+    }
+
+    #[test]
+    fn rotate_hue_wraps_around_the_wheel() {
+        let red = Color::Rgb(255, 0, 0);
+        assert_eq!(red.rotate_hue(120.0), Color::Rgb(0, 255, 0));
+        assert_eq!(red.rotate_hue(-120.0), Color::Rgb(0, 0, 255));
+        assert_eq!(red.rotate_hue(360.0), red);
+        assert_eq!(
+            Color::Rgb(90, 90, 90).rotate_hue(45.0),
+            Color::Rgb(90, 90, 90)
+        );
+    }
+
+    #[test]
+    fn hsl_and_oklab_round_trip() {
+        for color in [
+            Color::Rgb(0, 0, 0),
+            Color::Rgb(255, 255, 255),
+            Color::Rgb(12, 200, 99),
+            Color::Rgb(250, 128, 3),
+            Color::Rgb(70, 70, 180),
+        ] {
+            let (h, s, l) = color.to_hsl();
+            assert_eq!(Color::from_hsl(h, s, l), color);
+            let (l, a, b) = color.to_oklab();
+            assert_eq!(Color::from_oklab(l, a, b), color);
+        }
+    }
+
+    #[test]
+    fn oklab_midpoint_of_complements_stays_saturated() {
+        let blue = Color::Rgb(0, 0, 255);
+        let yellow = Color::Rgb(255, 255, 0);
+
+        let (_, rgb_saturation, _) = blue.lerp_in(yellow, 0.5, ColorSpace::Rgb).to_hsl();
+        let (_, oklab_saturation, _) = blue.lerp_in(yellow, 0.5, ColorSpace::Oklab).to_hsl();
+        assert!(rgb_saturation < 0.05);
+        assert!(oklab_saturation > 0.2, "saturation {oklab_saturation}");
+    }
+
+    #[test]
+    fn hsl_takes_the_shorter_hue_arc() {
+        let red = Color::from_hsl(350.0, 1.0, 0.5);
+        let orange = Color::from_hsl(30.0, 1.0, 0.5);
+        let (hue, _, _) = red.lerp_in(orange, 0.5, ColorSpace::Hsl).to_hsl();
+        assert!((hue - 10.0).abs() < 2.0, "hue {hue}");
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+use super::{Palette, Preset};
+
+fn registry() -> &'static RwLock<HashMap<String, Palette>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, Palette>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let presets = Preset::ALL
+            .iter()
+            .map(|preset| (preset.name().to_string(), Palette::preset(*preset)))
+            .collect();
+        RwLock::new(presets)
+    })
+}
+
+fn key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+/// Register a named palette, replacing any palette (including a preset) with the same name.
+///
+/// Names are case-insensitive.
+pub fn register_palette(name: &str, palette: Palette) -> Result<(), RegisterPaletteError> {
+    let key = key(name);
+    if key.is_empty() {
+        return Err(RegisterPaletteError::EmptyName);
+    }
+    if palette.colors().is_empty() {
+        return Err(RegisterPaletteError::EmptyPalette(key));
+    }
+    registry()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(key, palette);
+    Ok(())
+}
+
+/// Look up a registered palette by name (case-insensitive).
+///
+/// Built-in presets are registered under their kebab-case names, e.g. `"neon-cyber"`.
+pub fn palette(name: &str) -> Option<Palette> {
+    registry()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&key(name))
+        .cloned()
+}
+
+/// Error returned by [`register_palette`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegisterPaletteError {
+    /// The name was empty or whitespace.
+    EmptyName,
+    /// The palette had no colors.
+    EmptyPalette(String),
+}
+
+impl fmt::Display for RegisterPaletteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegisterPaletteError::EmptyName => write!(f, "palette name is empty"),
+            RegisterPaletteError::EmptyPalette(name) => {
+                write!(f, "palette {name:?} has no colors")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegisterPaletteError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Color;
+
+    #[test]
+    fn presets_are_preregistered() {
+        let palette = palette("Neon-Cyber").unwrap();
+        assert_eq!(
+            palette.colors(),
+            Palette::preset(Preset::NeonCyber).colors()
+        );
+    }
+
+    #[test]
+    fn registration_is_case_insensitive() {
+        let brand = Palette::new(vec![Color::Rgb(1, 2, 3), Color::Rgb(4, 5, 6)]);
+        register_palette("Registry-Test-Brand", brand).unwrap();
+        assert_eq!(
+            Palette::named("registry-test-brand").unwrap().colors(),
+            &[Color::Rgb(1, 2, 3), Color::Rgb(4, 5, 6)]
+        );
+        assert!(palette("registry-test-missing").is_none());
+    }
+
+    #[test]
+    fn empty_registrations_are_rejected() {
+        assert_eq!(
+            register_palette(" ", Palette::from_hex(&["#fff"])),
+            Err(RegisterPaletteError::EmptyName)
+        );
+        assert_eq!(
+            register_palette("Registry-Test-Empty", Palette::new(Vec::new())),
+            Err(RegisterPaletteError::EmptyPalette(
+                "registry-test-empty".to_string()
+            ))
+        );
+        assert!(palette("registry-test-empty").is_none());
+    }
+}
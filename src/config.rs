@@ -0,0 +1,195 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use crate::banner::{Banner, BannerError};
+use crate::color::{Color, Palette, Preset};
+use crate::frame::{Frame, FrameChars, FrameStyle};
+use crate::gradient::Gradient;
+use crate::grid::{Align, Padding};
+use crate::style::Style;
+
+/// Plain-data banner settings, e.g. a theme loaded from an app's config file.
+///
+/// Every field is optional; unset fields keep the [`Banner`] defaults. With the
+/// `serde` feature the struct (de)serializes with missing fields treated as unset.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct BannerConfig {
+    /// Named style, applied before everything else.
+    pub style: Option<Style>,
+    /// Palette preset, drawn as a diagonal gradient.
+    pub preset: Option<Preset>,
+    /// Custom palette, drawn as a diagonal gradient (wins over `preset`).
+    pub palette: Option<Palette>,
+    /// Explicit gradient (wins over `palette` and `preset`).
+    pub gradient: Option<Gradient>,
+    /// Horizontal alignment.
+    pub align: Option<Align>,
+    /// Padding around the banner.
+    pub padding: Option<Padding>,
+    /// Frame style.
+    pub frame: Option<FrameStyle>,
+    /// Custom frame characters (win over `frame`).
+    pub frame_chars: Option<FrameChars>,
+    /// Solid frame color.
+    pub frame_color: Option<Color>,
+    /// Fixed output width.
+    pub width: Option<usize>,
+    /// Maximum output width.
+    pub max_width: Option<usize>,
+    /// Columns between glyphs.
+    pub kerning: Option<usize>,
+    /// Blank rows between lines.
+    pub line_gap: Option<usize>,
+}
+
+impl Banner {
+    /// Create a banner from text and a [`BannerConfig`].
+    pub fn from_config(
+        text: impl Into<String>,
+        config: &BannerConfig,
+    ) -> Result<Self, BannerError> {
+        let mut banner = Banner::new(text)?;
+        if let Some(style) = config.style {
+            banner = banner.style(style);
+        }
+        let palette = config
+            .palette
+            .clone()
+            .or_else(|| config.preset.map(Palette::preset));
+        if let Some(gradient) = &config.gradient {
+            banner = banner.gradient(gradient.clone());
+        } else if let Some(palette) = palette {
+            banner = banner.gradient(Gradient::diagonal(palette));
+        }
+        if let Some(align) = config.align {
+            banner = banner.align(align);
+        }
+        if let Some(padding) = config.padding {
+            banner = banner.padding(padding);
+        }
+        if let Some(chars) = config.frame_chars.or(config.frame.map(FrameStyle::chars)) {
+            let mut frame = Frame::custom(chars);
+            if let Some(color) = config.frame_color {
+                frame = frame.color(color);
+            }
+            banner = banner.frame(frame);
+        }
+        if let Some(width) = config.width {
+            banner = banner.width(width);
+        }
+        if let Some(width) = config.max_width {
+            banner = banner.max_width(width);
+        }
+        if let Some(kerning) = config.kerning {
+            banner = banner.kerning(kerning);
+        }
+        if let Some(line_gap) = config.line_gap {
+            banner = banner.line_gap(line_gap);
+        }
+        Ok(banner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::ColorMode;
+
+    #[cfg(feature = "serde")]
+    fn sample() -> BannerConfig {
+        BannerConfig {
+            preset: Some(Preset::Matrix),
+            gradient: Some(
+                Gradient::new(
+                    vec![Color::Rgb(255, 0, 0), Color::Ansi256(45)],
+                    crate::gradient::GradientDirection::Plasma {
+                        scale: 4.0,
+                        seed: 7,
+                    },
+                )
+                .repeat(2.0),
+            ),
+            align: Some(Align::Center),
+            padding: Some(Padding::uniform(1)),
+            frame: Some(FrameStyle::Rounded),
+            frame_color: Some(Color::Rgb(0, 128, 255)),
+            kerning: Some(0),
+            ..BannerConfig::default()
+        }
+    }
+
+    fn render(config: &BannerConfig) -> String {
+        Banner::from_config("HI", config)
+            .unwrap()
+            .color_mode(ColorMode::TrueColor)
+            .render()
+    }
+
+    #[test]
+    fn from_config_matches_builder() {
+        let config = BannerConfig {
+            preset: Some(Preset::Matrix),
+            frame: Some(FrameStyle::Rounded),
+            frame_color: Some(Color::Rgb(0, 128, 255)),
+            padding: Some(Padding::uniform(1)),
+            ..BannerConfig::default()
+        };
+        let built = Banner::new("HI")
+            .unwrap()
+            .gradient(Gradient::diagonal(Palette::preset(Preset::Matrix)))
+            .padding(1)
+            .frame(Frame::new(FrameStyle::Rounded).color(Color::Rgb(0, 128, 255)))
+            .color_mode(ColorMode::TrueColor)
+            .render();
+        assert_eq!(render(&config), built);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trip() {
+        let config = sample();
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains("\"preset\":\"matrix\""), "{json}");
+        assert!(json.contains("\"#FF0000\",45"), "{json}");
+
+        let parsed: BannerConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        assert_eq!(render(&parsed), render(&config));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn toml_round_trip() {
+        let config = sample();
+        let text = toml::to_string(&config).unwrap();
+        let parsed: BannerConfig = toml::from_str(&text).unwrap();
+        assert_eq!(toml::to_string(&parsed).unwrap(), text);
+        assert_eq!(render(&parsed), render(&config));
+
+        let theme: BannerConfig = toml::from_str(
+            "style = \"neon-cyber\"\nframe = \"double\"\nframe_color = \"tomato\"\n",
+        )
+        .unwrap();
+        assert_eq!(theme.style, Some(Style::NeonCyber));
+        assert_eq!(theme.frame_color, Some(Color::Rgb(255, 99, 71)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn unknown_names_are_rejected() {
+        assert!(serde_json::from_str::<BannerConfig>(r#"{"preset":"neon-cyberr"}"#).is_err());
+        assert!(serde_json::from_str::<BannerConfig>(r##"{"frame_color":"#zzzzzz"}"##).is_err());
+        assert!(serde_json::from_str::<BannerConfig>(r#"{"frame_color":256}"#).is_err());
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use crate::color::{Color, ensure_contrast};
+use crate::grid::Grid;
+use crate::terminal::Background;
+
+/// Minimum contrast ratio kept against the terminal background.
+const MIN_TERMINAL_CONTRAST: f32 = 3.0;
+
+/// Minimum contrast ratio kept between a cell's foreground and background.
+const MIN_CELL_CONTRAST: f32 = 3.0;
+
+/// Adjust foreground colors in-place so they stay readable on the terminal background.
+///
+/// Does nothing when the background is unknown.
+pub fn apply_terminal_readability(grid: &mut Grid, background: Background) {
+    let bg = match background {
+        Background::Light => Color::Rgb(255, 255, 255),
+        Background::Dark => Color::Rgb(0, 0, 0),
+        Background::Unknown => return,
+    };
+
+    for row in grid.rows_mut() {
+        for cell in row {
+            if !cell.visible {
+                continue;
+            }
+            if let Some(fg) = cell.fg {
+                cell.fg = Some(ensure_contrast(fg, bg, MIN_TERMINAL_CONTRAST));
+            }
+        }
+    }
+}
+
+/// Adjust foreground colors in-place so they stay readable on their own cell background.
+///
+/// Cells without both a foreground and a background are left alone.
+pub fn apply_cell_contrast(grid: &mut Grid) {
+    for row in grid.rows_mut() {
+        for cell in row {
+            if !cell.visible {
+                continue;
+            }
+            if let (Some(fg), Some(bg)) = (cell.fg, cell.bg) {
+                cell.fg = Some(ensure_contrast(fg, bg, MIN_CELL_CONTRAST));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Palette;
+    use crate::gradient::Gradient;
+
+    #[test]
+    fn pale_gradient_is_darkened_on_light_terminals() {
+        let mut grid = Grid::from_char_rows(vec![vec!['#'; 4]; 2]);
+        Gradient::horizontal(Palette::from_hex(&["#FFF7D6", "#E6F6FF"])).apply(&mut grid);
+        let before = grid.cell(0, 0).unwrap().fg.unwrap();
+
+        apply_terminal_readability(&mut grid, Background::Light);
+
+        let after = grid.cell(0, 0).unwrap().fg.unwrap();
+        let white = Color::Rgb(255, 255, 255);
+        assert!(before.contrast_ratio(white) < MIN_TERMINAL_CONTRAST);
+        assert!(after.contrast_ratio(white) >= MIN_TERMINAL_CONTRAST);
+        assert!(after.luminance() < before.luminance());
+    }
+
+    #[test]
+    fn unknown_background_is_a_no_op() {
+        let mut grid = Grid::from_char_rows(vec![vec!['#']]);
+        grid.cell_mut(0, 0).unwrap().fg = Some(Color::Rgb(250, 250, 250));
+        apply_terminal_readability(&mut grid, Background::Unknown);
+        assert_eq!(grid.cell(0, 0).unwrap().fg, Some(Color::Rgb(250, 250, 250)));
+    }
+
+    #[test]
+    fn cell_contrast_uses_each_cell_background() {
+        let mut grid = Grid::from_char_rows(vec![vec!['#'; 3]]);
+        let pale = Color::Rgb(230, 240, 255);
+        for (c, bg) in [
+            Some(Color::Rgb(255, 255, 255)),
+            Some(Color::Rgb(0, 0, 60)),
+            None,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let cell = grid.cell_mut(0, c).unwrap();
+            cell.fg = Some(pale);
+            cell.bg = bg;
+        }
+
+        apply_cell_contrast(&mut grid);
+
+        let fg = |c: usize| grid.cell(0, c).unwrap().fg.unwrap();
+        assert!(fg(0).contrast_ratio(Color::Rgb(255, 255, 255)) >= MIN_CELL_CONTRAST);
+        assert_eq!(fg(1), pale);
+        assert_eq!(fg(2), pale);
+    }
+}
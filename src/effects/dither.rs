@@ -10,7 +10,7 @@
 // distributed under the License is distributed on an "AS IS" BASIS,
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 
-use crate::fill::{Dither, DitherMode};
+use crate::fill::{Dither, DitherMode, should_dither};
 use crate::grid::Grid;
 
 /// Apply dot dithering over selected glyph targets.
@@ -30,41 +30,19 @@ pub fn apply_dot_dither(grid: &Grid, dither: Dither, targets: &[char]) -> Grid {
             if !targets.contains(&cell.ch) {
                 continue;
             }
-            if should_dither(r, c, dither.mode) {
-                cell.ch = if (r + c) % 2 == 0 {
-                    dither.dot
-                } else {
-                    dither.alt
-                };
+            if matches!(dither.mode, DitherMode::Luminance { .. }) {
+                match dither.halftone_at(r, c, cell.fg) {
+                    Some(ch) => cell.ch = ch,
+                    None => {
+                        cell.ch = ' ';
+                        cell.visible = false;
+                    }
+                }
+            } else if should_dither(r, c, dither.mode) {
+                cell.ch = dither.dot_at(r, c);
             }
         }
     }
 
     out
 }
-
-fn should_dither(row: usize, col: usize, mode: DitherMode) -> bool {
-    match mode {
-        DitherMode::Checker { period } => {
-            if period == 0 {
-                false
-            } else {
-                (row + col).is_multiple_of(period as usize)
-            }
-        }
-        DitherMode::Noise { seed, threshold } => {
-            let hash = mix(seed, row as u32, col as u32);
-            (hash & 0xFF) < threshold as u32
-        }
-    }
-}
-
-fn mix(seed: u32, x: u32, y: u32) -> u32 {
-    let mut v = seed ^ x.wrapping_mul(0x9E3779B1) ^ y.wrapping_mul(0x85EBCA77);
-    v ^= v >> 16;
-    v = v.wrapping_mul(0x7FEB352D);
-    v ^= v >> 15;
-    v = v.wrapping_mul(0x846CA68B);
-    v ^= v >> 16;
-    v
-}
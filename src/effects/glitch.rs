@@ -0,0 +1,169 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use crate::color::Color;
+use crate::fill::mix;
+use crate::grid::Grid;
+
+/// Glyphs swapped in for scrambled cells by default.
+pub const DEFAULT_GLITCH_CHARSET: &str = "▓▒░█#%&@$*!?<>/\\|=+";
+
+const RED: Color = Color::Rgb(255, 40, 80);
+const CYAN: Color = Color::Rgb(0, 230, 255);
+
+/// Glitch configuration.
+#[derive(Clone, Debug)]
+pub struct GlitchConfig {
+    /// Seed; equal seeds give identical output.
+    pub seed: u32,
+    /// How much of the banner is disturbed (0.0..1.0).
+    pub intensity: f32,
+    /// Replacement glyphs for scrambled cells (empty keeps the original glyphs).
+    pub charset: Vec<char>,
+}
+
+impl GlitchConfig {
+    /// Create a glitch with the default charset.
+    pub fn new(seed: u32, intensity: f32) -> Self {
+        Self {
+            seed,
+            intensity,
+            charset: DEFAULT_GLITCH_CHARSET.chars().collect(),
+        }
+    }
+
+    /// Set the replacement glyphs.
+    pub fn charset(mut self, charset: &str) -> Self {
+        self.charset = charset.chars().collect();
+        self
+    }
+}
+
+/// Shift row segments, scramble glyphs and tint cells red/cyan, deterministically per seed.
+///
+/// Geometry is unchanged: shifted segments are clipped to the row.
+pub fn apply_glitch(grid: &Grid, config: &GlitchConfig) -> Grid {
+    let intensity = config.intensity.clamp(0.0, 1.0);
+    let mut out = grid.clone();
+    let width = grid.width();
+    if intensity <= 0.0 || width == 0 {
+        return out;
+    }
+    let seed = config.seed;
+
+    for (r, row) in out.rows_mut().iter_mut().enumerate() {
+        let r = r as u32;
+        if roll(seed, r, 0) >= intensity * 0.35 {
+            continue;
+        }
+        let start = mix(seed, r, 1) as usize % width;
+        let len = 1 + mix(seed, r, 2) as usize % (width / 2).max(1);
+        let end = (start + len).min(width);
+        let distance = 1 + mix(seed, r, 3) as usize % 3;
+        let segment = row[start..end].to_vec();
+        for cell in &mut row[start..end] {
+            cell.ch = ' ';
+            cell.visible = false;
+        }
+        let left = mix(seed, r, 4) & 1 == 0;
+        for (idx, cell) in segment.into_iter().enumerate() {
+            let col = if left {
+                (start + idx).checked_sub(distance)
+            } else {
+                Some(start + idx + distance)
+            };
+            if let Some(slot) = col.and_then(|col| row.get_mut(col)) {
+                *slot = cell;
+            }
+        }
+    }
+
+    out.map_cells(|r, c, cell| {
+        if !cell.visible {
+            return;
+        }
+        let (r, c) = (r as u32, c as u32);
+        if !config.charset.is_empty() && roll(seed ^ 0x5C4A_11E7, r, c) < intensity * 0.15 {
+            let pick = mix(seed ^ 0x0C4A_12E5, r, c) as usize % config.charset.len();
+            cell.ch = config.charset[pick];
+        }
+        if roll(seed ^ 0x7E1D_0A3B, r, c) < intensity * 0.2 {
+            let shift = if mix(seed ^ 0x3F8B_6C21, r, c) & 1 == 0 {
+                RED
+            } else {
+                CYAN
+            };
+            let base = cell.fg.unwrap_or(Color::Rgb(255, 255, 255));
+            cell.fg = Some(base.lerp(shift, 0.6));
+        }
+    });
+    out
+}
+
+/// Uniform value in `0.0..1.0` for a cell.
+fn roll(seed: u32, x: u32, y: u32) -> f32 {
+    (mix(seed, x, y) & 0xFFFF) as f32 / 65536.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid {
+        let mut grid = Grid::from_char_rows(vec![
+            "████████████████".chars().collect();
+            12
+        ]);
+        grid.map_cells(|_, _, cell| cell.fg = Some(Color::Rgb(120, 120, 120)));
+        grid
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_glitch() {
+        let grid = sample();
+        let config = GlitchConfig::new(7, 0.8);
+        let first = apply_glitch(&grid, &config);
+
+        assert_eq!(
+            first.to_debug_string(),
+            apply_glitch(&grid, &config).to_debug_string()
+        );
+        assert_ne!(first.to_debug_string(), grid.to_debug_string());
+        assert_ne!(
+            first.to_debug_string(),
+            apply_glitch(&grid, &GlitchConfig::new(8, 0.8)).to_debug_string()
+        );
+        assert_eq!(
+            (first.width(), first.height()),
+            (grid.width(), grid.height())
+        );
+        let tinted = first.rows().iter().flatten().filter_map(|cell| cell.fg);
+        assert!(tinted.into_iter().any(|fg| fg != Color::Rgb(120, 120, 120)));
+    }
+
+    #[test]
+    fn zero_intensity_and_empty_charset_leave_glyphs_alone() {
+        let grid = sample();
+        let clean = apply_glitch(&grid, &GlitchConfig::new(3, 0.0));
+        assert_eq!(clean.to_debug_string(), grid.to_debug_string());
+
+        let config = GlitchConfig::new(3, 1.0).charset("");
+        let glitched = apply_glitch(&grid, &config);
+        assert!(
+            glitched
+                .rows()
+                .iter()
+                .flatten()
+                .all(|cell| cell.ch == '█' || cell.ch == ' ')
+        );
+    }
+}
@@ -10,11 +10,15 @@
 // distributed under the License is distributed on an "AS IS" BASIS,
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 
+/// Contrast and readability helpers.
+pub mod contrast;
 /// Dot dithering helpers.
 pub mod dither;
+/// Glitch and scramble helpers.
+pub mod glitch;
 /// Light sweep highlight helpers.
 pub mod light_sweep;
-/// Edge shading helpers.
+/// Edge shading and outline helpers.
 pub mod outline;
 /// Drop shadow helpers.
 pub mod shadow;
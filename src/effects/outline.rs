@@ -22,6 +22,57 @@ pub struct EdgeShade {
     pub darken: f32,
 }
 
+/// Solid outline configuration.
+#[derive(Clone, Copy, Debug)]
+pub struct Outline {
+    /// Outline color.
+    pub color: Color,
+    /// Character used for the outline.
+    pub ch: char,
+    /// Also outline diagonal neighbors.
+    pub diagonals: bool,
+}
+
+/// Draw a solid 1-cell outline around the visible silhouette.
+///
+/// The grid grows by one cell on every side so the outline is never clipped; a grid with
+/// no visible cells has nothing to outline and is returned unchanged.
+pub fn apply_outline(grid: &Grid, outline: Outline) -> Grid {
+    if !grid.rows().iter().flatten().any(|cell| cell.visible) {
+        return grid.clone();
+    }
+    let mut out = Grid::new(grid.height() + 2, grid.width() + 2);
+    out.blit(grid, 1, 1);
+    let neighbors = if outline.diagonals {
+        NEIGHBORS
+    } else {
+        &NEIGHBORS[..4]
+    };
+
+    for r in 0..grid.height() {
+        for c in 0..grid.width() {
+            if !grid.cell(r, c).is_some_and(|cell| cell.visible) {
+                continue;
+            }
+            for (dr, dc) in neighbors {
+                let nr = (r as i32 + 1 + dr) as usize;
+                let nc = (c as i32 + 1 + dc) as usize;
+                let Some(target) = out.cell_mut(nr, nc) else {
+                    continue;
+                };
+                if target.visible {
+                    continue;
+                }
+                target.visible = true;
+                target.ch = outline.ch;
+                target.fg = Some(outline.color);
+            }
+        }
+    }
+
+    out
+}
+
 /// Add a 1-cell shaded edge around visible cells.
 pub fn apply_edge_shade(grid: &Grid, shade: EdgeShade) -> Grid {
     let mut out = grid.clone();
@@ -52,7 +103,7 @@ pub fn apply_edge_shade(grid: &Grid, shade: EdgeShade) -> Grid {
                 }
                 target.visible = true;
                 target.ch = shade.ch;
-                target.fg = cell.fg.map(|color| darken(color, shade.darken));
+                target.fg = cell.fg.map(|color| color.darken(shade.darken));
             }
         }
     }
@@ -60,18 +111,6 @@ pub fn apply_edge_shade(grid: &Grid, shade: EdgeShade) -> Grid {
     out
 }
 
-fn darken(color: Color, amount: f32) -> Color {
-    let factor = (1.0 - amount.clamp(0.0, 1.0)).clamp(0.0, 1.0);
-    match color {
-        Color::Rgb(r, g, b) => Color::Rgb(
-            (r as f32 * factor).round() as u8,
-            (g as f32 * factor).round() as u8,
-            (b as f32 * factor).round() as u8,
-        ),
-        other => other,
-    }
-}
-
 const NEIGHBORS: &[(i32, i32)] = &[
     (-1, 0),
     (1, 0),
@@ -82,3 +121,35 @@ const NEIGHBORS: &[(i32, i32)] = &[
     (1, -1),
     (1, 1),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outline_rows(text: &str, diagonals: bool) -> Vec<String> {
+        let grid = Grid::from_char_rows(vec![text.chars().collect()]);
+        let out = apply_outline(
+            &grid,
+            Outline {
+                color: Color::Rgb(255, 0, 0),
+                ch: 'o',
+                diagonals,
+            },
+        );
+        out.rows()
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.ch).collect())
+            .collect()
+    }
+
+    #[test]
+    fn outlines_with_and_without_diagonals() {
+        assert_eq!(outline_rows("#", true), ["ooo", "o#o", "ooo"]);
+        assert_eq!(outline_rows("#", false), [" o ", "o#o", " o "]);
+    }
+
+    #[test]
+    fn blank_grids_are_not_grown() {
+        assert_eq!(outline_rows("   ", true), ["   "]);
+    }
+}
@@ -20,6 +20,10 @@ pub struct Shadow {
     pub offset: (i32, i32),
     /// Darken factor (0.0..1.0).
     pub alpha: f32,
+    /// Fixed shadow color instead of a darkened copy of the glyph color.
+    ///
+    /// A single shadow uses it as-is; `alpha` then only fades later soft-shadow copies.
+    pub color: Option<Color>,
 }
 
 /// Apply a drop shadow (darkened copy at offset).
@@ -58,21 +62,66 @@ pub fn apply_shadow(grid: &Grid, shadow: Shadow) -> Grid {
 
             target.visible = true;
             target.ch = cell.ch;
-            target.fg = cell.fg.map(|color| darken(color, shadow.alpha));
+            target.fg = match shadow.color {
+                Some(color) => Some(color),
+                None => cell.fg.map(|color| color.darken(shadow.alpha)),
+            };
         }
     }
 
     out
 }
 
-fn darken(color: Color, alpha: f32) -> Color {
-    let factor = (1.0 - alpha.clamp(0.0, 1.0)).clamp(0.0, 1.0);
-    match color {
-        Color::Rgb(r, g, b) => Color::Rgb(
-            (r as f32 * factor).round() as u8,
-            (g as f32 * factor).round() as u8,
-            (b as f32 * factor).round() as u8,
-        ),
-        other => other,
+/// Apply `steps` stacked shadows, each offset again and darkened again.
+///
+/// Copy `k` sits at `k * offset`, so the grid grows to fit the last one.
+pub fn apply_soft_shadow(grid: &Grid, shadow: Shadow, steps: usize) -> Grid {
+    let mut out = grid.clone();
+    let mut step = shadow;
+    for _ in 0..steps {
+        out = apply_shadow(&out, step);
+        step.color = step.color.map(|color| color.darken(shadow.alpha));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn soft_shadow_fades_with_distance() {
+        let mut grid = Grid::from_char_rows(vec!["#".chars().collect()]);
+        grid.rows_mut()[0][0].fg = Some(Color::Rgb(200, 200, 200));
+        let shadow = Shadow {
+            offset: (1, 1),
+            alpha: 0.5,
+            color: None,
+        };
+
+        let out = apply_soft_shadow(&grid, shadow, 3);
+        assert_eq!((out.height(), out.width()), (4, 4));
+        let fg = |k: usize| out.cell(k, k).unwrap().fg;
+        assert_eq!(fg(0), Some(Color::Rgb(200, 200, 200)));
+        assert_eq!(fg(1), Some(Color::Rgb(100, 100, 100)));
+        assert_eq!(fg(2), Some(Color::Rgb(50, 50, 50)));
+        assert_eq!(fg(3), Some(Color::Rgb(25, 25, 25)));
+    }
+
+    #[test]
+    fn fixed_color_shadow_ignores_glyph_color() {
+        let mut grid = Grid::from_char_rows(vec!["#".chars().collect()]);
+        grid.rows_mut()[0][0].fg = Some(Color::Rgb(255, 255, 255));
+        let shadow = Shadow {
+            offset: (1, 0),
+            alpha: 0.5,
+            color: Some(Color::Rgb(0, 0, 200)),
+        };
+
+        let single = apply_shadow(&grid, shadow);
+        assert_eq!(single.cell(0, 1).unwrap().fg, Some(Color::Rgb(0, 0, 200)));
+        let soft = apply_soft_shadow(&grid, shadow, 2);
+        assert_eq!(soft.cell(0, 1).unwrap().fg, Some(Color::Rgb(0, 0, 200)));
+        assert_eq!(soft.cell(0, 2).unwrap().fg, Some(Color::Rgb(0, 0, 100)));
     }
 }
@@ -10,87 +10,1272 @@
 // distributed under the License is distributed on an "AS IS" BASIS,
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 
+use std::fmt;
+use std::io;
+
 use crate::color::{Color, ColorMode};
-use crate::grid::Grid;
+use crate::grid::{Cell, Grid};
 use crate::terminal::detect_color_mode;
 
+/// Options for assembling ANSI output; see [`emit_ansi_with`].
+///
+/// The default matches [`emit_ansi`]: every row resets its colors and keeps its full width.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EmitOptions {
+    /// Reset colors at the end of every row, so rows can be printed independently.
+    ///
+    /// When off, the foreground carries over into the next row; an active background is
+    /// still cleared before each newline so it cannot bleed into the terminal margin.
+    pub reset_per_line: bool,
+    /// Drop trailing spaces from every row, keeping any that carry a visible background.
+    pub trim_trailing_spaces: bool,
+    /// Reset colors at the very end of the output.
+    pub final_reset: bool,
+    /// Wrap linked cells in OSC 8 hyperlinks (never in `NoColor` mode).
+    ///
+    /// Links are closed at the end of every row, so copied lines carry no dangling link.
+    pub hyperlinks: bool,
+}
+
+impl Default for EmitOptions {
+    fn default() -> Self {
+        Self {
+            reset_per_line: true,
+            trim_trailing_spaces: false,
+            final_reset: true,
+            hyperlinks: true,
+        }
+    }
+}
+
+impl EmitOptions {
+    /// Smallest output that still leaves the terminal in its default colors.
+    pub fn compact() -> Self {
+        Self {
+            reset_per_line: false,
+            trim_trailing_spaces: true,
+            final_reset: true,
+            hyperlinks: true,
+        }
+    }
+}
+
 /// Emit ANSI-colored output from a grid.
 pub fn emit_ansi(grid: &Grid, color_mode: ColorMode) -> String {
+    emit_ansi_with(grid, color_mode, &EmitOptions::default())
+}
+
+/// Emit ANSI-colored output from a grid, assembled according to `options`.
+pub fn emit_ansi_with(grid: &Grid, color_mode: ColorMode, options: &EmitOptions) -> String {
+    let mut out = String::new();
+    // Writing into a `String` cannot fail.
+    let _ = push_ansi(&mut out, grid, color_mode, options);
+    out
+}
+
+/// Write the same output as [`emit_ansi`] into `w` without building it in memory first.
+///
+/// Escapes are written piece by piece, so pass a buffered writer (such as
+/// [`io::BufWriter`] or a locked stdout).
+pub fn write_ansi<W: io::Write>(grid: &Grid, color_mode: ColorMode, w: &mut W) -> io::Result<()> {
+    write_ansi_with(grid, color_mode, &EmitOptions::default(), w)
+}
+
+/// Write the same output as [`emit_ansi_with`] into `w`.
+pub fn write_ansi_with<W: io::Write>(
+    grid: &Grid,
+    color_mode: ColorMode,
+    options: &EmitOptions,
+    w: &mut W,
+) -> io::Result<()> {
+    let mut adapter = IoAdapter {
+        inner: w,
+        error: None,
+    };
+    match push_ansi(&mut adapter, grid, color_mode, options) {
+        Ok(()) => Ok(()),
+        Err(_) => Err(adapter
+            .error
+            .unwrap_or_else(|| io::Error::other("formatter error"))),
+    }
+}
+
+/// Forwards `fmt::Write` straight into an `io::Write`, keeping the first I/O error.
+struct IoAdapter<'a, W: io::Write> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+pub(crate) fn push_ansi<W: fmt::Write>(
+    out: &mut W,
+    grid: &Grid,
+    color_mode: ColorMode,
+    options: &EmitOptions,
+) -> fmt::Result {
     let mode = match color_mode {
         ColorMode::Auto => detect_color_mode(),
         other => other,
     };
 
-    let mut out = String::new();
-    let mut current_fg: Option<Color> = None;
+    let mut current = (None, None);
+    for (idx, row) in grid.rows().iter().enumerate() {
+        if idx > 0 {
+            if options.reset_per_line {
+                if current != (None, None) {
+                    out.write_str("\x1b[0m")?;
+                    current = (None, None);
+                }
+            } else if current.1.is_some() {
+                out.write_str("\x1b[49m")?;
+                current.1 = None;
+            }
+            out.write_char('\n')?;
+        }
+        let row = if options.trim_trailing_spaces {
+            let len = row
+                .iter()
+                .rposition(|cell| {
+                    cell.ch != ' ' || (cell.bg.is_some() && mode != ColorMode::NoColor)
+                })
+                .map_or(0, |last| last + 1);
+            &row[..len]
+        } else {
+            row
+        };
+        push_row(out, row, mode, options.hyperlinks, &mut current)?;
+    }
+    if options.final_reset && current != (None, None) {
+        out.write_str("\x1b[0m")?;
+    }
+    Ok(())
+}
 
-    for (row_idx, row) in grid.rows().iter().enumerate() {
+/// Emit the grid with readable tags in place of escape codes, for reviewable goldens.
+///
+/// Colors appear as `{fg=00E5FF}` / `{bg=000050}` when they change and `{/fg}` / `{/bg}` when
+/// they end; hyperlinks as `{link=URL}` / `{/link}`. Every row starts from no colors, so
+/// rows are independent. Literal braces are doubled. Output never depends on the terminal.
+pub fn emit_tagged(grid: &Grid) -> String {
+    let mut lines = Vec::with_capacity(grid.height());
+    for row in grid.rows() {
+        let mut line = String::new();
+        let (mut fg, mut bg, mut link) = (None, None, None);
         for cell in row {
-            match mode {
-                ColorMode::NoColor => {
-                    out.push(cell.ch);
+            push_tag(
+                &mut line,
+                "link",
+                link,
+                cell.link.as_deref(),
+                str::to_string,
+            );
+            link = cell.link.as_deref();
+            push_tag(&mut line, "fg", fg, cell.fg, tag_hex);
+            fg = cell.fg;
+            push_tag(&mut line, "bg", bg, cell.bg, tag_hex);
+            bg = cell.bg;
+            match cell.ch {
+                '{' => line.push_str("{{"),
+                '}' => line.push_str("}}"),
+                ch => line.push(ch),
+            }
+        }
+        push_tag(&mut line, "link", link, None, str::to_string);
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+/// Push `{name=value}` or `{/name}` when the tagged value changes.
+fn push_tag<T: PartialEq>(
+    out: &mut String,
+    name: &str,
+    current: Option<T>,
+    next: Option<T>,
+    value: impl Fn(T) -> String,
+) {
+    if current == next {
+        return;
+    }
+    match next {
+        Some(next) => out.push_str(&format!("{{{name}={}}}", value(next))),
+        None => out.push_str(&format!("{{/{name}}}")),
+    }
+}
+
+fn tag_hex(color: Color) -> String {
+    let (r, g, b) = color.rgb_components();
+    format!("{r:02X}{g:02X}{b:02X}")
+}
+
+/// Remove ANSI escape sequences (CSI such as colors and cursor moves, and OSC such as
+/// hyperlinks), leaving only the visible text.
+pub fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '\x1b' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameters and intermediates, then one final byte in `@..=~`.
+            Some('[') => {
+                for ch in chars.by_ref() {
+                    if ('@'..='~').contains(&ch) {
+                        break;
+                    }
                 }
-                _ => {
-                    if cell.fg != current_fg {
-                        if let Some(color) = cell.fg {
-                            push_fg_code(&mut out, color, mode);
-                        } else {
-                            out.push_str("\x1b[0m");
-                        }
-                        current_fg = cell.fg;
+            }
+            // OSC: terminated by BEL or ST (`ESC \`).
+            Some(']') => {
+                while let Some(ch) = chars.next() {
+                    if ch == '\x07' {
+                        break;
+                    }
+                    if ch == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
                     }
-                    out.push(cell.ch);
                 }
             }
+            // Two-character escapes such as `ESC 7`.
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Emit ANSI-colored output as one string per grid row.
+///
+/// Every line opens its own colors and ends with a reset, so lines can be printed independently.
+pub fn emit_ansi_lines(grid: &Grid, color_mode: ColorMode) -> Vec<String> {
+    let mode = match color_mode {
+        ColorMode::Auto => detect_color_mode(),
+        other => other,
+    };
+
+    grid.rows().iter().map(|row| emit_row(row, mode)).collect()
+}
+
+fn emit_row(row: &[Cell], mode: ColorMode) -> String {
+    let mut out = String::new();
+    let mut current = (None, None);
+    let _ = push_row(&mut out, row, mode, true, &mut current);
+    if current != (None, None) {
+        out.push_str("\x1b[0m");
+    }
+    out
+}
+
+/// Push one row of cells, starting from and updating the active `(fg, bg)` colors.
+///
+/// Hyperlinks never span rows: any link opened here is closed before returning.
+fn push_row<W: fmt::Write>(
+    out: &mut W,
+    row: &[Cell],
+    mode: ColorMode,
+    hyperlinks: bool,
+    (current_fg, current_bg): &mut (Option<Color>, Option<Color>),
+) -> fmt::Result {
+    let hyperlinks = hyperlinks && mode != ColorMode::NoColor;
+    let mut current_link: Option<&str> = None;
+    for cell in row {
+        if hyperlinks && cell.link.as_deref() != current_link {
+            current_link = cell.link.as_deref();
+            push_hyperlink(out, current_link)?;
+        }
+        if mode != ColorMode::NoColor {
+            if (cell.fg.is_none() && current_fg.is_some())
+                || (cell.bg.is_none() && current_bg.is_some())
+            {
+                out.write_str("\x1b[0m")?;
+                *current_fg = None;
+                *current_bg = None;
+            }
+            if cell.fg != *current_fg {
+                if let Some(color) = cell.fg {
+                    push_color_code(out, color, mode, 38)?;
+                }
+                *current_fg = cell.fg;
+            }
+            if cell.bg != *current_bg {
+                if let Some(color) = cell.bg {
+                    push_color_code(out, color, mode, 48)?;
+                }
+                *current_bg = cell.bg;
+            }
+        }
+        out.write_char(cell.ch)?;
+    }
+    if current_link.is_some() {
+        push_hyperlink(out, None)?;
+    }
+    Ok(())
+}
+
+/// Open an OSC 8 hyperlink to `url`, or close the open one with `None`.
+fn push_hyperlink<W: fmt::Write>(out: &mut W, url: Option<&str>) -> fmt::Result {
+    out.write_str("\x1b]8;;")?;
+    // Control bytes (ESC, BEL, ...) would end the sequence early and let the URL inject escapes.
+    for ch in url.unwrap_or("").chars().filter(|ch| !ch.is_control()) {
+        out.write_char(ch)?;
+    }
+    out.write_str("\x1b\\")
+}
+
+/// Push an SGR color code; `layer` is 38 for foreground or 48 for background.
+fn push_color_code<W: fmt::Write>(
+    out: &mut W,
+    color: Color,
+    mode: ColorMode,
+    layer: u8,
+) -> fmt::Result {
+    match mode {
+        ColorMode::TrueColor => match color {
+            Color::Rgb(r, g, b) => write!(out, "\x1b[{layer};2;{r};{g};{b}m"),
+            Color::Ansi256(code) => write!(out, "\x1b[{layer};5;{code}m"),
+        },
+        ColorMode::Ansi256 => write!(out, "\x1b[{layer};5;{}m", color.to_ansi256()),
+        ColorMode::Ansi16 => {
+            let index = color.ansi16_index();
+            // 38 -> 30/90 (foreground), 48 -> 40/100 (background).
+            let base = if index < 8 { layer - 8 } else { layer + 52 };
+            write!(out, "\x1b[{}m", base + index % 8)
         }
+        _ => Ok(()),
+    }
+}
+
+/// Options for [`emit_html`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HtmlOptions {
+    /// Color mode colors are reduced to (`Auto` is treated as truecolor).
+    pub color_mode: ColorMode,
+    /// Color runs with `tb-fg-RRGGBB` / `tb-bg-RRGGBB` classes defined in a leading
+    /// `<style>` block instead of inline `style` attributes.
+    pub css_classes: bool,
+    /// Wrap the `<pre>` in a `<div>` painted with this background color.
+    pub background: Option<Color>,
+}
 
-        if mode != ColorMode::NoColor && current_fg.is_some() {
-            out.push_str("\x1b[0m");
-            current_fg = None;
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        Self {
+            color_mode: ColorMode::TrueColor,
+            css_classes: false,
+            background: None,
         }
+    }
+}
+
+impl HtmlOptions {
+    /// Create options with the defaults (truecolor inline styles, no wrapper).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the color mode colors are reduced to.
+    pub fn color_mode(mut self, mode: ColorMode) -> Self {
+        self.color_mode = mode;
+        self
+    }
+
+    /// Use CSS classes instead of inline styles.
+    pub fn css_classes(mut self, enabled: bool) -> Self {
+        self.css_classes = enabled;
+        self
+    }
+
+    /// Wrap the output in a `<div>` with a background color.
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
 
-        if row_idx + 1 < grid.height() {
+    /// Wrap the output in a dark (`#0d1117`) background, for light pages.
+    pub fn dark_background(self) -> Self {
+        self.background(Color::Rgb(13, 17, 23))
+    }
+}
+
+/// Emit an HTML `<pre>` block with `<span>` color runs.
+///
+/// Adjacent cells with the same colors share one span, and `<`, `>` and `&` are escaped.
+pub fn emit_html(grid: &Grid, options: &HtmlOptions) -> String {
+    let mode = match options.color_mode {
+        ColorMode::Auto => ColorMode::TrueColor,
+        other => other,
+    };
+
+    let mut out = String::new();
+    if options.css_classes && mode != ColorMode::NoColor {
+        push_html_classes(&mut out, grid, mode);
+    }
+    if let Some(background) = options.background {
+        out.push_str(&format!(
+            "<div style=\"background-color:{};padding:1em\">",
+            css_hex(background, ColorMode::TrueColor)
+        ));
+    }
+    out.push_str("<pre>");
+    for (idx, row) in grid.rows().iter().enumerate() {
+        if idx > 0 {
             out.push('\n');
         }
+        emit_html_row(&mut out, row, mode, options.css_classes);
     }
+    out.push_str("</pre>");
+    if options.background.is_some() {
+        out.push_str("</div>");
+    }
+    out
+}
+
+/// Push a `<style>` block defining one class per color used, in a stable order.
+fn push_html_classes(out: &mut String, grid: &Grid, mode: ColorMode) {
+    let mut fgs = std::collections::BTreeSet::new();
+    let mut bgs = std::collections::BTreeSet::new();
+    for cell in grid.rows().iter().flatten() {
+        fgs.extend(cell.fg.map(|color| css_hex(color, mode)));
+        bgs.extend(cell.bg.map(|color| css_hex(color, mode)));
+    }
+    out.push_str("<style>\n");
+    for hex in fgs {
+        out.push_str(&format!(".tb-fg-{}{{color:{hex}}}\n", &hex[1..]));
+    }
+    for hex in bgs {
+        out.push_str(&format!(".tb-bg-{}{{background-color:{hex}}}\n", &hex[1..]));
+    }
+    out.push_str("</style>\n");
+}
+
+fn emit_html_row(out: &mut String, row: &[Cell], mode: ColorMode, classes: bool) {
+    let mut current: Option<(Option<Color>, Option<Color>)> = None;
+
+    for cell in row {
+        if mode != ColorMode::NoColor {
+            let colors = (cell.fg, cell.bg);
+            if current != Some(colors) {
+                if matches!(current, Some((fg, bg)) if fg.is_some() || bg.is_some()) {
+                    out.push_str("</span>");
+                }
+                if colors.0.is_some() || colors.1.is_some() {
+                    push_html_span(out, colors.0, colors.1, mode, classes);
+                }
+                current = Some(colors);
+            }
+        }
+        match cell.ch {
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '&' => out.push_str("&amp;"),
+            ch => out.push(ch),
+        }
+    }
+
+    if matches!(current, Some((fg, bg)) if fg.is_some() || bg.is_some()) {
+        out.push_str("</span>");
+    }
+}
+
+fn push_html_span(
+    out: &mut String,
+    fg: Option<Color>,
+    bg: Option<Color>,
+    mode: ColorMode,
+    classes: bool,
+) {
+    if classes {
+        let mut names = Vec::new();
+        if let Some(color) = fg {
+            names.push(format!("tb-fg-{}", &css_hex(color, mode)[1..]));
+        }
+        if let Some(color) = bg {
+            names.push(format!("tb-bg-{}", &css_hex(color, mode)[1..]));
+        }
+        out.push_str(&format!("<span class=\"{}\">", names.join(" ")));
+        return;
+    }
+    let mut style = Vec::new();
+    if let Some(color) = fg {
+        style.push(format!("color:{}", css_hex(color, mode)));
+    }
+    if let Some(color) = bg {
+        style.push(format!("background-color:{}", css_hex(color, mode)));
+    }
+    out.push_str(&format!("<span style=\"{}\">", style.join(";")));
+}
+
+fn css_hex(color: Color, mode: ColorMode) -> String {
+    let color = match (mode, color) {
+        (ColorMode::Ansi256, color) => Color::Ansi256(color.to_ansi256()),
+        (ColorMode::Ansi16, color) => Color::Ansi256(color.ansi16_index()),
+        _ => color,
+    };
+    let (r, g, b) = color.rgb_components();
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+/// Options for [`emit_svg`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SvgOptions {
+    /// Cell width in pixels.
+    pub cell_width: u32,
+    /// Cell height in pixels (also the font size).
+    pub cell_height: u32,
+    /// CSS font family for the glyphs.
+    pub font_family: String,
+    /// Color of the rect painted behind the whole banner (`None` leaves it transparent).
+    pub background: Option<Color>,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            cell_width: 10,
+            cell_height: 20,
+            font_family: "monospace".to_string(),
+            background: Some(Color::Rgb(13, 17, 23)),
+        }
+    }
+}
 
+impl SvgOptions {
+    /// Create options with the defaults (10 x 20 cells, `monospace`, dark background).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the cell size in pixels.
+    pub fn cell_size(mut self, width: u32, height: u32) -> Self {
+        self.cell_width = width;
+        self.cell_height = height;
+        self
+    }
+
+    /// Set the CSS font family.
+    pub fn font_family(mut self, family: impl Into<String>) -> Self {
+        self.font_family = family.into();
+        self
+    }
+
+    /// Set (or clear, with `None`) the background rect color.
+    pub fn background(mut self, color: Option<Color>) -> Self {
+        self.background = color;
+        self
+    }
+}
+
+/// Emit an SVG document with one `<text>` per row and one `<tspan>` per color run.
+///
+/// Every glyph is pinned to its cell on the monospace grid, and runs of cells sharing a
+/// background become one `<rect>` behind the text. Output is deterministic.
+pub fn emit_svg(grid: &Grid, options: &SvgOptions) -> String {
+    let (cell_width, cell_height) = (options.cell_width, options.cell_height);
+    let width = grid.width() as u32 * cell_width;
+    let height = grid.height() as u32 * cell_height;
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">\n"
+    );
+    if let Some(background) = options.background {
+        out.push_str(&format!(
+            "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+            css_hex(background, ColorMode::TrueColor)
+        ));
+    }
+    out.push_str(&format!(
+        "<g font-family=\"{}\" font-size=\"{cell_height}\" fill=\"currentColor\">\n",
+        xml_escape_str(&options.font_family)
+    ));
+
+    for (r, row) in grid.rows().iter().enumerate() {
+        let y = r as u32 * cell_height;
+        for (start, len, bg) in runs(row, |cell| cell.bg) {
+            let Some(bg) = bg else { continue };
+            out.push_str(&format!(
+                "<rect x=\"{}\" y=\"{y}\" width=\"{}\" height=\"{cell_height}\" fill=\"{}\"/>\n",
+                start as u32 * cell_width,
+                len as u32 * cell_width,
+                css_hex(bg, ColorMode::TrueColor)
+            ));
+        }
+
+        let glyph = |cell: &Cell| (cell.visible && cell.ch != ' ').then_some(cell.fg);
+        let spans: Vec<_> = runs(row, glyph)
+            .into_iter()
+            .filter_map(|(start, len, fg)| fg.map(|fg| (start, len, fg)))
+            .collect();
+        if spans.is_empty() {
+            continue;
+        }
+        // Baseline sits at 80% of the cell so glyph descenders stay inside it.
+        out.push_str(&format!("<text y=\"{}\">", y + cell_height * 4 / 5));
+        for (start, len, fg) in spans {
+            let xs: Vec<String> = (start..start + len)
+                .map(|c| (c as u32 * cell_width).to_string())
+                .collect();
+            let fill = fg
+                .map(|fg| format!(" fill=\"{}\"", css_hex(fg, ColorMode::TrueColor)))
+                .unwrap_or_default();
+            let text: String = row[start..start + len]
+                .iter()
+                .map(|cell| xml_escape(cell.ch))
+                .collect();
+            out.push_str(&format!(
+                "<tspan x=\"{}\"{fill}>{text}</tspan>",
+                xs.join(" ")
+            ));
+        }
+        out.push_str("</text>\n");
+    }
+
+    out.push_str("</g>\n</svg>\n");
     out
 }
 
-fn push_fg_code(out: &mut String, color: Color, mode: ColorMode) {
-    match mode {
-        ColorMode::TrueColor => match color {
-            Color::Rgb(r, g, b) => {
-                out.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+/// Options for [`emit_png`] and [`emit_kitty`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RasterOptions {
+    /// Cell width in pixels.
+    pub cell_width: u32,
+    /// Cell height in pixels.
+    pub cell_height: u32,
+    /// Color for glyphs without a foreground.
+    pub foreground: Color,
+    /// Color behind the whole banner (`None` leaves it transparent).
+    pub background: Option<Color>,
+}
+
+impl Default for RasterOptions {
+    fn default() -> Self {
+        Self {
+            cell_width: 8,
+            cell_height: 16,
+            foreground: Color::Rgb(230, 237, 243),
+            background: Some(Color::Rgb(13, 17, 23)),
+        }
+    }
+}
+
+impl RasterOptions {
+    /// Create options with the defaults (8 x 16 cells, light glyphs on a dark background).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the cell size in pixels.
+    pub fn cell_size(mut self, width: u32, height: u32) -> Self {
+        self.cell_width = width;
+        self.cell_height = height;
+        self
+    }
+
+    /// Set the color used for glyphs without a foreground.
+    pub fn foreground(mut self, color: Color) -> Self {
+        self.foreground = color;
+        self
+    }
+
+    /// Set (or clear, with `None`) the background color.
+    pub fn background(mut self, color: Option<Color>) -> Self {
+        self.background = color;
+        self
+    }
+}
+
+/// Error from [`emit_png`] and [`emit_kitty`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RasterError {
+    /// The image would exceed [`RasterError::MAX_PIXELS`] pixels (or PNG's 2^31 - 1 pixel side).
+    TooLarge {
+        /// Requested width in pixels (saturating).
+        width: u64,
+        /// Requested height in pixels (saturating).
+        height: u64,
+    },
+}
+
+impl RasterError {
+    /// Largest image, in pixels, that is rasterized (1 GiB of RGBA).
+    pub const MAX_PIXELS: u64 = 1 << 28;
+}
+
+impl fmt::Display for RasterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RasterError::TooLarge { width, height } => {
+                write!(
+                    f,
+                    "a {width}x{height} pixel image is too large to rasterize"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for RasterError {}
+
+/// Rasterize the grid into a PNG image, one solid block per cell.
+///
+/// Cell backgrounds are filled first; glyphs then cover their cell in the foreground color.
+/// Half blocks (`▀▄▌▐`) cover half a cell and shades (`░▒▓`) blend at 25/50/75%; every
+/// other glyph fills the whole cell. Output is deterministic. PNG has no empty images, so an
+/// empty grid (or a zero cell size) yields a single transparent pixel; images above
+/// [`RasterError::MAX_PIXELS`] are rejected.
+pub fn emit_png(grid: &Grid, options: &RasterOptions) -> Result<Vec<u8>, RasterError> {
+    let side = |cells: usize, size: u32| (cells as u64).saturating_mul(u64::from(size));
+    let (width, height) = (
+        side(grid.width(), options.cell_width),
+        side(grid.height(), options.cell_height),
+    );
+    if width == 0 || height == 0 {
+        return Ok(crate::png::encode_rgba(1, 1, &[0; 4]));
+    }
+    if width.saturating_mul(height) > RasterError::MAX_PIXELS {
+        return Err(RasterError::TooLarge { width, height });
+    }
+    // Both sides are at most MAX_PIXELS here, well inside PNG's limit and `usize`.
+    let (width, height) = (width as usize, height as usize);
+    let (cell_width, cell_height) = (options.cell_width as usize, options.cell_height as usize);
+    let clear = match options.background {
+        Some(color) => rgba(color, 255),
+        None => [0; 4],
+    };
+    let mut pixels = clear.repeat(width * height);
+
+    let mut fill = |r: usize, c: usize, (x0, y0, x1, y1): (usize, usize, usize, usize), color| {
+        for y in r * cell_height + y0..r * cell_height + y1 {
+            let row = y * width;
+            for x in c * cell_width + x0..c * cell_width + x1 {
+                let pixel = &mut pixels[(row + x) * 4..(row + x) * 4 + 4];
+                pixel.copy_from_slice(&blend(color, pixel));
             }
-            Color::Ansi256(code) => {
-                out.push_str(&format!("\x1b[38;5;{}m", code));
+        }
+    };
+    let (w, h) = (cell_width, cell_height);
+    for (r, row) in grid.rows().iter().enumerate() {
+        for (c, cell) in row.iter().enumerate() {
+            if let Some(bg) = cell.bg {
+                fill(r, c, (0, 0, w, h), rgba(bg, 255));
             }
-        },
-        ColorMode::Ansi256 => {
-            let code = match color {
-                Color::Ansi256(v) => v,
-                Color::Rgb(r, g, b) => rgb_to_ansi256(r, g, b),
+            if !cell.visible || cell.ch == ' ' {
+                continue;
+            }
+            let fg = cell.fg.unwrap_or(options.foreground);
+            let (area, alpha) = match cell.ch {
+                '▀' => ((0, 0, w, h / 2), 255),
+                '▄' => ((0, h / 2, w, h), 255),
+                '▌' => ((0, 0, w / 2, h), 255),
+                '▐' => ((w / 2, 0, w, h), 255),
+                '░' => ((0, 0, w, h), 64),
+                '▒' => ((0, 0, w, h), 128),
+                '▓' => ((0, 0, w, h), 191),
+                _ => ((0, 0, w, h), 255),
             };
-            out.push_str(&format!("\x1b[38;5;{}m", code));
+            fill(r, c, area, rgba(fg, alpha));
         }
-        _ => {}
     }
+
+    Ok(crate::png::encode_rgba(
+        width as u32,
+        height as u32,
+        &pixels,
+    ))
 }
 
-fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
-    if r == g && g == b {
-        if r < 8 {
-            return 16;
+/// Emit the [`emit_png`] image as a kitty graphics protocol escape sequence.
+///
+/// The base64 payload is split into 4096-byte chunks as the protocol requires. The image
+/// is placed at the cursor; print a newline afterwards to move below it. Fails like
+/// [`emit_png`].
+pub fn emit_kitty(grid: &Grid, options: &RasterOptions) -> Result<String, RasterError> {
+    let payload = crate::png::base64(&emit_png(grid, options)?);
+    let mut out = String::with_capacity(payload.len() + payload.len() / 4096 * 16 + 32);
+    let mut start = 0;
+    loop {
+        // Base64 is ASCII, so any byte offset is a char boundary.
+        let end = (start + 4096).min(payload.len());
+        let keys = if start == 0 { "a=T,f=100," } else { "" };
+        let more = u8::from(end < payload.len());
+        out.push_str(&format!(
+            "\x1b_G{keys}m={more};{}\x1b\\",
+            &payload[start..end]
+        ));
+        if end == payload.len() {
+            break;
         }
-        if r > 248 {
-            return 231;
+        start = end;
+    }
+    Ok(out)
+}
+
+fn rgba(color: Color, alpha: u8) -> [u8; 4] {
+    let (r, g, b) = color.rgb_components();
+    [r, g, b, alpha]
+}
+
+/// Composite `src` over `dst` (straight alpha).
+fn blend(src: [u8; 4], dst: &[u8]) -> [u8; 4] {
+    let src_a = f32::from(src[3]) / 255.0;
+    let dst_a = f32::from(dst[3]) / 255.0 * (1.0 - src_a);
+    let out_a = src_a + dst_a;
+    if out_a == 0.0 {
+        return [0; 4];
+    }
+    let channel = |idx: usize| {
+        ((f32::from(src[idx]) * src_a + f32::from(dst[idx]) * dst_a) / out_a).round() as u8
+    };
+    [
+        channel(0),
+        channel(1),
+        channel(2),
+        (out_a * 255.0).round() as u8,
+    ]
+}
+
+/// Split a row into `(start, len, key)` runs of adjacent cells with equal keys.
+fn runs<K: PartialEq + Copy>(row: &[Cell], key: impl Fn(&Cell) -> K) -> Vec<(usize, usize, K)> {
+    let mut out: Vec<(usize, usize, K)> = Vec::new();
+    for (c, cell) in row.iter().enumerate() {
+        let k = key(cell);
+        match out.last_mut() {
+            Some((_, len, last)) if *last == k => *len += 1,
+            _ => out.push((c, 1, k)),
         }
-        return 232 + ((r as u16 - 8) / 10) as u8;
     }
+    out
+}
+
+fn xml_escape(ch: char) -> String {
+    match ch {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        '"' => "&quot;".to_string(),
+        '\'' => "&apos;".to_string(),
+        ch => ch.to_string(),
+    }
+}
+
+fn xml_escape_str(value: &str) -> String {
+    value.chars().map(xml_escape).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ansi16_maps_to_nearest_basic_colors() {
+        let sgr = |color: Color| {
+            let mut out = String::new();
+            push_color_code(&mut out, color, ColorMode::Ansi16, 38).unwrap();
+            out
+        };
+        assert_eq!(sgr(Color::Rgb(255, 0, 0)), "\x1b[91m");
+        assert_eq!(sgr(Color::Rgb(30, 30, 30)), "\x1b[30m");
+        assert_eq!(sgr(Color::Rgb(70, 70, 70)), "\x1b[90m");
+        assert_eq!(sgr(Color::Rgb(110, 110, 110)), "\x1b[90m");
+        assert_eq!(sgr(Color::Rgb(20, 170, 180)), "\x1b[36m");
+        assert_eq!(sgr(Color::Ansi256(3)), "\x1b[33m");
 
-    let rc = (r as u16 * 5 / 255) as u8;
-    let gc = (g as u16 * 5 / 255) as u8;
-    let bc = (b as u16 * 5 / 255) as u8;
-    16 + 36 * rc + 6 * gc + bc
+        let mut bg = String::new();
+        push_color_code(&mut bg, Color::Rgb(0, 0, 250), ColorMode::Ansi16, 48).unwrap();
+        assert_eq!(bg, "\x1b[104m");
+    }
+
+    #[test]
+    fn ansi_tracks_background_runs() {
+        let mut grid = Grid::from_char_rows(vec!["ab c".chars().collect()]);
+        for cell in &mut grid.rows_mut()[0][..3] {
+            cell.bg = Some(Color::Rgb(0, 0, 80));
+        }
+        grid.rows_mut()[0][0].fg = Some(Color::Rgb(255, 255, 255));
+
+        assert_eq!(
+            emit_ansi(&grid, ColorMode::TrueColor),
+            "\x1b[38;2;255;255;255m\x1b[48;2;0;0;80ma\x1b[0m\x1b[48;2;0;0;80mb \x1b[0mc"
+        );
+        assert_eq!(
+            emit_ansi(&grid, ColorMode::Ansi256),
+            "\x1b[38;5;231m\x1b[48;5;17ma\x1b[0m\x1b[48;5;17mb \x1b[0mc"
+        );
+        assert_eq!(emit_ansi(&grid, ColorMode::NoColor), "ab c");
+    }
+
+    #[test]
+    fn ansi_lines_open_and_reset_colors_per_row() {
+        let mut grid = Grid::from_char_rows(vec![
+            "ab".chars().collect(),
+            "cd".chars().collect(),
+            "ef".chars().collect(),
+        ]);
+        for row in &mut grid.rows_mut()[..2] {
+            for cell in row {
+                cell.fg = Some(Color::Rgb(255, 0, 0));
+            }
+        }
+
+        assert_eq!(
+            emit_ansi_lines(&grid, ColorMode::TrueColor),
+            vec![
+                "\x1b[38;2;255;0;0mab\x1b[0m",
+                "\x1b[38;2;255;0;0mcd\x1b[0m",
+                "ef",
+            ]
+        );
+        assert_eq!(
+            emit_ansi_lines(&grid, ColorMode::NoColor),
+            vec!["ab", "cd", "ef"]
+        );
+        assert_eq!(
+            emit_ansi_lines(&grid, ColorMode::TrueColor).join("\n"),
+            emit_ansi(&grid, ColorMode::TrueColor)
+        );
+    }
+
+    #[test]
+    fn html_collapses_runs_and_escapes() {
+        let mut grid = Grid::from_char_rows(vec!["<&>".chars().collect(), "ab".chars().collect()]);
+        for cell in &mut grid.rows_mut()[0] {
+            cell.fg = Some(Color::Rgb(255, 0, 0));
+        }
+        grid.rows_mut()[1][1].bg = Some(Color::Rgb(0, 0, 255));
+
+        assert_eq!(
+            emit_html(&grid, &HtmlOptions::new()),
+            "<pre><span style=\"color:#ff0000\">&lt;&amp;&gt;</span>\n\
+             a<span style=\"background-color:#0000ff\">b</span></pre>"
+        );
+        assert_eq!(
+            emit_html(&grid, &HtmlOptions::new().color_mode(ColorMode::NoColor)),
+            "<pre>&lt;&amp;&gt;\nab</pre>"
+        );
+
+        let classes = HtmlOptions::new().css_classes(true).dark_background();
+        assert_eq!(
+            emit_html(&grid, &classes),
+            "<style>\n.tb-fg-ff0000{color:#ff0000}\n.tb-bg-0000ff{background-color:#0000ff}\n</style>\n\
+             <div style=\"background-color:#0d1117;padding:1em\">\
+             <pre><span class=\"tb-fg-ff0000\">&lt;&amp;&gt;</span>\n\
+             a<span class=\"tb-bg-0000ff\">b</span></pre></div>"
+        );
+    }
+
+    #[test]
+    fn svg_scales_cells_and_draws_backgrounds() {
+        let mut grid = Grid::from_char_rows(vec!["AB<".chars().collect(), "   ".chars().collect()]);
+        for cell in &mut grid.rows_mut()[0][..2] {
+            cell.fg = Some(Color::Rgb(0, 255, 0));
+        }
+        for cell in &mut grid.rows_mut()[1] {
+            cell.bg = Some(Color::Rgb(16, 16, 16));
+        }
+
+        let options = SvgOptions::new()
+            .cell_size(10, 20)
+            .font_family("Fira Code, \"Menlo\"")
+            .background(None);
+        let svg = emit_svg(&grid, &options);
+        assert!(svg.contains("width=\"30\" height=\"40\""));
+        assert!(svg.contains("font-family=\"Fira Code, &quot;Menlo&quot;\""));
+        assert!(svg.contains(
+            "<text y=\"16\"><tspan x=\"0 10\" fill=\"#00ff00\">AB</tspan><tspan x=\"20\">&lt;</tspan></text>"
+        ));
+        assert!(
+            svg.contains("<rect x=\"0\" y=\"20\" width=\"30\" height=\"20\" fill=\"#101010\"/>")
+        );
+        assert!(!svg.contains("100%"));
+        assert!(emit_svg(&grid, &SvgOptions::new()).contains("<rect width=\"100%\""));
+        assert_eq!(svg, emit_svg(&grid, &options));
+        assert_well_formed(&svg);
+    }
+
+    /// Minimal XML check: tags balance and every `&` starts a known entity.
+    fn assert_well_formed(xml: &str) {
+        let mut stack = Vec::new();
+        let mut rest = xml;
+        while let Some(open) = rest.find('<') {
+            let text = &rest[..open];
+            for (idx, _) in text.match_indices('&') {
+                let entity = &text[idx..];
+                assert!(
+                    ["&lt;", "&gt;", "&amp;", "&quot;", "&apos;"]
+                        .iter()
+                        .any(|known| entity.starts_with(known)),
+                    "bare & in {text:?}"
+                );
+            }
+            let close = rest[open..].find('>').expect("unterminated tag") + open;
+            let tag = &rest[open + 1..close];
+            let name = tag
+                .trim_start_matches('/')
+                .split([' ', '/'])
+                .next()
+                .unwrap();
+            if let Some(closing) = tag.strip_prefix('/') {
+                assert_eq!(
+                    stack.pop(),
+                    Some(closing.to_string()),
+                    "mismatched </{closing}>"
+                );
+            } else if !tag.ends_with('/') {
+                stack.push(name.to_string());
+            }
+            rest = &rest[close + 1..];
+        }
+        assert!(stack.is_empty(), "unclosed tags: {stack:?}");
+    }
+
+    #[test]
+    fn compact_emit_carries_colors_and_keeps_bg_spaces() {
+        let red = Some(Color::Rgb(255, 0, 0));
+        let mut grid =
+            Grid::from_char_rows(vec!["a   ".chars().collect(), "b   ".chars().collect()]);
+        for row in grid.rows_mut() {
+            row[0].fg = red;
+        }
+        grid.rows_mut()[1][1].bg = Some(Color::Rgb(0, 0, 80));
+
+        assert_eq!(
+            emit_ansi_with(&grid, ColorMode::TrueColor, &EmitOptions::default()),
+            emit_ansi(&grid, ColorMode::TrueColor)
+        );
+        assert_eq!(
+            emit_ansi_with(&grid, ColorMode::TrueColor, &EmitOptions::compact()),
+            "\x1b[38;2;255;0;0ma\nb\x1b[0m\x1b[48;2;0;0;80m \x1b[0m"
+        );
+        let unterminated = EmitOptions {
+            final_reset: false,
+            ..EmitOptions::compact()
+        };
+        assert_eq!(
+            emit_ansi_with(&grid, ColorMode::NoColor, &unterminated),
+            "a\nb"
+        );
+    }
+
+    #[test]
+    fn tagged_output_names_colors_and_links() {
+        let mut grid = Grid::from_char_rows(vec!["a{b".chars().collect(), "cd".chars().collect()]);
+        grid.rows_mut()[0][0].fg = Some(Color::Rgb(0, 229, 255));
+        grid.rows_mut()[0][1].fg = Some(Color::Rgb(0, 229, 255));
+        grid.rows_mut()[0][1].bg = Some(Color::Ansi256(196));
+        grid.rows_mut()[1][0].fg = Some(Color::Rgb(0, 229, 255));
+        grid.rows_mut()[1][1].link = Some("https://example.com".into());
+
+        assert_eq!(
+            emit_tagged(&grid),
+            "{fg=00E5FF}a{bg=FF0000}{{{/fg}{/bg}b\n{fg=00E5FF}c{link=https://example.com}{/fg}d{/link}"
+        );
+    }
+
+    #[test]
+    fn hyperlinks_are_framed_per_line() {
+        let mut grid = Grid::from_char_rows(vec!["ab".chars().collect(), "cd".chars().collect()]);
+        let url: std::sync::Arc<str> = "https://example.com".into();
+        for row in grid.rows_mut() {
+            row[1].link = Some(url.clone());
+        }
+        let unlinked = emit_ansi(
+            &Grid::from_char_rows(vec!["ab".chars().collect(); 2]),
+            ColorMode::TrueColor,
+        );
+        assert_eq!(unlinked, "ab\nab");
+
+        let open = "\x1b]8;;https://example.com\x1b\\";
+        let close = "\x1b]8;;\x1b\\";
+        assert_eq!(
+            emit_ansi(&grid, ColorMode::TrueColor),
+            format!("a{open}b{close}\nc{open}d{close}")
+        );
+        assert_eq!(emit_ansi(&grid, ColorMode::NoColor), "ab\ncd");
+        let no_links = EmitOptions {
+            hyperlinks: false,
+            ..EmitOptions::default()
+        };
+        assert_eq!(
+            emit_ansi_with(&grid, ColorMode::TrueColor, &no_links),
+            "ab\ncd"
+        );
+        assert_eq!(strip_ansi(&emit_ansi(&grid, ColorMode::Ansi16)), "ab\ncd");
+    }
+
+    #[test]
+    fn hyperlink_urls_drop_control_bytes() {
+        let mut grid = Grid::from_char_rows(vec!["a".chars().collect()]);
+        grid.rows_mut()[0][0].link = Some("https://x.test/\x1b[2J\x07\u{9b}y".into());
+        assert_eq!(
+            emit_ansi(&grid, ColorMode::TrueColor),
+            "\x1b]8;;https://x.test/[2Jy\x1b\\a\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn write_ansi_matches_emit_and_reports_errors() {
+        let mut grid = Grid::from_char_rows(vec!["ab".chars().collect(), "c ".chars().collect()]);
+        grid.rows_mut()[0][0].fg = Some(Color::Rgb(1, 2, 3));
+        grid.rows_mut()[1][1].bg = Some(Color::Rgb(4, 5, 6));
+
+        let mut written = Vec::new();
+        write_ansi(&grid, ColorMode::TrueColor, &mut written).unwrap();
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            emit_ansi(&grid, ColorMode::TrueColor)
+        );
+
+        let mut full = [0u8; 4];
+        let err = write_ansi(&grid, ColorMode::TrueColor, &mut &mut full[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    }
+
+    #[test]
+    fn strip_ansi_keeps_visible_text() {
+        assert_eq!(strip_ansi("\x1b[38;2;1;2;3mab\x1b[0m\nc"), "ab\nc");
+        assert_eq!(strip_ansi("\x1b[2J\x1b[?25lx\x1b[H"), "x");
+        assert_eq!(
+            strip_ansi("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07!"),
+            "link!"
+        );
+        assert_eq!(strip_ansi("plain █"), "plain █");
+    }
+
+    /// Decode a PNG from [`emit_png`] into `(width, height, rgba)`.
+    fn decode_png(png: &[u8]) -> (u32, u32, Vec<u8>) {
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        let (mut pos, mut idat) = (8, Vec::new());
+        let (mut width, mut height) = (0, 0);
+        while pos < png.len() {
+            let len = u32::from_be_bytes(png[pos..pos + 4].try_into().unwrap()) as usize;
+            let data = &png[pos + 8..pos + 8 + len];
+            match &png[pos + 4..pos + 8] {
+                b"IHDR" => {
+                    width = u32::from_be_bytes(data[..4].try_into().unwrap());
+                    height = u32::from_be_bytes(data[4..8].try_into().unwrap());
+                    assert_eq!(&data[8..10], &[8, 6]);
+                }
+                b"IDAT" => idat.extend_from_slice(data),
+                _ => {}
+            }
+            pos += len + 12;
+        }
+        let raw = inflate(&idat);
+        let stride = width as usize * 4 + 1;
+        assert_eq!(raw.len(), stride * height as usize);
+        let pixels = raw
+            .chunks(stride)
+            .flat_map(|row| row[1..].to_vec())
+            .collect();
+        (width, height, pixels)
+    }
+
+    #[cfg(feature = "gzip")]
+    fn inflate(stream: &[u8]) -> Vec<u8> {
+        use std::io::Read;
+        let mut out = Vec::new();
+        flate2::read::ZlibDecoder::new(stream)
+            .read_to_end(&mut out)
+            .unwrap();
+        out
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    fn inflate(stream: &[u8]) -> Vec<u8> {
+        let (mut pos, mut out) = (2, Vec::new());
+        loop {
+            let last = stream[pos] == 1;
+            let len = u16::from_le_bytes([stream[pos + 1], stream[pos + 2]]) as usize;
+            out.extend_from_slice(&stream[pos + 5..pos + 5 + len]);
+            pos += 5 + len;
+            if last {
+                return out;
+            }
+        }
+    }
+
+    #[test]
+    fn png_rasterizes_cells_into_blocks() {
+        let mut grid = Grid::from_char_rows(vec!["█▀ ".chars().collect()]);
+        grid.rows_mut()[0][0].fg = Some(Color::Rgb(255, 0, 0));
+        grid.rows_mut()[0][2].bg = Some(Color::Rgb(0, 0, 80));
+        let options = RasterOptions::new().cell_size(2, 4);
+        let (width, height, pixels) = decode_png(&emit_png(&grid, &options).unwrap());
+        let pixel = |x: usize, y: usize| &pixels[(y * width as usize + x) * 4..][..4];
+
+        assert_eq!((width, height), (6, 4));
+        assert_eq!(pixel(1, 3), [255, 0, 0, 255]);
+        // Upper half block in the default foreground over the background.
+        assert_eq!(pixel(2, 1), [230, 237, 243, 255]);
+        assert_eq!(pixel(2, 2), [13, 17, 23, 255]);
+        assert_eq!(pixel(5, 0), [0, 0, 80, 255]);
+
+        let transparent = RasterOptions::new().cell_size(2, 4).background(None);
+        let (_, _, pixels) = decode_png(&emit_png(&grid, &transparent).unwrap());
+        assert_eq!(pixels[(2 * 6 + 3) * 4 + 3], 0);
+        assert_eq!(emit_png(&grid, &options), emit_png(&grid, &options));
+    }
+
+    #[test]
+    fn png_of_an_empty_grid_is_one_transparent_pixel() {
+        let options = RasterOptions::new();
+        let (width, height, pixels) = decode_png(&emit_png(&Grid::new(0, 0), &options).unwrap());
+        assert_eq!((width, height, pixels), (1, 1, vec![0; 4]));
+        let zero_cells = RasterOptions::new().cell_size(0, 16);
+        let grid = Grid::from_char_rows(vec!["ab".chars().collect()]);
+        assert_eq!(decode_png(&emit_png(&grid, &zero_cells).unwrap()).0, 1);
+    }
+
+    #[test]
+    fn oversized_png_is_rejected() {
+        let grid = Grid::from_char_rows(vec!["ab".chars().collect()]);
+        let huge = RasterOptions::new().cell_size(u32::MAX, u32::MAX);
+        let err = emit_png(&grid, &huge).unwrap_err();
+        let side = 2 * u64::from(u32::MAX);
+        assert_eq!(
+            err,
+            RasterError::TooLarge {
+                width: side,
+                height: u64::from(u32::MAX)
+            }
+        );
+        assert!(emit_kitty(&grid, &huge).is_err());
+
+        let over = RasterOptions::new().cell_size(1 << 15, 1 << 14);
+        assert!(emit_png(&Grid::new(1, 1), &over).is_err());
+    }
+
+    #[test]
+    fn kitty_wraps_png_in_chunked_escapes() {
+        let mut grid = Grid::from_char_rows(vec!["█".repeat(80).chars().collect(); 30]);
+        // Distinct colors keep the image large enough to need several chunks when compressed.
+        for (r, row) in grid.rows_mut().iter_mut().enumerate() {
+            for (c, cell) in row.iter_mut().enumerate() {
+                let seed = ((r * 80 + c) as u32).wrapping_mul(2_654_435_761);
+                let [red, green, blue, _] = seed.to_le_bytes();
+                cell.fg = Some(Color::Rgb(red, green, blue));
+            }
+        }
+        let kitty = emit_kitty(&grid, &RasterOptions::default()).unwrap();
+        let chunks: Vec<&str> = kitty.split_terminator("\x1b\\").collect();
+
+        assert!(chunks[0].starts_with("\x1b_Ga=T,f=100,m=1;iVBORw0KGgo"));
+        assert!(
+            chunks[1..chunks.len() - 1]
+                .iter()
+                .all(|chunk| chunk.starts_with("\x1b_Gm=1;"))
+        );
+        assert!(chunks[chunks.len() - 1].starts_with("\x1b_Gm=0;"));
+        assert!(chunks.iter().all(|chunk| chunk.len() <= 4096 + 20));
+        assert!(kitty.ends_with("\x1b\\"));
+    }
 }
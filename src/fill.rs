@@ -10,6 +10,7 @@
 // distributed under the License is distributed on an "AS IS" BASIS,
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 
+use crate::color::Color;
 use crate::grid::Grid;
 
 /// Fill strategy for visible cells.
@@ -30,6 +31,16 @@ pub enum Fill {
     },
 }
 
+/// How [`Banner::canvas_color`](crate::Banner::canvas_color) paints blank cells.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CanvasFill {
+    /// Set the cell background (default).
+    #[default]
+    Bg,
+    /// Draw the character in the canvas color, leaving the background alone.
+    Char(char),
+}
+
 /// Dot dither configuration.
 #[derive(Clone, Copy, Debug)]
 pub struct Dither {
@@ -39,6 +50,8 @@ pub struct Dither {
     pub dot: char,
     /// Alternate dot character.
     pub alt: char,
+    /// Fraction (0..=1) of dot/alt choices flipped at random for a softer stipple.
+    pub jitter: f32,
 }
 
 /// Dither pattern selection.
@@ -56,6 +69,19 @@ pub enum DitherMode {
         /// Threshold (0..=255).
         threshold: u8,
     },
+    /// 4x4 Bayer ordered dither.
+    Bayer {
+        /// Dithered cells per 16-cell tile (0..=16).
+        levels: u8,
+    },
+    /// Halftone driven by each cell's color: bright cells get `dot`, mid-tones `alt`,
+    /// dark cells go blank.
+    ///
+    /// Only applies to dot dithering, which runs after the gradient.
+    Luminance {
+        /// Exponent applied as `luminance^(1/gamma)`; values above 1 lift mid-tones.
+        gamma: f32,
+    },
 }
 
 impl Dither {
@@ -66,6 +92,7 @@ impl Dither {
             mode: DitherMode::Checker { period },
             dot,
             alt,
+            jitter: 0.0,
         }
     }
 
@@ -76,10 +103,69 @@ impl Dither {
             mode: DitherMode::Noise { seed, threshold },
             dot,
             alt,
+            jitter: 0.0,
+        }
+    }
+
+    /// 4x4 Bayer ordered dither with dot characters (1 or 2 chars).
+    pub fn bayer(levels: u8, dots: &str) -> Self {
+        let (dot, alt) = parse_dots(dots);
+        Self {
+            mode: DitherMode::Bayer { levels },
+            dot,
+            alt,
+            jitter: 0.0,
+        }
+    }
+
+    /// Randomly flip a fraction (0..=1) of dot/alt choices.
+    pub fn jitter(mut self, jitter: f32) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Dot character for a dithered cell (alternating, with optional jitter).
+    pub(crate) fn dot_at(&self, row: usize, col: usize) -> char {
+        let mut primary = (row + col).is_multiple_of(2);
+        if self.jitter > 0.0 {
+            let seed = match self.mode {
+                DitherMode::Noise { seed, .. } => seed ^ JITTER_SALT,
+                DitherMode::Checker { .. }
+                | DitherMode::Bayer { .. }
+                | DitherMode::Luminance { .. } => JITTER_SALT,
+            };
+            let roll = (mix(seed, row as u32, col as u32) & 0xFFFF) as f32 / 65535.0;
+            if roll < self.jitter {
+                primary = !primary;
+            }
+        }
+        if primary { self.dot } else { self.alt }
+    }
+
+    /// Halftone character for a cell colored `fg` (`None` when it goes blank or the mode is not `Luminance`).
+    ///
+    /// Cells without a color count as fully bright.
+    pub(crate) fn halftone_at(&self, row: usize, col: usize, fg: Option<Color>) -> Option<char> {
+        let DitherMode::Luminance { gamma } = self.mode else {
+            return None;
+        };
+        let luminance = fg.map_or(1.0, Color::luminance);
+        let level = 2.0 * luminance.powf(1.0 / gamma.max(f32::EPSILON));
+        let threshold = (BAYER_4X4[row % 4][col % 4] as f32 + 0.5) / 16.0;
+        if level > 1.0 + threshold {
+            Some(self.dot)
+        } else if level > threshold {
+            Some(self.alt)
+        } else {
+            None
         }
     }
 }
 
+const JITTER_SALT: u32 = 0x6A09_E667;
+
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
 impl Fill {
     /// Default block fill.
     pub fn default_blocks() -> Self {
@@ -126,11 +212,7 @@ pub fn apply_fill(grid: &mut Grid, fill: Fill) {
                         if let Some(dither) = dither
                             && should_dither(r, c, dither.mode)
                         {
-                            cell.ch = if (r + c) % 2 == 0 {
-                                dither.dot
-                            } else {
-                                dither.alt
-                            };
+                            cell.ch = dither.dot_at(r, c);
                         }
                     }
                 }
@@ -139,7 +221,7 @@ pub fn apply_fill(grid: &mut Grid, fill: Fill) {
     }
 }
 
-fn should_dither(row: usize, col: usize, mode: DitherMode) -> bool {
+pub(crate) fn should_dither(row: usize, col: usize, mode: DitherMode) -> bool {
     match mode {
         DitherMode::Checker { period } => {
             if period == 0 {
@@ -152,10 +234,13 @@ fn should_dither(row: usize, col: usize, mode: DitherMode) -> bool {
             let hash = mix(seed, row as u32, col as u32);
             (hash & 0xFF) < threshold as u32
         }
+        DitherMode::Bayer { levels } => BAYER_4X4[row % 4][col % 4] < levels,
+        DitherMode::Luminance { .. } => false,
     }
 }
 
-fn mix(seed: u32, x: u32, y: u32) -> u32 {
+/// Deterministic integer hash of a seed and a cell position.
+pub(crate) fn mix(seed: u32, x: u32, y: u32) -> u32 {
     let mut v = seed ^ x.wrapping_mul(0x9E3779B1) ^ y.wrapping_mul(0x85EBCA77);
     v ^= v >> 16;
     v = v.wrapping_mul(0x7FEB352D);
@@ -171,3 +256,103 @@ fn parse_dots(dots: &str) -> (char, char) {
     let second = iter.next().unwrap_or(first);
     (first, second)
 }
+
+/// Paint every blank (non-visible) cell with the canvas color.
+pub(crate) fn apply_canvas(grid: &mut Grid, color: Color, fill: CanvasFill) {
+    grid.map_cells(|_, _, cell| {
+        if cell.visible {
+            return;
+        }
+        match fill {
+            CanvasFill::Bg => cell.bg = Some(color),
+            CanvasFill::Char(ch) => {
+                cell.ch = ch;
+                cell.fg = Some(color);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn luminance_halftone_thins_out_dark_cells() {
+        let dither = Dither {
+            mode: DitherMode::Luminance { gamma: 1.0 },
+            dot: ':',
+            alt: '.',
+            jitter: 0.0,
+        };
+        let count = |fg: Color| -> [usize; 3] {
+            let mut counts = [0; 3];
+            for r in 0..4 {
+                for c in 0..4 {
+                    match dither.halftone_at(r, c, Some(fg)) {
+                        Some(':') => counts[0] += 1,
+                        Some(_) => counts[1] += 1,
+                        None => counts[2] += 1,
+                    }
+                }
+            }
+            counts
+        };
+
+        assert_eq!(count(Color::Rgb(255, 255, 255)), [16, 0, 0]);
+        assert_eq!(count(Color::Rgb(0, 0, 0)), [0, 0, 16]);
+        let gray = count(Color::Rgb(90, 90, 90));
+        assert!(gray[2] > 0 && gray[2] < 16);
+    }
+
+    #[test]
+    fn bayer_levels_set_tile_density() {
+        for levels in [0, 1, 5, 8, 16] {
+            let mode = DitherMode::Bayer { levels };
+            let tile = |r0: usize, c0: usize| -> Vec<bool> {
+                (0..16)
+                    .map(|i| should_dither(r0 + i / 4, c0 + i % 4, mode))
+                    .collect()
+            };
+            let first = tile(0, 0);
+            assert_eq!(first.iter().filter(|&&hit| hit).count(), levels as usize);
+            assert_eq!(tile(4, 8), first);
+        }
+    }
+
+    #[test]
+    fn jitter_flips_some_dot_choices() {
+        let pure = Dither::checker(1, ".:");
+        let jittered = pure.jitter(0.3);
+        let cells: Vec<(usize, usize)> = (0..8).flat_map(|r| (0..8).map(move |c| (r, c))).collect();
+
+        let flipped = cells
+            .iter()
+            .filter(|&&(r, c)| pure.dot_at(r, c) != jittered.dot_at(r, c))
+            .count();
+        assert!(flipped > 0 && flipped < cells.len());
+        assert!(
+            cells
+                .iter()
+                .all(|&(r, c)| pure.dot_at(r, c) == pure.jitter(0.0).dot_at(r, c))
+        );
+    }
+
+    #[test]
+    fn canvas_paints_only_blank_cells() {
+        let color = Color::Rgb(10, 20, 10);
+        let mut grid = Grid::from_char_rows(vec![vec!['#', ' ']]);
+        apply_canvas(&mut grid, color, CanvasFill::Bg);
+        assert_eq!(grid.cell(0, 0).unwrap().bg, None);
+        assert_eq!(grid.cell(0, 1).unwrap().bg, Some(color));
+
+        let mut grid = Grid::from_char_rows(vec![vec!['#', ' ']]);
+        apply_canvas(&mut grid, color, CanvasFill::Char('░'));
+        let blank = grid.cell(0, 1).unwrap();
+        assert_eq!(
+            (blank.ch, blank.fg, blank.visible),
+            ('░', Some(color), false)
+        );
+        assert_eq!(grid.cell(0, 0).unwrap().ch, '#');
+    }
+}
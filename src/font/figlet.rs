@@ -11,8 +11,9 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 
 use std::collections::HashMap;
+use std::fmt;
 
-use super::{Font, Glyph};
+use super::{Font, Glyph, TextDirection};
 
 /// Errors when parsing Figlet fonts.
 #[derive(Debug)]
@@ -20,19 +21,120 @@ pub enum FigletError {
     /// Header line is missing or invalid.
     InvalidHeader,
     /// Not enough data for glyphs.
-    MissingData,
+    MissingData {
+        /// 1-based line number where more data was expected.
+        line: usize,
+        /// Character code being parsed (`None` while skipping comment lines).
+        code: Option<u32>,
+    },
     /// Numeric field parse error.
-    InvalidNumber,
+    InvalidNumber {
+        /// Header field that failed to parse.
+        field: &'static str,
+        /// The offending text.
+        value: String,
+    },
+    /// Font bytes could not be decoded (bad gzip stream or unsupported compression).
+    Decode,
+    /// Reading font data failed.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for FigletError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FigletError::InvalidHeader => {
+                write!(
+                    f,
+                    "line 1: expected a `flf2a`/`tlf2a` header with 5 numeric fields"
+                )
+            }
+            FigletError::MissingData {
+                line,
+                code: Some(code),
+            } => {
+                let ch = char::from_u32(*code).unwrap_or(char::REPLACEMENT_CHARACTER);
+                write!(
+                    f,
+                    "line {line}: font data ended while reading glyph {code} ({ch:?})"
+                )
+            }
+            FigletError::MissingData { line, code: None } => {
+                write!(f, "line {line}: font data ended inside the comment block")
+            }
+            FigletError::InvalidNumber { field, value } => {
+                write!(
+                    f,
+                    "line 1: header field `{field}` is not a number: {value:?}"
+                )
+            }
+            FigletError::Decode => write!(
+                f,
+                "font data could not be decoded (bad gzip stream or `gzip` feature disabled)"
+            ),
+            FigletError::Io(err) => write!(f, "failed to read font data: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for FigletError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FigletError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Decode raw font bytes into text, decompressing gzip data when present.
+///
+/// UTF-8 is tried first; anything else is decoded as latin-1.
+pub fn decode(bytes: &[u8]) -> Result<String, FigletError> {
+    if bytes.starts_with(&GZIP_MAGIC) {
+        let inflated = gunzip(bytes)?;
+        return Ok(decode_text(&inflated));
+    }
+    Ok(decode_text(bytes))
+}
+
+fn decode_text(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
+
+#[cfg(feature = "gzip")]
+fn gunzip(bytes: &[u8]) -> Result<Vec<u8>, FigletError> {
+    use std::io::Read;
+
+    let mut out = Vec::new();
+    flate2::read::MultiGzDecoder::new(bytes)
+        .read_to_end(&mut out)
+        .map_err(|_| FigletError::Decode)?;
+    Ok(out)
+}
+
+#[cfg(not(feature = "gzip"))]
+fn gunzip(_bytes: &[u8]) -> Result<Vec<u8>, FigletError> {
+    Err(FigletError::Decode)
 }
 
 /// Parse a Figlet `.flf` string into a font.
 pub fn parse(data: &str) -> Result<Font, FigletError> {
-    let mut lines = data.lines();
-    let header = lines.next().ok_or(FigletError::InvalidHeader)?;
-    let (hardblank, height, comment_lines) = parse_header(header)?;
+    let mut lines = data.lines().zip(1..);
+    let (header, _) = lines.next().ok_or(FigletError::InvalidHeader)?;
+    let (hardblank, height, comment_lines, direction) = parse_header(header)?;
+    let mut last_line = 1;
 
     for _ in 0..comment_lines {
-        lines.next().ok_or(FigletError::MissingData)?;
+        let (_, line_no) = lines.next().ok_or(FigletError::MissingData {
+            line: last_line + 1,
+            code: None,
+        })?;
+        last_line = line_no;
     }
 
     let mut glyphs: HashMap<char, Glyph> = HashMap::new();
@@ -41,7 +143,11 @@ pub fn parse(data: &str) -> Result<Font, FigletError> {
     for code in 32u8..=126u8 {
         let mut rows: Vec<Vec<char>> = Vec::with_capacity(height);
         for _ in 0..height {
-            let line = lines.next().ok_or(FigletError::MissingData)?;
+            let (line, line_no) = lines.next().ok_or(FigletError::MissingData {
+                line: last_line + 1,
+                code: Some(u32::from(code)),
+            })?;
+            last_line = line_no;
             let marker = endmark.get_or_insert_with(|| line.chars().last().unwrap_or('@'));
             let cleaned = clean_line(line, *marker, hardblank);
             rows.push(cleaned.chars().collect());
@@ -57,34 +163,45 @@ pub fn parse(data: &str) -> Result<Font, FigletError> {
         height,
         glyphs,
         fallback,
+        direction,
     })
 }
 
-fn parse_header(line: &str) -> Result<(char, usize, usize), FigletError> {
-    if !line.starts_with("flf2a") || line.len() < 6 {
+fn parse_header(line: &str) -> Result<(char, usize, usize, TextDirection), FigletError> {
+    if !(line.starts_with("flf2a") || line.starts_with("tlf2a")) || line.len() < 6 {
         return Err(FigletError::InvalidHeader);
     }
     let hardblank = line.chars().nth(5).ok_or(FigletError::InvalidHeader)?;
     let mut parts = line.split_whitespace();
     parts.next();
-    let height = parse_usize(parts.next())?;
-    let _baseline = parse_usize(parts.next())?;
-    let _max_len = parse_usize(parts.next())?;
-    let _old_layout = parse_i32(parts.next())?;
-    let comment_lines = parse_usize(parts.next())?;
-    Ok((hardblank, height, comment_lines))
+    let height = parse_usize(parts.next(), "height")?;
+    let _baseline = parse_usize(parts.next(), "baseline")?;
+    let _max_len = parse_usize(parts.next(), "max_length")?;
+    let _old_layout = parse_i32(parts.next(), "old_layout")?;
+    let comment_lines = parse_usize(parts.next(), "comment_lines")?;
+    let direction = match parts.next() {
+        Some(part) if parse_i32(Some(part), "print_direction")? == 1 => TextDirection::RightToLeft,
+        _ => TextDirection::LeftToRight,
+    };
+    Ok((hardblank, height, comment_lines, direction))
 }
 
-fn parse_usize(part: Option<&str>) -> Result<usize, FigletError> {
-    part.ok_or(FigletError::InvalidHeader)?
-        .parse::<usize>()
-        .map_err(|_| FigletError::InvalidNumber)
+fn parse_usize(part: Option<&str>, field: &'static str) -> Result<usize, FigletError> {
+    let part = part.ok_or(FigletError::InvalidHeader)?;
+    part.parse::<usize>()
+        .map_err(|_| invalid_number(field, part))
 }
 
-fn parse_i32(part: Option<&str>) -> Result<i32, FigletError> {
-    part.ok_or(FigletError::InvalidHeader)?
-        .parse::<i32>()
-        .map_err(|_| FigletError::InvalidNumber)
+fn parse_i32(part: Option<&str>, field: &'static str) -> Result<i32, FigletError> {
+    let part = part.ok_or(FigletError::InvalidHeader)?;
+    part.parse::<i32>().map_err(|_| invalid_number(field, part))
+}
+
+fn invalid_number(field: &'static str, value: &str) -> FigletError {
+    FigletError::InvalidNumber {
+        field,
+        value: value.to_string(),
+    }
 }
 
 fn clean_line(line: &str, endmark: char, hardblank: char) -> String {
@@ -92,3 +209,45 @@ fn clean_line(line: &str, endmark: char, hardblank: char) -> String {
     trimmed = trimmed.replace(hardblank, " ");
     trimmed
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_latin1_when_not_utf8() {
+        assert_eq!(decode(b"caf\xe9").unwrap(), "café");
+        assert_eq!(decode("café".as_bytes()).unwrap(), "café");
+    }
+
+    #[test]
+    fn errors_report_line_and_context() {
+        let truncated = parse("flf2a$ 2 1 2 0 1\ncomment\n @\n @@\n!@\n").unwrap_err();
+        assert_eq!(
+            truncated.to_string(),
+            "line 6: font data ended while reading glyph 33 ('!')"
+        );
+
+        let bad_number = parse("flf2a$ 2 x 2 0 1\n").unwrap_err();
+        assert_eq!(
+            bad_number.to_string(),
+            "line 1: header field `baseline` is not a number: \"x\""
+        );
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn decodes_gzip_streams() {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(b"flf2a$ 1 1 1 0 0").unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert_eq!(decode(&compressed).unwrap(), "flf2a$ 1 1 1 0 0");
+        assert!(matches!(
+            decode(&[0x1f, 0x8b, 0, 0]),
+            Err(FigletError::Decode)
+        ));
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use std::collections::HashMap;
+
+use super::{Font, Glyph, TextDirection};
+
+const HEIGHT: usize = 5;
+const PIXEL: char = '█';
+
+/// 3x5 bitmaps (`#` = filled).
+const GLYPHS: &[(char, [&str; HEIGHT])] = &[
+    ('A', ["###", "# #", "###", "# #", "# #"]),
+    ('B', ["## ", "# #", "## ", "# #", "## "]),
+    ('C', ["###", "#  ", "#  ", "#  ", "###"]),
+    ('D', ["## ", "# #", "# #", "# #", "## "]),
+    ('E', ["###", "#  ", "## ", "#  ", "###"]),
+    ('F', ["###", "#  ", "## ", "#  ", "#  "]),
+    ('G', ["###", "#  ", "# #", "# #", "###"]),
+    ('H', ["# #", "# #", "###", "# #", "# #"]),
+    ('I', ["###", " # ", " # ", " # ", "###"]),
+    ('J', ["  #", "  #", "  #", "# #", "###"]),
+    ('K', ["# #", "# #", "## ", "# #", "# #"]),
+    ('L', ["#  ", "#  ", "#  ", "#  ", "###"]),
+    ('M', ["# #", "###", "###", "# #", "# #"]),
+    ('N', ["## ", "# #", "# #", "# #", "# #"]),
+    ('O', ["###", "# #", "# #", "# #", "###"]),
+    ('P', ["###", "# #", "###", "#  ", "#  "]),
+    ('Q', ["###", "# #", "# #", "###", "  #"]),
+    ('R', ["## ", "# #", "## ", "# #", "# #"]),
+    ('S', ["###", "#  ", "###", "  #", "###"]),
+    ('T', ["###", " # ", " # ", " # ", " # "]),
+    ('U', ["# #", "# #", "# #", "# #", "###"]),
+    ('V', ["# #", "# #", "# #", "# #", " # "]),
+    ('W', ["# #", "# #", "###", "###", "# #"]),
+    ('X', ["# #", "# #", " # ", "# #", "# #"]),
+    ('Y', ["# #", "# #", " # ", " # ", " # "]),
+    ('Z', ["###", "  #", " # ", "#  ", "###"]),
+    ('0', ["###", "# #", "# #", "# #", "###"]),
+    ('1', [" # ", "## ", " # ", " # ", "###"]),
+    ('2', ["###", "  #", "###", "#  ", "###"]),
+    ('3', ["###", "  #", "###", "  #", "###"]),
+    ('4', ["# #", "# #", "###", "  #", "  #"]),
+    ('5', ["###", "#  ", "###", "  #", "###"]),
+    ('6', ["###", "#  ", "###", "# #", "###"]),
+    ('7', ["###", "  #", "  #", "  #", "  #"]),
+    ('8', ["###", "# #", "###", "# #", "###"]),
+    ('9', ["###", "# #", "###", "  #", "###"]),
+    (' ', ["   ", "   ", "   ", "   ", "   "]),
+    ('!', [" # ", " # ", " # ", "   ", " # "]),
+    ('?', ["###", "  #", " ##", "   ", " # "]),
+    ('.', ["   ", "   ", "   ", "   ", " # "]),
+    (',', ["   ", "   ", "   ", " # ", "#  "]),
+    (':', ["   ", " # ", "   ", " # ", "   "]),
+    (';', ["   ", " # ", "   ", " # ", "#  "]),
+    ('\'', [" # ", " # ", "   ", "   ", "   "]),
+    ('"', ["# #", "# #", "   ", "   ", "   "]),
+    ('-', ["   ", "   ", "###", "   ", "   "]),
+    ('+', ["   ", " # ", "###", " # ", "   "]),
+    ('=', ["   ", "###", "   ", "###", "   "]),
+    ('_', ["   ", "   ", "   ", "   ", "###"]),
+    ('*', ["# #", " # ", "# #", "   ", "   "]),
+    ('/', ["  #", "  #", " # ", "#  ", "#  "]),
+    ('\\', ["#  ", "#  ", " # ", "  #", "  #"]),
+    ('(', ["  #", " # ", " # ", " # ", "  #"]),
+    (')', ["#  ", " # ", " # ", " # ", "#  "]),
+    ('[', [" ##", " # ", " # ", " # ", " ##"]),
+    (']', ["## ", " # ", " # ", " # ", "## "]),
+    ('<', ["  #", " # ", "#  ", " # ", "  #"]),
+    ('>', ["#  ", " # ", "  #", " # ", "#  "]),
+    ('#', ["# #", "###", "# #", "###", "# #"]),
+    ('%', ["# #", "  #", " # ", "#  ", "# #"]),
+    ('&', [" # ", "# #", " # ", "# #", " ##"]),
+    ('@', ["###", "# #", "###", "#  ", "###"]),
+    ('$', [" ##", "## ", " # ", " ##", "## "]),
+    ('|', [" # ", " # ", " # ", " # ", " # "]),
+];
+
+/// Build the minimal font from the hard-coded bitmaps.
+pub(super) fn build() -> Font {
+    let glyphs: HashMap<char, Glyph> = GLYPHS
+        .iter()
+        .map(|(ch, rows)| (*ch, bitmap(rows)))
+        .collect();
+    let fallback = glyphs
+        .get(&'?')
+        .cloned()
+        .unwrap_or_else(|| bitmap(&["###"; HEIGHT]));
+
+    Font {
+        height: HEIGHT,
+        glyphs,
+        fallback,
+        direction: TextDirection::LeftToRight,
+    }
+}
+
+fn bitmap(rows: &[&str; HEIGHT]) -> Glyph {
+    Glyph {
+        rows: rows
+            .iter()
+            .map(|row| {
+                row.chars()
+                    .map(|ch| if ch == '#' { PIXEL } else { ' ' })
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::font::render_text;
+
+    #[test]
+    fn renders_every_glyph_at_fixed_height() {
+        let font = build();
+        let text: String = GLYPHS.iter().map(|(ch, _)| *ch).collect();
+        let grid = render_text(&text, &font, 1, 0);
+        assert_eq!(grid.height(), HEIGHT);
+        assert_eq!(grid.width(), GLYPHS.len() * 4 - 1);
+    }
+}
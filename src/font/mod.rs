@@ -11,11 +11,14 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 
 use std::collections::HashMap;
+use std::io::Read;
+use std::ops::Range;
 
-use crate::grid::Grid;
+use crate::grid::{Align, Grid};
 
 /// Figlet font parser.
 pub mod figlet;
+mod minimal;
 
 /// A single glyph as character rows.
 #[derive(Clone, Debug)]
@@ -29,9 +32,38 @@ pub struct Font {
     height: usize,
     glyphs: HashMap<char, Glyph>,
     fallback: Glyph,
+    direction: TextDirection,
+}
+
+/// Direction in which glyphs are laid out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    /// Glyphs placed side by side (default).
+    Horizontal,
+    /// Glyphs stacked top to bottom.
+    Vertical,
+}
+
+/// Glyph composition order (the Figlet print direction).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextDirection {
+    /// First character is placed leftmost.
+    LeftToRight,
+    /// First character is placed rightmost.
+    RightToLeft,
 }
 
 impl Font {
+    /// Create an empty font with the given height (add glyphs with [`Font::set_glyph`]).
+    pub fn new(height: usize) -> Self {
+        Self {
+            height,
+            glyphs: HashMap::new(),
+            fallback: Glyph::from_rows(vec![vec![' ']; height]),
+            direction: TextDirection::LeftToRight,
+        }
+    }
+
     /// Built-in DOS Rebel (Figlet) font.
     ///
     /// Returns an error if the bundled font data is invalid.
@@ -39,52 +71,226 @@ impl Font {
         figlet::parse(include_str!("../../assets/fonts/dosrebel.flf"))
     }
 
+    /// Built-in 3x5 block font (A-Z, 0-9, basic punctuation).
+    ///
+    /// Built from hard-coded bitmaps, so it can never fail.
+    pub fn minimal() -> Self {
+        minimal::build()
+    }
+
     /// Parse a Figlet `.flf` string into a font.
     pub fn from_figlet_str(data: &str) -> Result<Self, figlet::FigletError> {
         figlet::parse(data)
     }
 
+    /// Parse Figlet font bytes (plain or gzip-compressed, UTF-8 or latin-1).
+    ///
+    /// Gzip input requires the `gzip` feature.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, figlet::FigletError> {
+        figlet::parse(&figlet::decode(data)?)
+    }
+
+    /// Read and parse a Figlet font from any reader (see [`Font::from_bytes`]).
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, figlet::FigletError> {
+        let mut data = Vec::new();
+        reader
+            .read_to_end(&mut data)
+            .map_err(figlet::FigletError::Io)?;
+        Self::from_bytes(&data)
+    }
+
     /// Font height in rows.
     pub fn height(&self) -> usize {
         self.height
     }
 
+    /// Print direction declared by the font header.
+    pub fn direction(&self) -> TextDirection {
+        self.direction
+    }
+
     /// Get glyph by character (falls back if missing).
     pub fn glyph(&self, ch: char) -> &Glyph {
         self.glyphs.get(&ch).unwrap_or(&self.fallback)
     }
+
+    /// Measure the rendered width of a single line of text.
+    pub fn measure(&self, text: &str, kerning: usize) -> usize {
+        let mut width = 0;
+        let mut count: usize = 0;
+        for ch in text.chars() {
+            width += self.glyph(ch.to_ascii_uppercase()).width();
+            count += 1;
+        }
+        width + kerning * count.saturating_sub(1)
+    }
+
+    /// Insert or replace a glyph, padding or truncating it to the font height.
+    pub fn set_glyph(&mut self, ch: char, mut glyph: Glyph) {
+        let width = glyph.width();
+        glyph.rows.resize(self.height, vec![' '; width]);
+        self.glyphs.insert(ch, glyph);
+    }
+
+    /// Remove a glyph, returning it if present (the fallback is used afterwards).
+    pub fn remove_glyph(&mut self, ch: char) -> Option<Glyph> {
+        self.glyphs.remove(&ch)
+    }
+
+    /// Strip blank side margins from every glyph, keeping at most one trailing column.
+    ///
+    /// The space glyph (and any fully blank glyph) is left untouched so words stay apart.
+    pub fn trim_glyphs(mut self) -> Font {
+        for (ch, glyph) in self.glyphs.iter_mut() {
+            if *ch != ' ' {
+                glyph.trim_margins();
+            }
+        }
+        self.fallback.trim_margins();
+        self
+    }
 }
 
 impl Glyph {
+    /// Build a glyph from character rows; shorter rows are padded with spaces.
+    pub fn from_rows(mut rows: Vec<Vec<char>>) -> Self {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, ' ');
+        }
+        Self { rows }
+    }
+
+    /// Build a glyph from a string, one row per line.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(art: &str) -> Self {
+        Self::from_rows(art.lines().map(|line| line.chars().collect()).collect())
+    }
+
     /// Width of the glyph.
     pub fn width(&self) -> usize {
         self.rows.first().map(|r| r.len()).unwrap_or(0)
     }
+
+    fn trim_margins(&mut self) {
+        let width = self.width();
+        let leading = self
+            .rows
+            .iter()
+            .map(|row| row.iter().take_while(|c| **c == ' ').count())
+            .min()
+            .unwrap_or(0);
+        if leading >= width {
+            return;
+        }
+        let trailing = self
+            .rows
+            .iter()
+            .map(|row| row.iter().rev().take_while(|c| **c == ' ').count())
+            .min()
+            .unwrap_or(0);
+        let end = width - trailing + trailing.min(1);
+        for row in &mut self.rows {
+            row.truncate(end);
+            row.drain(..leading);
+        }
+    }
 }
 
 /// Render text into a grid using a font.
 pub fn render_text(text: &str, font: &Font, kerning: usize, line_gap: usize) -> Grid {
+    let layout = TextLayout {
+        kerning,
+        overlap: 0,
+        line_gap,
+        direction: font.direction(),
+        orientation: Orientation::Horizontal,
+        align: Align::Left,
+        line_align: Align::Left,
+    };
+    render_text_with(text, font, &layout)
+}
+
+/// Text layout settings used by the banner pipeline.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct TextLayout {
+    pub(crate) kerning: usize,
+    /// Columns adjacent glyphs may overlap by (negative kerning), clamped per pair.
+    pub(crate) overlap: usize,
+    pub(crate) line_gap: usize,
+    pub(crate) direction: TextDirection,
+    pub(crate) orientation: Orientation,
+    pub(crate) align: Align,
+    pub(crate) line_align: Align,
+}
+
+/// Cells covered by one rendered glyph, including the kerning gap after it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct GlyphSpan {
+    pub(crate) ch: char,
+    pub(crate) rows: Range<usize>,
+    pub(crate) cols: Range<usize>,
+}
+
+/// Glyph characters with the row or column range each one covers along its line.
+type CharSpans = Vec<(char, Range<usize>)>;
+
+/// Render text into a grid using explicit layout settings.
+pub(crate) fn render_text_with(text: &str, font: &Font, layout: &TextLayout) -> Grid {
+    render_text_spans(text, font, layout).0
+}
+
+/// Render text like [`render_text_with`], also returning where each glyph landed.
+pub(crate) fn render_text_spans(
+    text: &str,
+    font: &Font,
+    layout: &TextLayout,
+) -> (Grid, Vec<GlyphSpan>) {
+    if layout.orientation == Orientation::Vertical {
+        return render_vertical(text, font, layout);
+    }
+    let TextLayout {
+        kerning,
+        overlap,
+        line_gap,
+        direction,
+        line_align,
+        ..
+    } = *layout;
+
     let lines: Vec<&str> = text.lines().collect();
     if lines.is_empty() {
-        return Grid::new(0, 0);
+        return (Grid::new(0, 0), Vec::new());
     }
 
     let mut line_grids = Vec::with_capacity(lines.len());
     let mut max_width = 0;
 
     for line in &lines {
-        let grid = render_line(line, font, kerning);
+        let (grid, line_spans) = render_line(line, font, kerning, overlap, direction);
         max_width = max_width.max(grid.width());
-        line_grids.push(grid);
+        line_grids.push((grid, line_spans));
     }
 
     let mut rows: Vec<Vec<char>> = Vec::new();
-    for (idx, grid) in line_grids.into_iter().enumerate() {
+    let mut spans = Vec::new();
+    for (idx, (grid, line_spans)) in line_grids.into_iter().enumerate() {
+        let extra = max_width - grid.width();
+        let left = match line_align {
+            Align::Left => 0,
+            Align::Center => extra / 2,
+            Align::Right => extra,
+        };
+        let top = rows.len();
+        spans.extend(line_spans.into_iter().map(|(ch, cols)| GlyphSpan {
+            ch,
+            rows: top..top + grid.height(),
+            cols: left + cols.start..left + cols.end,
+        }));
         for row in grid.rows() {
-            let mut chars = row.iter().map(|cell| cell.ch).collect::<Vec<_>>();
-            if chars.len() < max_width {
-                chars.extend(std::iter::repeat_n(' ', max_width - chars.len()));
-            }
+            let mut chars = vec![' '; left];
+            chars.extend(row.iter().map(|cell| cell.ch));
+            chars.resize(max_width, ' ');
             rows.push(chars);
         }
         if idx + 1 < lines.len() {
@@ -94,22 +300,362 @@ pub fn render_text(text: &str, font: &Font, kerning: usize, line_gap: usize) ->
         }
     }
 
-    Grid::from_char_rows(rows)
+    (Grid::from_char_rows(rows), spans)
 }
 
-fn render_line(text: &str, font: &Font, kerning: usize) -> Grid {
+/// Re-break text at word boundaries so no line renders wider than `limit`.
+///
+/// Existing line breaks are kept; words wider than the limit are hard-broken.
+pub fn wrap_text(text: &str, font: &Font, kerning: usize, limit: usize) -> String {
+    let mut out: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let mut current = String::new();
+        for word in line.split_whitespace() {
+            let candidate = if current.is_empty() {
+                word.to_string()
+            } else {
+                format!("{current} {word}")
+            };
+            if font.measure(&candidate, kerning) <= limit {
+                current = candidate;
+                continue;
+            }
+            if !current.is_empty() {
+                out.push(std::mem::take(&mut current));
+            }
+            if font.measure(word, kerning) <= limit {
+                current = word.to_string();
+                continue;
+            }
+            for ch in word.chars() {
+                let mut chunk = current.clone();
+                chunk.push(ch);
+                if !current.is_empty() && font.measure(&chunk, kerning) > limit {
+                    out.push(std::mem::take(&mut current));
+                    current.push(ch);
+                } else {
+                    current = chunk;
+                }
+            }
+        }
+        out.push(current);
+    }
+    out.join("\n")
+}
+
+fn render_vertical(text: &str, font: &Font, layout: &TextLayout) -> (Grid, Vec<GlyphSpan>) {
+    let columns: Vec<(Grid, CharSpans)> = text
+        .lines()
+        .map(|line| render_column(line, font, layout))
+        .collect();
+    if columns.is_empty() {
+        return (Grid::new(0, 0), Vec::new());
+    }
+
+    let height = columns
+        .iter()
+        .map(|(grid, _)| grid.height())
+        .max()
+        .unwrap_or(0);
+    let gaps = layout.kerning * (columns.len() - 1);
+    let width = columns.iter().map(|(grid, _)| grid.width()).sum::<usize>() + gaps;
+    let mut out = Grid::new(height, width);
+    let mut spans = Vec::new();
+    let mut left = 0;
+    for (idx, (column, glyph_rows)) in columns.iter().enumerate() {
+        out.blit(column, 0, left);
+        let gap = if idx + 1 < columns.len() {
+            layout.kerning
+        } else {
+            0
+        };
+        let cols = left..left + column.width() + gap;
+        spans.extend(glyph_rows.iter().map(|(ch, rows)| GlyphSpan {
+            ch: *ch,
+            rows: rows.clone(),
+            cols: cols.clone(),
+        }));
+        left += column.width() + layout.kerning;
+    }
+    (out, spans)
+}
+
+/// Render one column of stacked glyphs, with the rows each glyph covers.
+fn render_column(text: &str, font: &Font, layout: &TextLayout) -> (Grid, CharSpans) {
+    let mut chars: Vec<char> = text.chars().collect();
+    if layout.direction == TextDirection::RightToLeft {
+        chars.reverse();
+    }
+    let glyphs: Vec<&Glyph> = chars
+        .iter()
+        .map(|ch| font.glyph(ch.to_ascii_uppercase()))
+        .collect();
+    let width = glyphs.iter().map(|glyph| glyph.width()).max().unwrap_or(0);
+
+    let mut rows: Vec<Vec<char>> = Vec::new();
+    let mut spans = Vec::with_capacity(glyphs.len());
+    for (idx, glyph) in glyphs.iter().enumerate() {
+        let top = rows.len();
+        let extra = width - glyph.width();
+        let left = match layout.align {
+            Align::Left => 0,
+            Align::Center => extra / 2,
+            Align::Right => extra,
+        };
+        for row in &glyph.rows {
+            let mut line = vec![' '; left];
+            line.extend(row.iter().copied());
+            line.resize(width, ' ');
+            rows.push(line);
+        }
+        if idx + 1 < glyphs.len() {
+            for _ in 0..layout.line_gap {
+                rows.push(vec![' '; width]);
+            }
+        }
+        spans.push((chars[idx], top..rows.len()));
+    }
+
+    (Grid::from_char_rows(rows), spans)
+}
+
+/// Render one line of glyphs, with the columns each glyph (and its kerning gap) covers.
+///
+/// With `overlap`, each glyph slides left over the previous one by up to that many
+/// columns, but only as far as blank cells allow in every row.
+fn render_line(
+    text: &str,
+    font: &Font,
+    kerning: usize,
+    overlap: usize,
+    direction: TextDirection,
+) -> (Grid, CharSpans) {
     let mut rows: Vec<Vec<char>> = vec![Vec::new(); font.height()];
-    let chars: Vec<char> = text.chars().collect();
+    let mut chars: Vec<char> = text.chars().collect();
+    if direction == TextDirection::RightToLeft {
+        chars.reverse();
+    }
 
+    let mut spans: CharSpans = Vec::with_capacity(chars.len());
+    let mut left = 0;
     for (idx, ch) in chars.iter().enumerate() {
         let glyph = font.glyph(ch.to_ascii_uppercase());
+        let shift = if idx > 0 {
+            overlap_room(&rows, &glyph.rows, overlap)
+        } else {
+            0
+        };
+        if shift > 0 {
+            left -= shift;
+            if let Some((_, previous)) = spans.last_mut() {
+                previous.end = previous.end.saturating_sub(shift).max(previous.start);
+            }
+        }
+        let gap = if idx + 1 < chars.len() { kerning } else { 0 };
         for (row_idx, row) in glyph.rows.iter().enumerate() {
-            rows[row_idx].extend(row.iter().copied());
-            if idx + 1 < chars.len() && kerning > 0 {
-                rows[row_idx].extend(std::iter::repeat_n(' ', kerning));
+            let line = &mut rows[row_idx];
+            let start = line.len() - shift;
+            for (offset, &glyph_ch) in row.iter().enumerate() {
+                match line.get_mut(start + offset) {
+                    Some(cell) if glyph_ch != ' ' => *cell = glyph_ch,
+                    Some(_) => {}
+                    None => line.push(glyph_ch),
+                }
             }
+            line.resize(start + row.len(), ' ');
+            if gap > 0 {
+                line.extend(std::iter::repeat_n(' ', gap));
+            }
+        }
+        let right = left + glyph.width() + gap;
+        spans.push((*ch, left..right));
+        left = right;
+    }
+
+    (Grid::from_char_rows(rows), spans)
+}
+
+/// How many columns the next glyph can slide left without covering a visible cell.
+fn overlap_room(line: &[Vec<char>], glyph: &[Vec<char>], limit: usize) -> usize {
+    line.iter()
+        .zip(glyph)
+        .map(|(line_row, glyph_row)| {
+            let trailing = line_row.iter().rev().take_while(|&&ch| ch == ' ').count();
+            let leading = glyph_row.iter().take_while(|&&ch| ch == ' ').count();
+            (trailing + leading).min(line_row.len())
+        })
+        .fold(limit, usize::min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_font() -> Font {
+        let mut glyphs = HashMap::new();
+        for ch in (b'A'..=b'Z').map(char::from).chain([' ']) {
+            glyphs.insert(
+                ch,
+                Glyph {
+                    rows: vec![vec![ch; 2]],
+                },
+            );
+        }
+        Font {
+            height: 1,
+            glyphs,
+            fallback: Glyph {
+                rows: vec![vec!['?'; 2]],
+            },
+            direction: TextDirection::LeftToRight,
+        }
+    }
+
+    #[test]
+    fn wraps_at_word_boundaries() {
+        let font = block_font();
+        assert_eq!(font.measure("AB CD", 1), 14);
+        assert_eq!(wrap_text("AB CD EF", &font, 1, 9), "AB\nCD\nEF");
+        assert_eq!(wrap_text("AB CD\nEF", &font, 1, 20), "AB CD\nEF");
+    }
+
+    #[test]
+    fn right_to_left_fonts_reverse_glyph_order() {
+        let mut data = String::from("flf2a$ 1 1 2 0 0 1\n");
+        for code in 32u8..=126u8 {
+            data.push_str(&format!("{}@\n", code as char));
         }
+        let font = Font::from_figlet_str(&data).unwrap();
+        assert_eq!(font.direction(), TextDirection::RightToLeft);
+
+        let grid = render_text("AB", &font, 0, 0);
+        assert_eq!(grid.cell(0, 0).unwrap().ch, 'B');
+        assert_eq!(grid.cell(0, 1).unwrap().ch, 'A');
     }
 
-    Grid::from_char_rows(rows)
+    #[test]
+    fn custom_glyphs_are_normalized_and_rendered() {
+        let mut font = Font::new(3);
+        font.set_glyph('§', Glyph::from_str("/\\\n\\/"));
+        font.set_glyph('X', Glyph::from_rows(vec![vec!['x'; 3]; 5]));
+
+        let grid = render_text("§", &font, 0, 0);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.cell(0, 0).unwrap().ch, '/');
+        assert_eq!(grid.cell(1, 1).unwrap().ch, '/');
+        assert!(!grid.cell(2, 0).unwrap().visible);
+        assert_eq!(font.glyph('X').rows.len(), 3);
+
+        let banner = crate::Banner::new_with("§", font.clone())
+            .fill(crate::Fill::Keep)
+            .color_mode(crate::ColorMode::NoColor);
+        assert_eq!(banner.render(), "/\\\n\\/\n  ");
+
+        assert!(font.remove_glyph('§').is_some());
+        assert_eq!(render_text("§", &font, 0, 0).width(), 1);
+    }
+
+    fn aligned_rows(line_align: Align) -> Vec<String> {
+        let layout = TextLayout {
+            kerning: 0,
+            overlap: 0,
+            line_gap: 0,
+            direction: TextDirection::LeftToRight,
+            orientation: Orientation::Horizontal,
+            align: Align::Left,
+            line_align,
+        };
+        let grid = render_text_with("ABC\nD", &block_font(), &layout);
+        grid.rows()
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.ch).collect())
+            .collect()
+    }
+
+    #[test]
+    fn centers_shorter_lines() {
+        assert_eq!(aligned_rows(Align::Center), ["AABBCC", "  DD  "]);
+    }
+
+    #[test]
+    fn right_aligns_shorter_lines() {
+        assert_eq!(aligned_rows(Align::Right), ["AABBCC", "    DD"]);
+    }
+
+    #[test]
+    fn vertical_orientation_stacks_glyphs_in_columns() {
+        let mut font = block_font();
+        font.glyphs.insert(
+            'I',
+            Glyph {
+                rows: vec![vec!['I']],
+            },
+        );
+        let layout = TextLayout {
+            kerning: 1,
+            overlap: 0,
+            line_gap: 1,
+            direction: TextDirection::LeftToRight,
+            orientation: Orientation::Vertical,
+            align: Align::Right,
+            line_align: Align::Left,
+        };
+        let grid = render_text_with("AI\nB", &font, &layout);
+        let rows: Vec<String> = grid
+            .rows()
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.ch).collect())
+            .collect();
+        assert_eq!(rows, ["AA BB", "     ", " I   "]);
+    }
+
+    #[test]
+    fn hard_breaks_long_words() {
+        let font = block_font();
+        assert_eq!(wrap_text("ABCDE", &font, 0, 4), "AB\nCD\nE");
+    }
+
+    #[test]
+    fn overlap_slides_glyphs_over_blank_columns() {
+        let mut font = block_font();
+        font.glyphs.insert(
+            'L',
+            Glyph {
+                rows: vec![vec!['#', ' ', ' ']],
+            },
+        );
+        font.glyphs.insert(
+            'J',
+            Glyph {
+                rows: vec![vec![' ', ' ', '#']],
+            },
+        );
+        let layout = |overlap| TextLayout {
+            kerning: 1,
+            overlap,
+            line_gap: 0,
+            direction: TextDirection::LeftToRight,
+            orientation: Orientation::Horizontal,
+            align: Align::Left,
+            line_align: Align::Left,
+        };
+        let row = |text: &str, overlap| {
+            let (grid, spans) = render_text_spans(text, &font, &layout(overlap));
+            let chars: String = grid.rows()[0].iter().map(|cell| cell.ch).collect();
+            (
+                chars,
+                spans
+                    .iter()
+                    .map(|span| span.cols.clone())
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        assert_eq!(row("LJ", 0).0, "#     #");
+        assert_eq!(row("LJ", 3), ("#  #".to_string(), vec![0..1, 1..4]));
+        // Never more than the blank columns on both sides allow.
+        assert_eq!(row("LJ", 10).0, "# #");
+        assert_eq!(row("AB", 10).0, "AABB");
+    }
 }
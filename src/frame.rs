@@ -12,10 +12,12 @@
 
 use crate::color::Color;
 use crate::gradient::Gradient;
-use crate::grid::Grid;
+use crate::grid::{Align, Grid, Padding};
 
 /// Predefined frame styles.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum FrameStyle {
     /// Single-line box drawing.
     Single,
@@ -31,6 +33,8 @@ pub enum FrameStyle {
 
 /// Character set for rendering frames.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct FrameChars {
     /// Top-left corner.
     pub top_left: char,
@@ -44,6 +48,20 @@ pub struct FrameChars {
     pub horizontal: char,
     /// Vertical line.
     pub vertical: char,
+    /// Tee glyphs for dividers (`None` lets rules butt into the border).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub junctions: Option<FrameJunctions>,
+}
+
+/// Junction glyphs where divider rules meet the border.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct FrameJunctions {
+    /// Tee on the left border, e.g. `├`.
+    pub left: char,
+    /// Tee on the right border, e.g. `┤`.
+    pub right: char,
 }
 
 /// Color treatment for frame strokes.
@@ -60,21 +78,60 @@ pub enum FramePaint {
 pub struct Frame {
     chars: FrameChars,
     paint: Option<FramePaint>,
+    title: Option<(String, Align)>,
+    inner_padding: Padding,
+    sides: Sides,
+    dividers: Vec<usize>,
+    thickness: usize,
+    background: Option<Color>,
+}
+
+/// Which frame sides are drawn.
+#[derive(Clone, Copy, Debug)]
+struct Sides {
+    top: bool,
+    bottom: bool,
+    left: bool,
+    right: bool,
+}
+
+impl Sides {
+    const ALL: Sides = Sides {
+        top: true,
+        bottom: true,
+        left: true,
+        right: true,
+    };
 }
 
 impl FrameStyle {
     /// Resolve the glyph set for this style.
     pub fn chars(self) -> FrameChars {
+        let light = FrameJunctions::new('├', '┤');
         match self {
-            FrameStyle::Single => FrameChars::new('┌', '┐', '└', '┘', '─', '│'),
-            FrameStyle::Double => FrameChars::new('╔', '╗', '╚', '╝', '═', '║'),
-            FrameStyle::Rounded => FrameChars::new('╭', '╮', '╰', '╯', '─', '│'),
-            FrameStyle::Heavy => FrameChars::new('┏', '┓', '┗', '┛', '━', '┃'),
-            FrameStyle::Ascii => FrameChars::new('+', '+', '+', '+', '-', '|'),
+            FrameStyle::Single => {
+                FrameChars::new('┌', '┐', '└', '┘', '─', '│').with_junctions(light)
+            }
+            FrameStyle::Double => FrameChars::new('╔', '╗', '╚', '╝', '═', '║')
+                .with_junctions(FrameJunctions::new('╠', '╣')),
+            FrameStyle::Rounded => {
+                FrameChars::new('╭', '╮', '╰', '╯', '─', '│').with_junctions(light)
+            }
+            FrameStyle::Heavy => FrameChars::new('┏', '┓', '┗', '┛', '━', '┃')
+                .with_junctions(FrameJunctions::new('┣', '┫')),
+            FrameStyle::Ascii => FrameChars::new('+', '+', '+', '+', '-', '|')
+                .with_junctions(FrameJunctions::new('+', '+')),
         }
     }
 }
 
+impl FrameJunctions {
+    /// Build a junction set from the left and right tees.
+    pub const fn new(left: char, right: char) -> Self {
+        Self { left, right }
+    }
+}
+
 impl FrameChars {
     /// Build a custom frame character set.
     pub const fn new(
@@ -92,22 +149,83 @@ impl FrameChars {
             bottom_right,
             horizontal,
             vertical,
+            junctions: None,
         }
     }
+
+    /// Add junction glyphs for dividers.
+    pub const fn with_junctions(mut self, junctions: FrameJunctions) -> Self {
+        self.junctions = Some(junctions);
+        self
+    }
 }
 
 impl Frame {
+    /// Deepest border [`Frame::thickness`] accepts.
+    pub const MAX_THICKNESS: usize = 64;
+
     /// Create a frame from a built-in style.
     pub fn new(style: FrameStyle) -> Self {
         Self {
             chars: style.chars(),
             paint: None,
+            title: None,
+            inner_padding: Padding::uniform(0),
+            sides: Sides::ALL,
+            dividers: Vec::new(),
+            thickness: 1,
+            background: None,
         }
     }
 
     /// Create a frame from a custom character set.
     pub fn custom(chars: FrameChars) -> Self {
-        Self { chars, paint: None }
+        Self {
+            chars,
+            paint: None,
+            title: None,
+            inner_padding: Padding::uniform(0),
+            sides: Sides::ALL,
+            dividers: Vec::new(),
+            thickness: 1,
+            background: None,
+        }
+    }
+
+    /// Draw only some sides, e.g. `sides(true, true, false, false)` for top and bottom rules.
+    ///
+    /// The banner only grows by the enabled sides, and corners are drawn only where both
+    /// adjacent sides are. A title needs the top side.
+    pub fn sides(mut self, top: bool, bottom: bool, left: bool, right: bool) -> Self {
+        self.sides = Sides {
+            top,
+            bottom,
+            left,
+            right,
+        };
+        self
+    }
+
+    /// Embed a label in the top border, e.g. `┌─ BUILD ─┐`.
+    ///
+    /// The label is clipped to the inner width and painted like the rest of the border.
+    pub fn title(mut self, text: impl Into<String>, align: Align) -> Self {
+        self.title = Some((text.into(), align));
+        self
+    }
+
+    /// Draw each side `thickness` cells deep (clamped to `1..=MAX_THICKNESS`) by repeating
+    /// the border glyphs inward; corners become solid blocks of the corner glyph.
+    pub fn thickness(mut self, thickness: usize) -> Self {
+        self.thickness = thickness.clamp(1, Self::MAX_THICKNESS);
+        self
+    }
+
+    /// Insert a horizontal rule above content row `at`, joined to the side borders with
+    /// tees (e.g. `├───┤`). Repeatable; `at` past the last row puts the rule below it.
+    pub fn divider_row(mut self, at: usize) -> Self {
+        self.dividers.push(at);
+        self
     }
 
     /// Apply a solid color to the frame.
@@ -122,6 +240,33 @@ impl Frame {
         self
     }
 
+    /// Fill every cell inside the border with a background color.
+    ///
+    /// Content keeps its own foreground, and any background it already has.
+    pub fn fill_background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Add blank space between the content and the border, without moving anything
+    /// outside the frame (unlike [`Banner::padding`](crate::Banner::padding)).
+    pub fn inner_padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.inner_padding = padding.into();
+        self
+    }
+
+    /// Render just this frame around a blank interior of the given size.
+    pub fn render_empty(&self, inner_height: usize, inner_width: usize) -> Grid {
+        apply_frame(Grid::new(inner_height, inner_width), self)
+    }
+
+    /// Columns the frame adds to the banner width.
+    pub(crate) fn horizontal_size(&self) -> usize {
+        (usize::from(self.sides.left) + usize::from(self.sides.right)) * self.thickness
+            + self.inner_padding.left
+            + self.inner_padding.right
+    }
+
     pub(crate) fn chars(&self) -> FrameChars {
         self.chars
     }
@@ -132,37 +277,79 @@ impl Frame {
 }
 
 pub(crate) fn apply_frame(grid: Grid, frame: &Frame) -> Grid {
-    let inner_height = grid.height();
-    let inner_width = grid.width();
-    let out_height = inner_height + 2;
-    let out_width = inner_width + 2;
+    let pad = frame.inner_padding;
+    let sides = frame.sides;
+    let depth = |enabled: bool| usize::from(enabled) * frame.thickness;
+    let (top, bottom) = (depth(sides.top), depth(sides.bottom));
+    let (left, right) = (depth(sides.left), depth(sides.right));
+    let mut dividers: Vec<usize> = frame
+        .dividers
+        .iter()
+        .map(|at| (*at).min(grid.height()))
+        .collect();
+    dividers.sort_unstable();
+    dividers.dedup();
+    let inner_height = (grid.height() + dividers.len())
+        .saturating_add(pad.top)
+        .saturating_add(pad.bottom);
+    let inner_width = grid
+        .width()
+        .saturating_add(pad.left)
+        .saturating_add(pad.right);
+    let out_height = inner_height.saturating_add(top + bottom);
+    let out_width = inner_width.saturating_add(left + right);
     let mut framed = Grid::new(out_height, out_width);
     let chars = frame.chars();
 
-    set_cell(&mut framed, 0, 0, chars.top_left);
-    set_cell(&mut framed, 0, out_width - 1, chars.top_right);
-    set_cell(&mut framed, out_height - 1, 0, chars.bottom_left);
-    set_cell(
-        &mut framed,
-        out_height - 1,
-        out_width - 1,
-        chars.bottom_right,
-    );
-
-    if out_width > 2 {
-        for col in 1..out_width - 1 {
-            set_cell(&mut framed, 0, col, chars.horizontal);
-            set_cell(&mut framed, out_height - 1, col, chars.horizontal);
+    // Corners only exist where both adjacent sides do; a lone rule or bar spans the
+    // content edge without them. Empty content just leaves the edge runs empty.
+    let rule = |first: char, last: char| {
+        (0..out_width).map(move |col| match col {
+            col if col < left => first,
+            col if col >= out_width - right => last,
+            _ => chars.horizontal,
+        })
+    };
+    for row in 0..top {
+        for (col, ch) in rule(chars.top_left, chars.top_right).enumerate() {
+            set_cell(&mut framed, row, col, ch);
         }
     }
-
-    if out_height > 2 {
-        for row in 1..out_height - 1 {
-            set_cell(&mut framed, row, 0, chars.vertical);
-            set_cell(&mut framed, row, out_width - 1, chars.vertical);
+    for row in out_height - bottom..out_height {
+        for (col, ch) in rule(chars.bottom_left, chars.bottom_right).enumerate() {
+            set_cell(&mut framed, row, col, ch);
+        }
+    }
+    for row in top..top + inner_height {
+        for col in (0..left).chain(out_width - right..out_width) {
+            set_cell(&mut framed, row, col, chars.vertical);
+        }
+    }
+    // Each divider sits above its content row, pushing the rows below it down by one.
+    // Tees join the innermost border column; thicker borders stay solid outside them.
+    let divider_rows = dividers
+        .iter()
+        .enumerate()
+        .map(|(idx, at)| top + pad.top + at + idx);
+    for row in divider_rows {
+        let (first, last) = chars
+            .junctions
+            .map_or((chars.vertical, chars.vertical), |j| (j.left, j.right));
+        for (col, ch) in rule(first, last).enumerate() {
+            let outer = col + 1 < left || col > out_width - right;
+            set_cell(
+                &mut framed,
+                row,
+                col,
+                if outer { chars.vertical } else { ch },
+            );
         }
     }
 
+    if let Some((title, align)) = frame.title.as_ref().filter(|_| sides.top) {
+        draw_title(&mut framed, title, *align, left, inner_width);
+    }
+
     if let Some(paint) = frame.paint() {
         match paint {
             FramePaint::Solid(color) => {
@@ -174,10 +361,50 @@ pub(crate) fn apply_frame(grid: Grid, frame: &Frame) -> Grid {
         }
     }
 
-    framed.blit(&grid, 1, 1);
+    // Copy blank cells too so backgrounds and canvas tints survive inside the frame.
+    for (idx, row) in grid.rows().iter().enumerate() {
+        let shift = dividers.partition_point(|at| *at <= idx);
+        let target = top + pad.top + idx + shift;
+        for (col, cell) in row.iter().enumerate() {
+            if let Some(slot) = framed.cell_mut(target, left + pad.left + col) {
+                *slot = cell.clone();
+            }
+        }
+    }
+
+    if let Some(color) = frame.background {
+        for row in &mut framed.rows_mut()[top..top + inner_height] {
+            for cell in &mut row[left..left + inner_width] {
+                cell.bg.get_or_insert(color);
+            }
+        }
+    }
     framed
 }
 
+/// Overwrite the top border between `start` and `start + inner` with `title`, keeping one
+/// border char beside it when it fits.
+fn draw_title(framed: &mut Grid, title: &str, align: Align, start: usize, inner: usize) {
+    let len = title.chars().count();
+    let (label, margin): (Vec<char>, usize) = if len + 4 <= inner {
+        (format!(" {title} ").chars().collect(), 1)
+    } else if len + 2 <= inner {
+        (format!(" {title} ").chars().collect(), 0)
+    } else {
+        (title.chars().take(inner).collect(), 0)
+    };
+    let extra = inner - 2 * margin - label.len();
+    let offset = match align {
+        Align::Left => 0,
+        Align::Center => extra / 2,
+        Align::Right => extra,
+    };
+    let start = start + margin + offset;
+    for (idx, ch) in label.into_iter().enumerate() {
+        set_cell(framed, 0, start + idx, ch);
+    }
+}
+
 fn set_cell(grid: &mut Grid, row: usize, col: usize, ch: char) {
     if let Some(cell) = grid.cell_mut(row, col) {
         cell.ch = ch;
@@ -198,6 +425,7 @@ fn apply_solid_color(grid: &mut Grid, color: Color) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gradient::GradientDirection;
     use crate::grid::Grid;
 
     #[test]
@@ -214,4 +442,193 @@ mod tests {
         assert_eq!(framed.cell(2, 2).unwrap().ch, '┘');
         assert_eq!(framed.cell(1, 1).unwrap().ch, 'A');
     }
+
+    fn frame_rows(grid: Grid) -> Vec<String> {
+        let framed = apply_frame(grid, &Frame::new(FrameStyle::Single));
+        framed
+            .rows()
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.ch).collect())
+            .collect()
+    }
+
+    #[test]
+    fn frames_empty_content_as_corners() {
+        assert_eq!(frame_rows(Grid::new(0, 0)), ["┌┐", "└┘"]);
+    }
+
+    #[test]
+    fn frames_zero_width_row() {
+        assert_eq!(frame_rows(Grid::new(1, 0)), ["┌┐", "││", "└┘"]);
+    }
+
+    #[test]
+    fn frames_single_blank_cell() {
+        assert_eq!(frame_rows(Grid::new(1, 1)), ["┌─┐", "│ │", "└─┘"]);
+    }
+
+    #[test]
+    fn renders_empty_frame() {
+        let grid = Frame::new(FrameStyle::Double).render_empty(3, 5);
+
+        assert_eq!(
+            grid.to_debug_string(),
+            "╔═════╗\n║·····║\n║·····║\n║·····║\n╚═════╝"
+        );
+    }
+
+    #[test]
+    fn title_is_embedded_in_top_border() {
+        let top = |title: &str, align, width| {
+            let frame = Frame::new(FrameStyle::Single).title(title, align);
+            let grid = frame.render_empty(1, width);
+            grid.rows()[0]
+                .iter()
+                .map(|cell| cell.ch)
+                .collect::<String>()
+        };
+
+        assert_eq!(top("BUILD", Align::Center, 9), "┌─ BUILD ─┐");
+        assert_eq!(top("OK", Align::Left, 9), "┌─ OK ────┐");
+        assert_eq!(top("OK", Align::Right, 9), "┌──── OK ─┐");
+        assert_eq!(top("BUILD", Align::Center, 7), "┌ BUILD ┐");
+        assert_eq!(top("BUILD", Align::Center, 3), "┌BUI┐");
+        assert_eq!(top("X", Align::Center, 0), "┌┐");
+
+        let painted = Frame::new(FrameStyle::Single)
+            .title("A B", Align::Left)
+            .color(Color::Rgb(1, 2, 3))
+            .render_empty(1, 9);
+        let title = &painted.rows()[0];
+        assert_eq!(title[3].fg, Some(Color::Rgb(1, 2, 3)));
+        // The spaces around and inside the label stay blank.
+        assert!(!title[2].visible && !title[4].visible);
+    }
+
+    #[test]
+    fn inner_padding_grows_the_border_only() {
+        let grid = Grid::from_char_rows(vec![vec!['A']]);
+        let frame = Frame::new(FrameStyle::Single).inner_padding((0, 2, 1, 1));
+        let framed = apply_frame(grid, &frame);
+
+        assert_eq!(framed.to_debug_string(), "┌────┐\n│·A··│\n│····│\n└────┘");
+        assert_eq!(frame.horizontal_size(), 5);
+    }
+
+    #[test]
+    fn partial_frames_grow_only_by_enabled_sides() {
+        let framed = |frame: Frame| apply_frame(Grid::from_char_rows(vec![vec!['A'; 2]]), &frame);
+        let single = || Frame::new(FrameStyle::Single);
+
+        let rules = framed(single().sides(true, true, false, false));
+        assert_eq!(rules.to_debug_string(), "──\nAA\n──");
+
+        let bars = framed(single().sides(false, false, true, true));
+        assert_eq!(bars.to_debug_string(), "│AA│");
+
+        let open_right = single()
+            .sides(true, true, true, false)
+            .title("T", Align::Left);
+        assert_eq!(open_right.horizontal_size(), 1);
+        assert_eq!(framed(open_right).to_debug_string(), "┌T─\n│AA\n└──");
+
+        let untitled = single()
+            .sides(false, true, true, true)
+            .title("T", Align::Left);
+        assert_eq!(framed(untitled).to_debug_string(), "│AA│\n└──┘");
+        assert_eq!(
+            framed(single().sides(false, false, false, false)).to_debug_string(),
+            "AA"
+        );
+    }
+
+    #[test]
+    fn divider_rows_insert_rules_with_junctions() {
+        let grid = Grid::from_char_rows(vec![vec!['A'], vec!['B'], vec!['C']]);
+        let framed = |frame: Frame| apply_frame(grid.clone(), &frame).to_debug_string();
+
+        assert_eq!(
+            framed(Frame::new(FrameStyle::Single).divider_row(1)),
+            "┌─┐\n│A│\n├─┤\n│B│\n│C│\n└─┘"
+        );
+        assert_eq!(
+            framed(Frame::new(FrameStyle::Double).divider_row(9).divider_row(2)),
+            "╔═╗\n║A║\n║B║\n╠═╣\n║C║\n╠═╣\n╚═╝"
+        );
+        assert_eq!(
+            framed(
+                Frame::new(FrameStyle::Heavy)
+                    .divider_row(0)
+                    .inner_padding(1)
+            ),
+            "┏━━━┓\n┃···┃\n┣━━━┫\n┃·A·┃\n┃·B·┃\n┃·C·┃\n┃···┃\n┗━━━┛"
+        );
+        let plain = Frame::custom(FrameChars::new('+', '+', '+', '+', '=', '!'));
+        assert_eq!(
+            framed(plain.divider_row(1).sides(false, false, true, false)),
+            "!A\n!=\n!B\n!C"
+        );
+    }
+
+    #[test]
+    fn thick_frames_repeat_the_border_inward() {
+        let frame = Frame::new(FrameStyle::Single).thickness(2);
+        assert_eq!(frame.horizontal_size(), 4);
+        assert_eq!(
+            frame.render_empty(1, 2).to_debug_string(),
+            "┌┌──┐┐\n┌┌──┐┐\n││··││\n└└──┘┘\n└└──┘┘"
+        );
+
+        let grid = Grid::from_char_rows(vec![vec!['A'], vec!['B']]);
+        let split = apply_frame(grid, &frame.divider_row(1));
+        assert_eq!(
+            split.rows()[3].iter().map(|c| c.ch).collect::<String>(),
+            "│├─┤│"
+        );
+
+        let painted = Frame::new(FrameStyle::Ascii)
+            .thickness(3)
+            .gradient(Gradient::new(
+                vec![Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255)],
+                GradientDirection::Horizontal,
+            ))
+            .render_empty(1, 1);
+        assert_eq!(painted.width(), 7);
+        let border = painted.rows().iter().flatten().filter(|cell| cell.visible);
+        assert!(border.clone().all(|cell| cell.fg.is_some()));
+        assert_eq!(border.count(), 7 * 7 - 1);
+
+        let huge = Frame::new(FrameStyle::Single).thickness(usize::MAX);
+        assert_eq!(huge.horizontal_size(), 2 * Frame::MAX_THICKNESS);
+    }
+
+    #[test]
+    fn fill_background_covers_the_interior_only() {
+        let mut grid = Grid::from_char_rows(vec![vec!['A', ' ']]);
+        grid.rows_mut()[0][0].fg = Some(Color::Rgb(1, 1, 1));
+        grid.rows_mut()[0][1].bg = Some(Color::Rgb(2, 2, 2));
+        let fill = Color::Rgb(9, 9, 9);
+        let frame = Frame::new(FrameStyle::Single)
+            .inner_padding((0, 0, 0, 1))
+            .fill_background(fill);
+        let framed = apply_frame(grid, &frame);
+
+        let bgs: Vec<Vec<Option<Color>>> = framed
+            .rows()
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.bg).collect())
+            .collect();
+        assert_eq!(bgs[0], [None; 5]);
+        assert_eq!(
+            bgs[1],
+            [
+                None,
+                Some(fill),
+                Some(fill),
+                Some(Color::Rgb(2, 2, 2)),
+                None
+            ]
+        );
+        assert_eq!(framed.cell(1, 2).unwrap().fg, Some(Color::Rgb(1, 1, 1)));
+    }
 }
@@ -10,18 +10,131 @@
 // distributed under the License is distributed on an "AS IS" BASIS,
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 
-use crate::color::{Color, Palette};
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::color::{Color, ColorSpace, Palette};
+use crate::fill::mix;
 use crate::grid::Grid;
 
+/// Terminal cells are about twice as tall as wide; rows are scaled by this to look square.
+const CELL_ASPECT: f32 = 2.0;
+
 /// Gradient definition for coloring a grid.
+///
+/// With the `serde` feature, masks are skipped when (de)serializing.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Gradient {
     stops: Vec<Color>,
     direction: GradientDirection,
+    #[cfg_attr(feature = "serde", serde(default))]
+    space: ColorSpace,
+    #[cfg_attr(feature = "serde", serde(default))]
+    spread: GradientSpread,
+    #[cfg_attr(feature = "serde", serde(default = "one_cycle"))]
+    cycles: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    mask: Option<GradientMask>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    reversed: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    gamma_correct: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    easing: Easing,
+}
+
+#[cfg(feature = "serde")]
+fn one_cycle() -> f32 {
+    1.0
+}
+
+/// Limits which cells a [`Gradient`] colors.
+///
+/// Positions along the gradient are still measured over the whole grid.
+#[derive(Clone)]
+pub enum GradientMask {
+    /// Only rows in the range.
+    Rows(Range<usize>),
+    /// Only columns in the range.
+    Cols(Range<usize>),
+    /// Only cells where the closure returns `true` for `(row, col)`.
+    Custom(Arc<dyn Fn(usize, usize) -> bool + Send + Sync>),
+}
+
+impl GradientMask {
+    /// Build a mask from a `(row, col)` predicate.
+    pub fn custom(f: impl Fn(usize, usize) -> bool + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(f))
+    }
+
+    fn contains(&self, r: usize, c: usize) -> bool {
+        match self {
+            Self::Rows(rows) => rows.contains(&r),
+            Self::Cols(cols) => cols.contains(&c),
+            Self::Custom(f) => f(r, c),
+        }
+    }
+}
+
+impl fmt::Debug for GradientMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rows(rows) => f.debug_tuple("Rows").field(rows).finish(),
+            Self::Cols(cols) => f.debug_tuple("Cols").field(cols).finish(),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// How the palette behaves when repeated across the axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum GradientSpread {
+    /// Stretch the palette once; positions past the end keep the last color (default).
+    #[default]
+    Clamp,
+    /// Restart the palette every cycle.
+    Repeat,
+    /// Alternate forward and backward cycles to avoid seams.
+    Mirror,
+}
+
+/// Curve applied to a cell's position before the color lookup.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Easing {
+    /// Colors evenly spaced along the axis (default).
+    #[default]
+    Linear,
+    /// Start slowly: the first stop lingers.
+    EaseIn,
+    /// End slowly: the last stop lingers.
+    EaseOut,
+    /// Linger at both ends and change fastest in the middle.
+    SmoothStep,
+}
+
+impl Easing {
+    /// Map `t` (`0.0..=1.0`) through the curve.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::SmoothStep => smoothstep(t),
+        }
+    }
 }
 
 /// Gradient direction.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum GradientDirection {
     /// Top to bottom.
     Vertical,
@@ -29,12 +142,35 @@ pub enum GradientDirection {
     Horizontal,
     /// Top-left to bottom-right.
     Diagonal,
+    /// Linear ramp at an angle in degrees, clockwise from left-to-right: `0.0` matches
+    /// [`Horizontal`](GradientDirection::Horizontal) and `90.0` matches
+    /// [`Vertical`](GradientDirection::Vertical).
+    ///
+    /// Rows are scaled for the cell aspect ratio, so 45° looks like 45° on screen.
+    Angle(f32),
+    /// Smooth 2D value noise instead of a linear ramp (see [`Gradient::plasma`]).
+    Plasma {
+        /// Approximate blob size in columns.
+        scale: f32,
+        /// Noise seed; equal seeds give identical output.
+        seed: u32,
+    },
 }
 
 impl Gradient {
     /// Create a gradient from color stops and direction.
     pub fn new(stops: Vec<Color>, direction: GradientDirection) -> Self {
-        Self { stops, direction }
+        Self {
+            stops,
+            direction,
+            space: ColorSpace::Rgb,
+            spread: GradientSpread::Clamp,
+            cycles: 1.0,
+            mask: None,
+            reversed: false,
+            gamma_correct: false,
+            easing: Easing::Linear,
+        }
     }
 
     /// Vertical gradient (top -> bottom).
@@ -52,52 +188,274 @@ impl Gradient {
         Self::new(palette.colors().to_vec(), GradientDirection::Diagonal)
     }
 
+    /// Nebula-like gradient sampling the palette through smooth value noise.
+    ///
+    /// `scale` is the approximate blob size in columns; the same `seed` always
+    /// produces the same colors.
+    pub fn plasma(palette: Palette, scale: f32, seed: u32) -> Self {
+        Self::new(
+            palette.colors().to_vec(),
+            GradientDirection::Plasma { scale, seed },
+        )
+    }
+
+    /// Interpolate between stops in the given color space (default: RGB).
+    pub fn color_space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Blend RGB stops in linear light instead of raw sRGB bytes (default: off).
+    ///
+    /// Avoids the dark, muddy midpoints of plain RGB fades. Only affects
+    /// [`ColorSpace::Rgb`]; HSL and Oklab blends are unchanged.
+    pub fn gamma_correct(mut self, enabled: bool) -> Self {
+        self.gamma_correct = enabled;
+        self
+    }
+
+    /// Bias where colors sit along the axis (default: [`Easing::Linear`]).
+    ///
+    /// With repeats, the curve applies within every cycle.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Cycle the palette `n` times across the axis (switches `Clamp` to `Repeat`).
+    pub fn repeat(mut self, n: f32) -> Self {
+        self.cycles = n.max(0.0);
+        if self.spread == GradientSpread::Clamp {
+            self.spread = GradientSpread::Repeat;
+        }
+        self
+    }
+
+    /// Set how repeated cycles join (see [`GradientSpread`]).
+    pub fn spread(mut self, spread: GradientSpread) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Run the gradient the other way (a vertical gradient goes bottom to top).
+    pub fn reversed(mut self) -> Self {
+        self.reversed = !self.reversed;
+        self
+    }
+
+    /// Restrict the gradient to the cells selected by `mask`.
+    pub fn masked(mut self, mask: GradientMask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
     /// Apply the gradient to a grid in-place.
     pub fn apply(&self, grid: &mut Grid) {
         if self.stops.is_empty() {
             return;
         }
 
+        self.apply_shifted(grid, 0.0);
+    }
+
+    /// Apply the gradient with every cell's position shifted by `phase` (wrapping).
+    fn apply_shifted(&self, grid: &mut Grid, phase: f32) {
+        if self.stops.is_empty() {
+            return;
+        }
+
         let height = grid.height().max(1);
         let width = grid.width().max(1);
 
         for r in 0..height {
             for c in 0..width {
-                let t = match self.direction {
-                    GradientDirection::Vertical => {
-                        if height <= 1 {
-                            0.0
-                        } else {
-                            r as f32 / (height - 1) as f32
-                        }
-                    }
-                    GradientDirection::Horizontal => {
-                        if width <= 1 {
-                            0.0
-                        } else {
-                            c as f32 / (width - 1) as f32
-                        }
-                    }
-                    GradientDirection::Diagonal => {
-                        if width + height <= 2 {
-                            0.0
-                        } else {
-                            (r + c) as f32 / (width + height - 2) as f32
-                        }
-                    }
-                };
-
+                let t = shift(self.position(r, c, height, width), phase);
+                if !self.covers(r, c) {
+                    continue;
+                }
                 if let Some(cell) = grid.cell_mut(r, c)
                     && cell.visible
                 {
-                    cell.fg = Some(color_at(&self.stops, t));
+                    cell.fg = Some(self.sample(t));
                 }
             }
         }
     }
+
+    /// Paint cell backgrounds across the whole grid, blank cells included.
+    pub(crate) fn apply_background(&self, grid: &mut Grid) {
+        if self.stops.is_empty() {
+            return;
+        }
+
+        let height = grid.height();
+        let width = grid.width();
+        for (r, row) in grid.rows_mut().iter_mut().enumerate() {
+            for (c, cell) in row.iter_mut().enumerate() {
+                if self.covers(r, c) {
+                    cell.bg = Some(self.sample(self.position(r, c, height, width)));
+                }
+            }
+        }
+    }
+
+    fn covers(&self, r: usize, c: usize) -> bool {
+        self.mask.as_ref().is_none_or(|mask| mask.contains(r, c))
+    }
+
+    /// Color at axis position `t` after applying the spread.
+    pub(crate) fn sample(&self, t: f32) -> Color {
+        let u = t * self.cycles;
+        let t = match self.spread {
+            GradientSpread::Clamp => u,
+            // The far end of the axis closes the last cycle instead of restarting it.
+            GradientSpread::Repeat if t >= 1.0 && u > 0.0 && u.fract() == 0.0 => 1.0,
+            GradientSpread::Repeat => u.rem_euclid(1.0),
+            GradientSpread::Mirror => {
+                let m = u.rem_euclid(2.0);
+                if m > 1.0 { 2.0 - m } else { m }
+            }
+        };
+        color_at(
+            &self.stops,
+            self.easing.apply(t),
+            self.space,
+            self.gamma_correct,
+        )
+    }
+
+    fn position(&self, r: usize, c: usize, height: usize, width: usize) -> f32 {
+        let t = self.axis_position(r, c, height, width);
+        if self.reversed { 1.0 - t } else { t }
+    }
+
+    fn axis_position(&self, r: usize, c: usize, height: usize, width: usize) -> f32 {
+        match self.direction {
+            GradientDirection::Vertical => {
+                if height <= 1 {
+                    0.0
+                } else {
+                    r as f32 / (height - 1) as f32
+                }
+            }
+            GradientDirection::Horizontal => {
+                if width <= 1 {
+                    0.0
+                } else {
+                    c as f32 / (width - 1) as f32
+                }
+            }
+            GradientDirection::Diagonal => {
+                if width + height <= 2 {
+                    0.0
+                } else {
+                    (r + c) as f32 / (width + height - 2) as f32
+                }
+            }
+            GradientDirection::Angle(degrees) => {
+                // Snap float noise so right angles match the axis directions exactly.
+                let snap = |v: f32| if v.abs() < 1e-6 { 0.0 } else { v };
+                let (sin, cos) = degrees.to_radians().sin_cos();
+                let (sin, cos) = (snap(sin), snap(cos));
+                let project = |r: f32, c: f32| c * cos + CELL_ASPECT * r * sin;
+                let (bottom, right) = ((height - 1) as f32, (width - 1) as f32);
+                let corners = [
+                    project(0.0, 0.0),
+                    project(0.0, right),
+                    project(bottom, 0.0),
+                    project(bottom, right),
+                ];
+                let min = corners.iter().copied().fold(f32::INFINITY, f32::min);
+                let max = corners.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+                if max - min <= f32::EPSILON {
+                    0.0
+                } else {
+                    (project(r as f32, c as f32) - min) / (max - min)
+                }
+            }
+            GradientDirection::Plasma { scale, seed } => {
+                let scale = scale.max(1.0);
+                value_noise(seed, c as f32 / scale, CELL_ASPECT * r as f32 / scale)
+            }
+        }
+    }
+}
+
+/// Per-cell gradient positions precomputed for one grid size.
+///
+/// Animations that only move the gradient phase reuse the table instead of
+/// recomputing geometry every frame.
+pub(crate) struct GradientTable {
+    gradient: Gradient,
+    width: usize,
+    positions: Vec<f32>,
 }
 
-fn color_at(stops: &[Color], t: f32) -> Color {
+impl GradientTable {
+    /// Precompute positions for a `height` x `width` grid.
+    pub(crate) fn new(gradient: &Gradient, height: usize, width: usize) -> Self {
+        let (h, w) = (height.max(1), width.max(1));
+        let positions = (0..h)
+            .flat_map(|r| (0..w).map(move |c| (r, c)))
+            .map(|(r, c)| gradient.position(r, c, h, w))
+            .collect();
+        Self {
+            gradient: gradient.clone(),
+            width: w,
+            positions,
+        }
+    }
+
+    /// Color visible cells with the gradient shifted by `phase`.
+    pub(crate) fn apply(&self, grid: &mut Grid, phase: f32) {
+        if self.gradient.stops.is_empty() {
+            return;
+        }
+        for (r, row) in grid.rows_mut().iter_mut().enumerate() {
+            for (c, cell) in row.iter_mut().enumerate() {
+                if !cell.visible || c >= self.width || !self.gradient.covers(r, c) {
+                    continue;
+                }
+                if let Some(t) = self.positions.get(r * self.width + c) {
+                    cell.fg = Some(self.gradient.sample(shift(*t, phase)));
+                }
+            }
+        }
+    }
+}
+
+fn shift(t: f32, phase: f32) -> f32 {
+    if phase == 0.0 {
+        t
+    } else {
+        (t + phase).rem_euclid(1.0)
+    }
+}
+
+/// Bilinear value noise in `0.0..=1.0` over an integer lattice.
+fn value_noise(seed: u32, x: f32, y: f32) -> f32 {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (smoothstep(x - x0), smoothstep(y - y0));
+    let (ix, iy) = (x0 as i32, y0 as i32);
+
+    let top = lerp(lattice(seed, ix, iy), lattice(seed, ix + 1, iy), fx);
+    let bottom = lerp(lattice(seed, ix, iy + 1), lattice(seed, ix + 1, iy + 1), fx);
+    lerp(top, bottom, fy)
+}
+
+fn lattice(seed: u32, x: i32, y: i32) -> f32 {
+    (mix(seed, x as u32, y as u32) & 0xFFFF) as f32 / 65535.0
+}
+
+fn smoothstep(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+fn color_at(stops: &[Color], t: f32, space: ColorSpace, gamma_correct: bool) -> Color {
     if stops.len() == 1 {
         return stops[0];
     }
@@ -109,5 +467,200 @@ fn color_at(stops: &[Color], t: f32) -> Color {
     let next = idx.min(max_index - 1) + 1;
     let local_t = scaled - idx as f32;
 
-    stops[idx].lerp(stops[next], local_t)
+    if gamma_correct && space == ColorSpace::Rgb {
+        return stops[idx].lerp_linear(stops[next], local_t);
+    }
+    stops[idx].lerp_in(stops[next], local_t, space)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mirrored_repeat_is_symmetric() {
+        let gradient = Gradient::horizontal(Palette::from_hex(&["#ff0000", "#00ff00", "#0000ff"]))
+            .repeat(2.0)
+            .spread(GradientSpread::Mirror);
+        let mut grid = Grid::from_char_rows(vec![vec!['#'; 100]]);
+        gradient.apply(&mut grid);
+
+        let row = &grid.rows()[0];
+        for c in 0..50 {
+            assert_eq!(row[c].fg, row[99 - c].fg, "column {c}");
+        }
+        assert_ne!(row[0].fg, row[49].fg);
+    }
+
+    #[test]
+    fn repeat_ends_on_the_last_stop() {
+        let gradient = Gradient::horizontal(Palette::from_hex(&["#ff0000", "#0000ff"])).repeat(2.0);
+        assert_eq!(gradient.sample(0.0), Color::Rgb(255, 0, 0));
+        assert_eq!(gradient.sample(0.5), Color::Rgb(255, 0, 0));
+        assert_eq!(gradient.sample(1.0), Color::Rgb(0, 0, 255));
+
+        let mut grid = Grid::from_char_rows(vec![vec!['#'; 9]]);
+        gradient.apply(&mut grid);
+        assert_eq!(grid.rows()[0][8].fg, Some(Color::Rgb(0, 0, 255)));
+    }
+
+    #[test]
+    fn table_matches_per_frame_recompute() {
+        let gradient = Gradient::diagonal(Palette::from_hex(&["#ff0000", "#00ff00", "#0000ff"]));
+        let base = Grid::from_char_rows(vec![vec!['#'; 9]; 4]);
+        let table = GradientTable::new(&gradient, base.height(), base.width());
+
+        for step in 0..8 {
+            let phase = step as f32 / 8.0;
+            let mut naive = base.clone();
+            gradient.apply_shifted(&mut naive, phase);
+            let mut fast = base.clone();
+            table.apply(&mut fast, phase);
+
+            for (a, b) in naive
+                .rows()
+                .iter()
+                .flatten()
+                .zip(fast.rows().iter().flatten())
+            {
+                assert_eq!(a.fg, b.fg);
+            }
+        }
+    }
+
+    #[test]
+    fn masked_gradient_leaves_other_cells_alone() {
+        let mut grid = Grid::from_char_rows(vec![vec!['#'; 2]; 3]);
+        grid.rows_mut()[2][0].fg = Some(Color::Rgb(1, 2, 3));
+        Gradient::horizontal(Palette::from_hex(&["#ff0000"]))
+            .masked(GradientMask::Rows(0..2))
+            .apply(&mut grid);
+        Gradient::horizontal(Palette::from_hex(&["#0000ff"]))
+            .masked(GradientMask::custom(|r, c| r == 1 && c == 1))
+            .apply(&mut grid);
+
+        let fg = |r: usize, c: usize| grid.cell(r, c).unwrap().fg;
+        assert_eq!(fg(0, 0), Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(fg(1, 0), Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(fg(1, 1), Some(Color::Rgb(0, 0, 255)));
+        assert_eq!(fg(2, 0), Some(Color::Rgb(1, 2, 3)));
+        assert_eq!(fg(2, 1), None);
+    }
+
+    #[test]
+    fn reversed_matches_reversed_palette() {
+        let hexes = ["#ff0000", "#00ff00", "#0000ff"];
+        for palette in [Palette::from_hex(&hexes), Palette::from_hex(&hexes[..1])] {
+            for height in [1, 4, 7] {
+                let mut flipped = Grid::from_char_rows(vec![vec!['#'; 2]; height]);
+                let mut rebuilt = flipped.clone();
+                Gradient::vertical(palette.clone())
+                    .reversed()
+                    .apply(&mut flipped);
+                Gradient::vertical(palette.reversed()).apply(&mut rebuilt);
+                let fgs = |grid: &Grid| -> Vec<Option<Color>> {
+                    grid.rows().iter().flatten().map(|cell| cell.fg).collect()
+                };
+                assert_eq!(fgs(&flipped), fgs(&rebuilt));
+            }
+        }
+    }
+
+    #[test]
+    fn plasma_is_deterministic() {
+        let render = |seed: u32| -> Vec<String> {
+            let mut grid = Grid::from_char_rows(vec![vec!['#'; 16]; 4]);
+            Gradient::plasma(Palette::from_hex(&["#000000", "#ffffff"]), 4.0, seed)
+                .apply(&mut grid);
+            grid.rows()
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|cell| {
+                            let (v, _, _) = cell.fg.unwrap().rgb_components();
+                            char::from(b'0' + v / 26)
+                        })
+                        .collect()
+                })
+                .collect()
+        };
+
+        let expected = [
+            "6666666667889875",
+            "4456666667889864",
+            "2356777667789752",
+            "4445555555554431",
+        ];
+        assert_eq!(render(7), expected);
+        assert_ne!(render(8), expected);
+    }
+
+    #[test]
+    fn gamma_correct_brightens_midpoints() {
+        let stops = [Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255)];
+        assert_eq!(
+            color_at(&stops, 0.5, ColorSpace::Rgb, false),
+            Color::Rgb(128, 0, 128)
+        );
+        assert_eq!(
+            color_at(&stops, 0.5, ColorSpace::Rgb, true),
+            Color::Rgb(188, 0, 188)
+        );
+        assert_eq!(color_at(&stops, 0.0, ColorSpace::Rgb, true), stops[0]);
+        assert_eq!(color_at(&stops, 1.0, ColorSpace::Rgb, true), stops[1]);
+
+        let plain = Gradient::horizontal(Palette::new(stops.to_vec()));
+        let gamma = plain.clone().gamma_correct(true);
+        assert_eq!(plain.sample(0.5), Color::Rgb(128, 0, 128));
+        assert_eq!(gamma.sample(0.5), Color::Rgb(188, 0, 188));
+    }
+
+    #[test]
+    fn easing_biases_positions() {
+        let palette = Palette::from_hex(&["#000000", "#ffffff"]);
+        let linear = Gradient::vertical(palette.clone());
+        assert_eq!(linear.sample(0.5), Color::Rgb(128, 128, 128));
+        assert_eq!(
+            linear.clone().easing(Easing::EaseIn).sample(0.5),
+            Color::Rgb(64, 64, 64)
+        );
+        assert_eq!(
+            linear.clone().easing(Easing::EaseOut).sample(0.5),
+            Color::Rgb(191, 191, 191)
+        );
+        let smooth = linear.easing(Easing::SmoothStep);
+        assert_eq!(smooth.sample(0.5), Color::Rgb(128, 128, 128));
+        assert_eq!(smooth.sample(1.0), Color::Rgb(255, 255, 255));
+    }
+
+    #[test]
+    fn angle_matches_axis_directions() {
+        let palette = Palette::from_hex(&["#ff0000", "#00ff00", "#0000ff"]);
+        let paint = |direction| {
+            let mut grid = Grid::from_char_rows(vec![vec!['#'; 9]; 5]);
+            Gradient::new(palette.colors().to_vec(), direction).apply(&mut grid);
+            grid.rows()
+                .iter()
+                .map(|row| row.iter().map(|cell| cell.fg).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            paint(GradientDirection::Angle(0.0)),
+            paint(GradientDirection::Horizontal)
+        );
+        assert_eq!(
+            paint(GradientDirection::Angle(90.0)),
+            paint(GradientDirection::Vertical)
+        );
+
+        let reversed = paint(GradientDirection::Angle(180.0));
+        assert_eq!(reversed[0][0], Some(Color::Rgb(0, 0, 255)));
+        assert_eq!(reversed[0][8], Some(Color::Rgb(255, 0, 0)));
+
+        // 30° on a 9 x 5 grid: rows still matter, but less than columns.
+        let tilted = paint(GradientDirection::Angle(30.0));
+        assert_eq!(tilted[0][0], Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(tilted[4][8], Some(Color::Rgb(0, 0, 255)));
+        assert_ne!(tilted[0][4], tilted[4][4]);
+    }
 }
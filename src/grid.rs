@@ -10,10 +10,48 @@
 // distributed under the License is distributed on an "AS IS" BASIS,
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 
-use crate::color::Color;
+use std::collections::HashMap;
+
+use std::sync::Arc;
+
+use crate::color::{Color, ColorMode};
+
+/// Color conversions for one output mode, cached across grids.
+///
+/// Animations keep one for the whole playback so each distinct color is converted once,
+/// not once per frame.
+pub(crate) struct Quantizer {
+    mode: ColorMode,
+    cache: HashMap<Color, Color>,
+}
+
+impl Quantizer {
+    pub(crate) fn new(mode: ColorMode) -> Self {
+        Self {
+            mode,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Convert `grid`'s colors in place (a no-op outside the palette modes).
+    pub(crate) fn apply(&mut self, grid: &mut Grid) {
+        let mode = self.mode;
+        if !matches!(mode, ColorMode::Ansi256 | ColorMode::Ansi16) {
+            return;
+        }
+        let cache = &mut self.cache;
+        let mut convert =
+            |color: Color| *cache.entry(color).or_insert_with(|| color.quantize(mode));
+        grid.map_cells(|_, _, cell| {
+            cell.fg = cell.fg.map(&mut convert);
+            cell.bg = cell.bg.map(&mut convert);
+        });
+    }
+}
 
 /// Single cell in the grid.
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct Cell {
     /// Character rendered at this cell.
     pub ch: char,
@@ -23,6 +61,21 @@ pub struct Cell {
     pub bg: Option<Color>,
     /// Visibility flag (used for effects).
     pub visible: bool,
+    /// OSC 8 hyperlink target for this cell.
+    pub link: Option<Arc<str>>,
+}
+
+impl Cell {
+    /// Uncolored, unlinked cell; visible unless `ch` is a space.
+    pub fn new(ch: char) -> Self {
+        Self {
+            ch,
+            fg: None,
+            bg: None,
+            visible: ch != ' ',
+            link: None,
+        }
+    }
 }
 
 /// 2D grid of cells.
@@ -31,8 +84,21 @@ pub struct Grid {
     cells: Vec<Vec<Cell>>,
 }
 
+/// How [`Grid::overlay`] combines cells.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Blend {
+    /// Copy every cell, including blank ones (punches holes).
+    Replace,
+    /// Copy visible cells only where the target is not already visible.
+    KeepUnderVisible,
+    /// Like `KeepUnderVisible`, darkening the copied colors by the given amount (0..=1).
+    AlphaDarken(f32),
+}
+
 /// Horizontal alignment.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum Align {
     /// Align to the left.
     Left,
@@ -44,6 +110,7 @@ pub enum Align {
 
 /// Padding around a grid.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Padding {
     /// Top padding.
     pub top: usize,
@@ -58,15 +125,7 @@ pub struct Padding {
 impl Grid {
     /// Create an empty grid with given dimensions.
     pub fn new(height: usize, width: usize) -> Self {
-        let row = vec![
-            Cell {
-                ch: ' ',
-                fg: None,
-                bg: None,
-                visible: false,
-            };
-            width
-        ];
+        let row = vec![Cell::new(' '); width];
         let cells = vec![row; height];
         Self { cells }
     }
@@ -75,20 +134,21 @@ impl Grid {
     pub fn from_char_rows(rows: Vec<Vec<char>>) -> Self {
         let cells = rows
             .into_iter()
-            .map(|row| {
-                row.into_iter()
-                    .map(|ch| Cell {
-                        ch,
-                        fg: None,
-                        bg: None,
-                        visible: ch != ' ',
-                    })
-                    .collect::<Vec<_>>()
-            })
+            .map(|row| row.into_iter().map(Cell::new).collect::<Vec<_>>())
             .collect::<Vec<_>>();
         Self { cells }
     }
 
+    /// Build a grid from cells; shorter rows are padded with blank cells.
+    pub fn from_cells(mut cells: Vec<Vec<Cell>>) -> Self {
+        let width = cells.iter().map(Vec::len).max().unwrap_or(0);
+        let blank = Cell::new(' ');
+        for row in &mut cells {
+            row.resize(width, blank.clone());
+        }
+        Self { cells }
+    }
+
     /// Height of the grid.
     pub fn height(&self) -> usize {
         self.cells.len()
@@ -109,6 +169,20 @@ impl Grid {
         self.cells.get(row).and_then(|r| r.get(col))
     }
 
+    /// Character layout for readable test diffs: one line per row, colors ignored, and
+    /// invisible cells shown as `·`.
+    pub fn to_debug_string(&self) -> String {
+        self.cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| if cell.visible { cell.ch } else { '·' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Borrow rows.
     pub fn rows(&self) -> &[Vec<Cell>] {
         &self.cells
@@ -119,6 +193,22 @@ impl Grid {
         &mut self.cells
     }
 
+    /// Call `f(row, col, cell)` for every cell.
+    pub fn map_cells(&mut self, mut f: impl FnMut(usize, usize, &mut Cell)) {
+        for (r, row) in self.cells.iter_mut().enumerate() {
+            for (c, cell) in row.iter_mut().enumerate() {
+                f(r, c, cell);
+            }
+        }
+    }
+
+    /// Pre-convert cell colors to what `mode` can display (see [`Color::quantize`]).
+    ///
+    /// Each distinct color is converted once per call.
+    pub fn quantize_colors(&mut self, mode: ColorMode) {
+        Quantizer::new(mode).apply(self);
+    }
+
     /// Blit another grid onto this grid at the given offset.
     pub fn blit(&mut self, other: &Grid, top: usize, left: usize) {
         for (r, row) in other.cells.iter().enumerate() {
@@ -133,11 +223,55 @@ impl Grid {
                 }
                 if cell.visible {
                     self.cells[target_r][target_c] = cell.clone();
+                } else if cell.link.is_some() {
+                    // Blank cells are transparent, but keep hyperlinks contiguous.
+                    self.cells[target_r][target_c].link = cell.link.clone();
+                }
+            }
+        }
+    }
+
+    /// Draw another grid onto this one at the given offset, clipped to bounds.
+    pub fn overlay(&mut self, other: &Grid, top: usize, left: usize, blend: Blend) {
+        for (r, row) in other.cells.iter().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                let Some(target) = self.cell_mut(top + r, left + c) else {
+                    continue;
+                };
+                match blend {
+                    Blend::Replace => *target = cell.clone(),
+                    Blend::KeepUnderVisible | Blend::AlphaDarken(_) => {
+                        if !cell.visible || target.visible {
+                            continue;
+                        }
+                        *target = cell.clone();
+                        if let Blend::AlphaDarken(amount) = blend {
+                            target.fg = cell.fg.map(|color| color.darken(amount));
+                        }
+                    }
                 }
             }
         }
     }
 
+    /// Join grids side by side (top-aligned), separated by `gap` blank columns.
+    pub fn concat(parts: &[Grid], gap: usize) -> Self {
+        let height = parts.iter().map(Grid::height).max().unwrap_or(0);
+        let width =
+            parts.iter().map(Grid::width).sum::<usize>() + gap * parts.len().saturating_sub(1);
+        let mut out = Grid::new(height, width);
+        let mut left = 0;
+        for part in parts {
+            for (r, row) in part.cells.iter().enumerate() {
+                for (c, cell) in row.iter().enumerate() {
+                    out.cells[r][left + c] = cell.clone();
+                }
+            }
+            left += part.width() + gap;
+        }
+        out
+    }
+
     /// Trim fully blank rows from the top and bottom.
     pub fn trim_vertical(&self) -> Self {
         if self.height() == 0 {
@@ -163,6 +297,61 @@ impl Grid {
             cells: self.cells[top..bottom].to_vec(),
         }
     }
+
+    /// Copy out a `height` x `width` window starting at (`top`, `left`).
+    ///
+    /// Parts of the window outside the grid come back as blank cells.
+    pub fn crop(&self, top: usize, left: usize, height: usize, width: usize) -> Grid {
+        let mut out = Grid::new(height, width);
+        for (r, row) in out.cells.iter_mut().enumerate() {
+            let Some(source) = top.checked_add(r).and_then(|index| self.cells.get(index)) else {
+                break;
+            };
+            for (slot, cell) in row.iter_mut().zip(source.iter().skip(left)) {
+                *slot = cell.clone();
+            }
+        }
+        out
+    }
+
+    /// Mirror the grid left to right. Cells are only reordered; glyphs are not mirrored.
+    pub fn flip_horizontal(&self) -> Grid {
+        let cells = self
+            .cells
+            .iter()
+            .map(|row| row.iter().rev().cloned().collect())
+            .collect();
+        Grid { cells }
+    }
+
+    /// Mirror the grid top to bottom, e.g. for a reflection below the text.
+    pub fn flip_vertical(&self) -> Grid {
+        Grid {
+            cells: self.cells.iter().rev().cloned().collect(),
+        }
+    }
+
+    /// Turn the grid upside down (both flips at once).
+    pub fn rotate_180(&self) -> Grid {
+        self.flip_vertical().flip_horizontal()
+    }
+
+    /// Surround the grid with blank cells.
+    pub fn pad(&self, padding: Padding) -> Grid {
+        let blank = Cell::new(' ');
+        self.pad_with(padding, &blank)
+    }
+
+    /// Surround the grid with copies of `fill`, keeping every content cell as is.
+    pub(crate) fn pad_with(&self, padding: Padding, fill: &Cell) -> Grid {
+        let width = padding.left + self.width() + padding.right;
+        let height = padding.top + self.height() + padding.bottom;
+        let mut cells = vec![vec![fill.clone(); width]; height];
+        for (r, row) in self.cells.iter().enumerate() {
+            cells[padding.top + r][padding.left..padding.left + row.len()].clone_from_slice(row);
+        }
+        Grid { cells }
+    }
 }
 
 fn row_has_visible(row: &[Cell]) -> bool {
@@ -197,3 +386,83 @@ impl From<(usize, usize, usize, usize)> for Padding {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(grid: &Grid) -> Vec<String> {
+        grid.rows()
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.ch).collect())
+            .collect()
+    }
+
+    #[test]
+    fn overlay_clips_to_bounds() {
+        let mut base = Grid::from_char_rows(vec![vec!['.'; 3]; 2]);
+        let badge = Grid::from_char_rows(vec![vec!['#'; 3]; 3]);
+        base.overlay(&badge, 1, 2, Blend::Replace);
+        assert_eq!(chars(&base), ["...", "..#"]);
+
+        base.overlay(&badge, 5, 5, Blend::Replace);
+        assert_eq!(chars(&base), ["...", "..#"]);
+    }
+
+    #[test]
+    fn replace_punches_holes_and_keep_under_respects_visible() {
+        let mut base = Grid::from_char_rows(vec!["ab".chars().collect()]);
+        let hole = Grid::new(1, 1);
+        base.overlay(&hole, 0, 0, Blend::Replace);
+        assert_eq!(base.to_debug_string(), "·b");
+
+        let mut under = Grid::from_char_rows(vec!["xy".chars().collect()]);
+        under.rows_mut()[0][0].fg = Some(Color::Rgb(200, 100, 0));
+        base.overlay(&under, 0, 0, Blend::AlphaDarken(0.5));
+        assert_eq!(chars(&base), ["xb"]);
+        assert_eq!(base.cell(0, 0).unwrap().fg, Some(Color::Rgb(100, 50, 0)));
+    }
+
+    #[test]
+    fn from_cells_round_trips_and_map_cells_visits_all() {
+        let mut grid = Grid::from_char_rows(vec!["ab".chars().collect(), "c".chars().collect()]);
+        grid.map_cells(|r, c, cell| cell.fg = Some(Color::Rgb(r as u8, c as u8, 0)));
+
+        let rebuilt = Grid::from_cells(grid.rows().to_vec());
+        assert_eq!((rebuilt.height(), rebuilt.width()), (2, 2));
+        assert_eq!(rebuilt.to_debug_string(), "ab\nc·");
+        assert_eq!(rebuilt.cell(1, 0).unwrap().fg, Some(Color::Rgb(1, 0, 0)));
+    }
+
+    #[test]
+    fn crop_and_pad_fill_with_blank_cells() {
+        let grid = Grid::from_char_rows(vec!["ab".chars().collect(), "cd".chars().collect()]);
+
+        assert_eq!(chars(&grid.crop(1, 1, 1, 1)), ["d"]);
+        assert_eq!(chars(&grid.crop(1, 1, 2, 3)), ["d  ", "   "]);
+        assert_eq!(grid.crop(5, 5, 1, 2).to_debug_string(), "··");
+        assert_eq!(grid.crop(0, 0, 0, 4).height(), 0);
+        assert_eq!(grid.crop(usize::MAX, 0, 2, 2).to_debug_string(), "··\n··");
+
+        let padded = grid.pad(Padding::from((1, 0, 0, 2)));
+        assert_eq!(padded.to_debug_string(), "····\n··ab\n··cd");
+        assert_eq!(
+            padded.crop(1, 2, 2, 2).to_debug_string(),
+            grid.to_debug_string()
+        );
+    }
+
+    #[test]
+    fn flips_and_rotation_reorder_cells() {
+        let mut grid = Grid::from_char_rows(vec!["ab.".chars().collect(), "c  ".chars().collect()]);
+        grid.rows_mut()[0][0].fg = Some(Color::Rgb(1, 2, 3));
+
+        assert_eq!(chars(&grid.flip_horizontal()), [".ba", "  c"]);
+        assert_eq!(chars(&grid.flip_vertical()), ["c  ", "ab."]);
+        let rotated = grid.rotate_180();
+        assert_eq!(chars(&rotated), ["  c", ".ba"]);
+        assert_eq!(rotated.rows()[1][2].fg, Some(Color::Rgb(1, 2, 3)));
+        assert_eq!(chars(&rotated.rotate_180()), chars(&grid));
+        assert_eq!(Grid::new(0, 0).rotate_180().height(), 0);
+    }
+}
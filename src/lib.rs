@@ -30,13 +30,17 @@
 //! # }
 //! ```
 
+/// Animation descriptions and playback options.
+pub mod animation;
 /// High-level banner builder API.
 pub mod banner;
 /// Color types and palettes.
 pub mod color;
-/// Visual effects (dither, outline, shadow).
+/// Plain-data banner configuration.
+pub mod config;
+/// Visual effects (contrast, dither, outline, shadow).
 pub mod effects;
-/// ANSI output emitter.
+/// ANSI, HTML, SVG and raster output emitters.
 pub mod emit;
 /// Fill and dither configuration.
 pub mod fill;
@@ -48,18 +52,33 @@ pub mod frame;
 pub mod gradient;
 /// Grid and layout types.
 pub mod grid;
+mod png;
 /// Named banner styles.
 pub mod style;
 /// Terminal capability detection.
 pub mod terminal;
+#[cfg(feature = "ratatui")]
+mod widget;
 
-pub use banner::{Banner, BannerError};
-pub use color::{Color, ColorMode, Palette, Preset};
+pub use animation::{
+    Animation, AnimationFrames, AnimationOptions, FadeDirection, LoopMode, MATRIX_CHARSET, Reveal,
+    ScrollDirection,
+};
+pub use banner::{Banner, BannerError, RenderedBanner};
+pub use color::{
+    Color, ColorMode, ColorSpace, Palette, PaletteError, Preset, RegisterPaletteError,
+    register_palette,
+};
+pub use config::BannerConfig;
+pub use effects::glitch::GlitchConfig;
 pub use effects::light_sweep::{LightSweep, SweepDirection};
 pub use effects::outline::EdgeShade;
-pub use fill::{Dither, DitherMode, Fill};
-pub use font::{Font, figlet::FigletError};
-pub use frame::{Frame, FrameChars, FramePaint, FrameStyle};
-pub use gradient::{Gradient, GradientDirection};
-pub use grid::{Align, Padding};
+pub use fill::{CanvasFill, Dither, DitherMode, Fill};
+pub use font::{Font, Glyph, Orientation, TextDirection, figlet::FigletError};
+pub use frame::{Frame, FrameChars, FrameJunctions, FramePaint, FrameStyle};
+pub use gradient::{Easing, Gradient, GradientDirection, GradientMask, GradientSpread};
+pub use grid::{Align, Blend, Padding};
 pub use style::Style;
+pub use terminal::Background;
+#[cfg(feature = "ratatui")]
+pub use widget::BannerWidget;
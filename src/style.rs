@@ -14,6 +14,8 @@ use crate::color::Preset;
 
 /// Named banner styles.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub enum Style {
     /// Neon Cyber (cyan -> purple -> pink).
     NeonCyber,
@@ -46,6 +48,37 @@ pub enum Style {
 }
 
 impl Style {
+    /// Every style, in declaration order.
+    pub const ALL: &'static [Style] = &[
+        Style::NeonCyber,
+        Style::ArcticTech,
+        Style::SunsetNeon,
+        Style::ForestSky,
+        Style::Chrome,
+        Style::CrtAmber,
+        Style::OceanFlow,
+        Style::DeepSpace,
+        Style::FireWarning,
+        Style::WarmLuxury,
+        Style::EarthTone,
+        Style::RoyalPurple,
+        Style::Matrix,
+        Style::AuroraFlux,
+    ];
+
+    /// Kebab-case name, e.g. `"neon-cyber"` (the same as its preset's name).
+    pub fn name(self) -> &'static str {
+        self.preset().name()
+    }
+
+    /// Look up a style by its kebab-case [`name`](Style::name).
+    pub fn from_name(name: &str) -> Option<Style> {
+        Style::ALL
+            .iter()
+            .copied()
+            .find(|style| style.name() == name)
+    }
+
     pub(crate) fn preset(self) -> Preset {
         match self {
             Style::NeonCyber => Preset::NeonCyber,
@@ -65,3 +98,35 @@ impl Style {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Palette;
+
+    #[test]
+    fn every_style_maps_to_a_gradient_preset() {
+        assert_eq!(Style::ALL.len(), Preset::ALL.len());
+        for style in Style::ALL {
+            let preset = style.preset();
+            assert!(Preset::ALL.contains(&preset), "{style:?}");
+            assert!(Palette::preset(preset).colors().len() >= 2, "{style:?}");
+        }
+        for preset in Preset::ALL {
+            assert_eq!(
+                Palette::preset(*preset).colors().len(),
+                preset.hexes().len(),
+                "{preset:?} has an invalid hex"
+            );
+        }
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for style in Style::ALL {
+            assert_eq!(Style::from_name(style.name()), Some(*style));
+        }
+        assert_eq!(Style::from_name("aurora-flux"), Some(Style::AuroraFlux));
+        assert_eq!(Style::from_name("nope"), None);
+    }
+}
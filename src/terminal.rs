@@ -11,24 +11,586 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 
 use std::env;
+use std::io::{self, IsTerminal, Write};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crate::color::ColorMode;
 
+/// Terminal background brightness.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Background {
+    /// Light background (dark text reads best).
+    Light,
+    /// Dark background (light text reads best).
+    Dark,
+    /// Background could not be determined.
+    Unknown,
+}
+
 /// Detect terminal color capability.
+///
+/// Follows the usual conventions, in order of precedence:
+/// - `NO_COLOR` (any value) disables color.
+/// - `FORCE_COLOR` forces color: `0`/`false` disables, `1`/`2`/`3` pick 16/256/true
+///   color, anything else uses the detected capability (true color if unknown).
+/// - `CLICOLOR_FORCE` (non-empty, not `0`) forces the detected capability.
+/// - Otherwise `CLICOLOR=0` or a non-terminal stdout (piped or redirected) disables color.
+/// - `COLORTERM` and `TERM` then decide between true color, 256 and 16 colors.
 pub fn detect_color_mode() -> ColorMode {
-    if env::var("NO_COLOR").is_ok() {
+    color_mode_from(io::stdout().is_terminal(), |name| env::var(name).ok())
+}
+
+fn color_mode_from(is_tty: bool, var: impl Fn(&str) -> Option<String>) -> ColorMode {
+    if var("NO_COLOR").is_some() {
         return ColorMode::NoColor;
     }
 
-    let colorterm = env::var("COLORTERM").unwrap_or_default().to_lowercase();
+    let capability = capability_from(&var);
+    if let Some(level) = var("FORCE_COLOR") {
+        return match level.trim().to_lowercase().as_str() {
+            "0" | "false" => ColorMode::NoColor,
+            "1" => ColorMode::Ansi16,
+            "2" => ColorMode::Ansi256,
+            "3" => ColorMode::TrueColor,
+            _ => capability.unwrap_or(ColorMode::TrueColor),
+        };
+    }
+    if var("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0") {
+        return capability.unwrap_or(ColorMode::TrueColor);
+    }
+
+    if !is_tty || var("CLICOLOR").as_deref() == Some("0") {
+        return ColorMode::NoColor;
+    }
+    capability.unwrap_or(ColorMode::NoColor)
+}
+
+/// Color support advertised by `COLORTERM` / `TERM`, if any.
+fn capability_from(var: &impl Fn(&str) -> Option<String>) -> Option<ColorMode> {
+    let colorterm = var("COLORTERM").unwrap_or_default().to_lowercase();
     if colorterm.contains("truecolor") || colorterm.contains("24bit") {
-        return ColorMode::TrueColor;
+        return Some(ColorMode::TrueColor);
     }
 
-    let term = env::var("TERM").unwrap_or_default().to_lowercase();
+    let term = var("TERM").unwrap_or_default().to_lowercase();
     if term.contains("256color") {
-        return ColorMode::Ansi256;
+        return Some(ColorMode::Ansi256);
+    }
+    if !term.is_empty() && term != "dumb" {
+        return Some(ColorMode::Ansi16);
+    }
+    None
+}
+
+/// Width of the terminal in columns, if it can be determined.
+///
+/// Asks the terminal attached to stdout (or stderr), then falls back to the `COLUMNS`
+/// environment variable. On Unix, asking the terminal needs the `terminal` feature.
+pub fn terminal_width() -> Option<usize> {
+    sys::columns().or_else(|| columns_from_env(&env::var("COLUMNS").ok()?))
+}
+
+fn columns_from_env(value: &str) -> Option<usize> {
+    value.trim().parse().ok().filter(|&columns| columns > 0)
+}
+
+#[cfg(all(unix, feature = "terminal"))]
+mod sys {
+    use std::sync::{Mutex, PoisonError};
+
+    /// Installed hooks, and the Ctrl-C disposition to put back once the last one is dropped.
+    static HOOKS: Mutex<(usize, Option<libc::sigaction>)> = Mutex::new((0, None));
+
+    extern "C" fn on_sigint(_: libc::c_int) {
+        super::PRESSES.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Catch SIGINT unless it is ignored; `false` leaves the disposition untouched.
+    pub(super) fn hook_ctrl_c() -> bool {
+        let mut hooks = HOOKS.lock().unwrap_or_else(PoisonError::into_inner);
+        if hooks.0 == 0 {
+            // SAFETY: `sigaction` only reads `action` and writes `previous`; the handler just
+            // bumps an atomic, which is async-signal-safe.
+            unsafe {
+                let mut previous: libc::sigaction = std::mem::zeroed();
+                if libc::sigaction(libc::SIGINT, std::ptr::null(), &mut previous) != 0
+                    || previous.sa_sigaction == libc::SIG_IGN
+                {
+                    return false;
+                }
+                let mut action: libc::sigaction = std::mem::zeroed();
+                action.sa_sigaction = on_sigint as extern "C" fn(libc::c_int) as libc::sighandler_t;
+                action.sa_flags = libc::SA_RESTART;
+                libc::sigemptyset(&mut action.sa_mask);
+                if libc::sigaction(libc::SIGINT, &action, std::ptr::null_mut()) != 0 {
+                    return false;
+                }
+                hooks.1 = Some(previous);
+            }
+        }
+        hooks.0 += 1;
+        true
+    }
+
+    pub(super) fn unhook_ctrl_c() {
+        let mut hooks = HOOKS.lock().unwrap_or_else(PoisonError::into_inner);
+        hooks.0 -= 1;
+        if hooks.0 > 0 {
+            return;
+        }
+        if let Some(previous) = hooks.1.take() {
+            // SAFETY: restores the disposition saved by `hook_ctrl_c`.
+            unsafe { libc::sigaction(libc::SIGINT, &previous, std::ptr::null_mut()) };
+        }
+    }
+
+    pub(super) fn columns() -> Option<usize> {
+        [libc::STDOUT_FILENO, libc::STDERR_FILENO]
+            .into_iter()
+            .find_map(|fd| {
+                let mut size = libc::winsize {
+                    ws_row: 0,
+                    ws_col: 0,
+                    ws_xpixel: 0,
+                    ws_ypixel: 0,
+                };
+                // SAFETY: TIOCGWINSZ only writes a `winsize` struct through the pointer.
+                let result = unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut size) };
+                (result == 0 && size.ws_col > 0).then_some(size.ws_col as usize)
+            })
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::ffi::c_void;
+    use std::io;
+
+    const STD_OUTPUT_HANDLE: u32 = -11i32 as u32;
+    const STD_ERROR_HANDLE: u32 = -12i32 as u32;
+    const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct Coord {
+        x: i16,
+        y: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct SmallRect {
+        left: i16,
+        top: i16,
+        right: i16,
+        bottom: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct ConsoleScreenBufferInfo {
+        size: Coord,
+        cursor_position: Coord,
+        attributes: u16,
+        window: SmallRect,
+        maximum_window_size: Coord,
+    }
+
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GetStdHandle(std_handle: u32) -> *mut c_void;
+        fn GetConsoleScreenBufferInfo(
+            console: *mut c_void,
+            info: *mut ConsoleScreenBufferInfo,
+        ) -> i32;
+        fn GetConsoleMode(console: *mut c_void, mode: *mut u32) -> i32;
+        fn SetConsoleMode(console: *mut c_void, mode: u32) -> i32;
+        #[cfg(feature = "terminal")]
+        fn SetConsoleCtrlHandler(
+            handler: Option<unsafe extern "system" fn(u32) -> i32>,
+            add: i32,
+        ) -> i32;
+    }
+
+    /// Installed hooks; the console handler is removed again with the last one.
+    #[cfg(feature = "terminal")]
+    static HOOKS: std::sync::Mutex<usize> = std::sync::Mutex::new(0);
+
+    #[cfg(feature = "terminal")]
+    unsafe extern "system" fn on_ctrl(event: u32) -> i32 {
+        const CTRL_C_EVENT: u32 = 0;
+        if event != CTRL_C_EVENT {
+            return 0;
+        }
+        super::PRESSES.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        1
+    }
+
+    /// Handle Ctrl-C in the console; `false` leaves the default handling in place.
+    #[cfg(feature = "terminal")]
+    pub(super) fn hook_ctrl_c() -> bool {
+        let mut hooks = HOOKS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        // SAFETY: registers a handler that only bumps an atomic.
+        if *hooks == 0 && unsafe { SetConsoleCtrlHandler(Some(on_ctrl), 1) } == 0 {
+            return false;
+        }
+        *hooks += 1;
+        true
+    }
+
+    #[cfg(feature = "terminal")]
+    pub(super) fn unhook_ctrl_c() {
+        let mut hooks = HOOKS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        *hooks -= 1;
+        if *hooks == 0 {
+            // SAFETY: removes the handler added by `hook_ctrl_c`.
+            unsafe { SetConsoleCtrlHandler(Some(on_ctrl), 0) };
+        }
+    }
+
+    pub(super) fn columns() -> Option<usize> {
+        [STD_OUTPUT_HANDLE, STD_ERROR_HANDLE]
+            .into_iter()
+            .find_map(|std_handle| {
+                let mut info = ConsoleScreenBufferInfo::default();
+                // SAFETY: both calls only read the handle and write `info`.
+                let ok =
+                    unsafe { GetConsoleScreenBufferInfo(GetStdHandle(std_handle), &mut info) != 0 };
+                let width = info.window.right - info.window.left + 1;
+                (ok && width > 0).then_some(width as usize)
+            })
+    }
+
+    pub(super) fn enable_virtual_terminal() -> io::Result<()> {
+        // SAFETY: the calls only read the handle and read/write the local `mode`.
+        unsafe {
+            let console = GetStdHandle(STD_OUTPUT_HANDLE);
+            let mut mode = 0;
+            if GetConsoleMode(console, &mut mode) == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING == 0
+                && SetConsoleMode(console, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) == 0
+            {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(any(all(unix, feature = "terminal"), windows)))]
+mod sys {
+    pub(super) fn columns() -> Option<usize> {
+        None
+    }
+}
+
+/// Let the console interpret ANSI escape codes.
+///
+/// Older Windows consoles print escapes literally unless virtual terminal processing is
+/// switched on; this enables it for stdout. Animations call it automatically; call it
+/// once yourself before printing [`Banner::render`](crate::Banner::render) output.
+/// Does nothing on other platforms.
+pub fn enable_ansi_support() -> io::Result<()> {
+    #[cfg(windows)]
+    {
+        sys::enable_virtual_terminal()
+    }
+    #[cfg(not(windows))]
+    {
+        Ok(())
+    }
+}
+
+/// Detect whether the terminal background is light or dark.
+///
+/// Uses the `COLORFGBG` convention (`fg;bg`, set by rxvt, Konsole, iTerm2 and others).
+pub fn detect_background() -> Background {
+    env::var("COLORFGBG")
+        .map(|value| background_from_colorfgbg(&value))
+        .unwrap_or(Background::Unknown)
+}
+
+fn background_from_colorfgbg(value: &str) -> Background {
+    let Some(bg) = value.rsplit(';').next() else {
+        return Background::Unknown;
+    };
+    match bg.trim().parse::<u8>() {
+        Ok(7 | 15) => Background::Light,
+        Ok(0..=6 | 8..=14) => Background::Dark,
+        _ => Background::Unknown,
     }
+}
+
+/// Hides the cursor and restores it (plus colors and a final newline) when dropped.
+///
+/// Dropping also runs while unwinding from a panic, so animations never leave the
+/// terminal with a hidden cursor.
+pub(crate) struct CursorGuard<W: Write> {
+    out: W,
+    alternate: bool,
+}
+
+impl<W: Write> CursorGuard<W> {
+    /// Hide the cursor, switching to the alternate screen first when `alternate` is set.
+    ///
+    /// The guard exists before anything is written, so a failed write still restores.
+    pub(crate) fn enter(out: W, alternate: bool) -> io::Result<Self> {
+        let mut guard = Self { out, alternate };
+        if alternate {
+            guard.out.write_all(b"\x1b[?1049h")?;
+        }
+        guard.out.write_all(b"\x1b[?25l")?;
+        guard.out.flush()?;
+        Ok(guard)
+    }
+}
+
+impl<W: Write> Write for CursorGuard<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.out.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
 
-    ColorMode::NoColor
+impl<W: Write> Drop for CursorGuard<W> {
+    fn drop(&mut self) {
+        let restore: &[u8] = if self.alternate {
+            b"\x1b[?25h\x1b[0m\x1b[?1049l"
+        } else {
+            b"\x1b[?25h\x1b[0m\n"
+        };
+        let _ = self.out.write_all(restore);
+        let _ = self.out.flush();
+    }
+}
+
+/// Ctrl-C presses seen while at least one [`InterruptHook`] was installed.
+#[cfg(all(any(unix, windows), feature = "terminal"))]
+static PRESSES: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+/// Catches Ctrl-C while installed so animations can stop and restore the terminal.
+///
+/// The process's previous Ctrl-C disposition is put back once the last hook is dropped,
+/// and an ignored Ctrl-C (e.g. under `nohup`) stays ignored. Needs the `terminal` feature.
+pub(crate) struct InterruptHook {
+    /// Press count when the hook was installed (`None` if it is not installed).
+    #[cfg_attr(not(all(any(unix, windows), feature = "terminal")), allow(dead_code))]
+    seen: Option<usize>,
+}
+
+impl InterruptHook {
+    /// Install the Ctrl-C hook until dropped.
+    ///
+    /// If the handler cannot be registered, Ctrl-C keeps its current behavior.
+    pub(crate) fn install() -> Self {
+        #[cfg(all(any(unix, windows), feature = "terminal"))]
+        {
+            let seen = PRESSES.load(std::sync::atomic::Ordering::SeqCst);
+            Self {
+                seen: sys::hook_ctrl_c().then_some(seen),
+            }
+        }
+        #[cfg(not(all(any(unix, windows), feature = "terminal")))]
+        {
+            Self::none()
+        }
+    }
+
+    /// A hook that never fires, for output that is not the terminal.
+    pub(crate) fn none() -> Self {
+        Self { seen: None }
+    }
+
+    /// Whether Ctrl-C was pressed since the hook was installed.
+    pub(crate) fn interrupted(&self) -> bool {
+        #[cfg(all(any(unix, windows), feature = "terminal"))]
+        {
+            self.seen
+                .is_some_and(|seen| PRESSES.load(std::sync::atomic::Ordering::SeqCst) != seen)
+        }
+        #[cfg(not(all(any(unix, windows), feature = "terminal")))]
+        {
+            false
+        }
+    }
+
+    /// `Err(Interrupted)` if Ctrl-C was pressed, so callers can exit with the interrupt status.
+    pub(crate) fn check(&self) -> io::Result<()> {
+        if self.interrupted() {
+            Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "interrupted by Ctrl-C",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Drop for InterruptHook {
+    fn drop(&mut self) {
+        #[cfg(all(any(unix, windows), feature = "terminal"))]
+        if self.seen.take().is_some() {
+            sys::unhook_ctrl_c();
+        }
+    }
+}
+
+/// Sleep for `duration`, waking early once `stopped` returns true.
+pub(crate) fn sleep_unless(duration: Duration, stopped: impl Fn() -> bool) {
+    let deadline = Instant::now() + duration;
+    while !stopped() {
+        let now = Instant::now();
+        if now >= deadline {
+            break;
+        }
+        thread::sleep((deadline - now).min(Duration::from_millis(20)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn columns_env_fallback_ignores_junk() {
+        assert_eq!(columns_from_env("120"), Some(120));
+        assert_eq!(columns_from_env(" 80\n"), Some(80));
+        assert_eq!(columns_from_env("0"), None);
+        assert_eq!(columns_from_env("wide"), None);
+    }
+
+    #[test]
+    fn color_mode_requires_a_terminal() {
+        let vars = [("COLORTERM", "truecolor"), ("TERM", "xterm-256color")];
+        assert_eq!(color_mode_from(true, env(&vars)), ColorMode::TrueColor);
+        assert_eq!(color_mode_from(false, env(&vars)), ColorMode::NoColor);
+        assert_eq!(
+            color_mode_from(true, env(&[("TERM", "xterm-256color")])),
+            ColorMode::Ansi256
+        );
+        assert_eq!(
+            color_mode_from(true, env(&[("NO_COLOR", ""), ("COLORTERM", "24bit")])),
+            ColorMode::NoColor
+        );
+        assert_eq!(
+            color_mode_from(true, env(&[("TERM", "dumb")])),
+            ColorMode::NoColor
+        );
+    }
+
+    #[test]
+    fn unknown_terms_detect_16_colors() {
+        let detect = |vars: &[(&str, &str)]| color_mode_from(true, env(vars));
+        assert_eq!(detect(&[("TERM", "xterm")]), ColorMode::Ansi16);
+        assert_eq!(detect(&[("TERM", "vt100")]), ColorMode::Ansi16);
+        assert_eq!(detect(&[("TERM", "Linux")]), ColorMode::Ansi16);
+        assert_eq!(detect(&[("TERM", "screen-256color")]), ColorMode::Ansi256);
+        assert_eq!(
+            detect(&[("TERM", "xterm"), ("COLORTERM", "truecolor")]),
+            ColorMode::TrueColor
+        );
+        assert_eq!(detect(&[("TERM", "")]), ColorMode::NoColor);
+        assert_eq!(detect(&[("TERM", "dumb")]), ColorMode::NoColor);
+        assert_eq!(detect(&[]), ColorMode::NoColor);
+    }
+
+    #[test]
+    fn force_color_overrides_missing_terminal() {
+        let term = ("TERM", "xterm-256color");
+        let forced = |vars: &[(&str, &str)]| color_mode_from(false, env(vars));
+        assert_eq!(forced(&[("FORCE_COLOR", "1"), term]), ColorMode::Ansi16);
+        assert_eq!(forced(&[("FORCE_COLOR", "3"), term]), ColorMode::TrueColor);
+        assert_eq!(forced(&[("FORCE_COLOR", ""), term]), ColorMode::Ansi256);
+        assert_eq!(forced(&[("FORCE_COLOR", "true")]), ColorMode::TrueColor);
+        assert_eq!(forced(&[("FORCE_COLOR", "0"), term]), ColorMode::NoColor);
+        assert_eq!(forced(&[("CLICOLOR_FORCE", "1"), term]), ColorMode::Ansi256);
+        assert_eq!(forced(&[("CLICOLOR_FORCE", "1")]), ColorMode::TrueColor);
+        assert_eq!(forced(&[("CLICOLOR_FORCE", "0"), term]), ColorMode::NoColor);
+        assert_eq!(
+            forced(&[("CLICOLOR_FORCE", "1"), ("NO_COLOR", "1")]),
+            ColorMode::NoColor
+        );
+    }
+
+    #[test]
+    fn clicolor_zero_disables_color_on_terminals() {
+        let vars = [("CLICOLOR", "0"), ("COLORTERM", "truecolor")];
+        assert_eq!(color_mode_from(true, env(&vars)), ColorMode::NoColor);
+        let vars = [("CLICOLOR", "1"), ("COLORTERM", "truecolor")];
+        assert_eq!(color_mode_from(true, env(&vars)), ColorMode::TrueColor);
+        let vars = [
+            ("CLICOLOR", "0"),
+            ("CLICOLOR_FORCE", "1"),
+            ("TERM", "xterm"),
+        ];
+        assert_eq!(color_mode_from(true, env(&vars)), ColorMode::Ansi16);
+    }
+
+    #[test]
+    fn parses_colorfgbg() {
+        assert_eq!(background_from_colorfgbg("0;15"), Background::Light);
+        assert_eq!(background_from_colorfgbg("15;default;0"), Background::Dark);
+        assert_eq!(background_from_colorfgbg("default"), Background::Unknown);
+    }
+
+    #[cfg(all(unix, feature = "terminal"))]
+    #[test]
+    fn interrupt_hook_restores_the_previous_disposition() {
+        use std::sync::atomic::Ordering;
+
+        let disposition = || {
+            // SAFETY: a null new action only reads the current one into `current`.
+            unsafe {
+                let mut current: libc::sigaction = std::mem::zeroed();
+                libc::sigaction(libc::SIGINT, std::ptr::null(), &mut current);
+                current.sa_sigaction
+            }
+        };
+        let before = disposition();
+        let hook = InterruptHook::install();
+        assert!(hook.check().is_ok());
+        assert_ne!(disposition(), before);
+
+        // Simulate a press rather than raising SIGINT inside the shared test process.
+        PRESSES.fetch_add(1, Ordering::SeqCst);
+        assert!(hook.interrupted());
+        assert_eq!(hook.check().unwrap_err().kind(), io::ErrorKind::Interrupted);
+        assert!(!InterruptHook::install().interrupted());
+        drop(hook);
+        assert_eq!(disposition(), before);
+        assert!(!InterruptHook::none().interrupted());
+    }
+
+    #[test]
+    fn cursor_guard_restores_on_drop() {
+        let mut out = Vec::new();
+        {
+            let mut guard = CursorGuard::enter(&mut out, false).unwrap();
+            guard.write_all(b"frame").unwrap();
+        }
+        assert_eq!(out, b"\x1b[?25lframe\x1b[?25h\x1b[0m\n");
+
+        let mut alt = Vec::new();
+        drop(CursorGuard::enter(&mut alt, true).unwrap());
+        assert_eq!(alt, b"\x1b[?1049h\x1b[?25l\x1b[?25h\x1b[0m\x1b[?1049l");
+    }
 }
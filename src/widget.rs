@@ -0,0 +1,207 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color as TuiColor, Style as TuiStyle};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::Widget;
+
+use crate::color::{Color, ColorMode};
+use crate::grid::{Align, Grid};
+
+/// A ratatui widget that draws a rendered banner cell by cell, without ANSI.
+///
+/// Build one with [`Banner::widget`](crate::Banner::widget). The banner is rendered once;
+/// drawing only copies cells. Blank cells without a background leave the buffer untouched,
+/// and a banner larger than the area is clipped (keeping the side picked by the alignment).
+#[derive(Clone, Debug)]
+pub struct BannerWidget {
+    grid: Grid,
+    mode: ColorMode,
+    alignment: Alignment,
+}
+
+impl BannerWidget {
+    pub(crate) fn new(grid: Grid, mode: ColorMode, align: Align) -> Self {
+        Self {
+            grid,
+            mode,
+            alignment: alignment(align),
+        }
+    }
+
+    /// Set the horizontal alignment inside the render area (default: the banner's own).
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Rendered `(width, height)` in cells.
+    pub fn size(&self) -> (usize, usize) {
+        (self.grid.width(), self.grid.height())
+    }
+}
+
+impl Widget for BannerWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &BannerWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let width = self.grid.width();
+        let visible = width.min(area.width as usize);
+        let extra = width.abs_diff(area.width as usize);
+        let offset = match self.alignment {
+            Alignment::Left => 0,
+            Alignment::Center => extra / 2,
+            Alignment::Right => extra,
+        };
+        // Wider banners are cropped from the aligned side; narrower ones are shifted.
+        let (skip, left) = if width > area.width as usize {
+            (offset, 0)
+        } else {
+            (0, offset)
+        };
+
+        for (y, row) in self
+            .grid
+            .rows()
+            .iter()
+            .take(area.height as usize)
+            .enumerate()
+        {
+            for (x, cell) in row.iter().skip(skip).take(visible).enumerate() {
+                if !cell.visible && cell.bg.is_none() {
+                    continue;
+                }
+                let position = (area.x + (left + x) as u16, area.y + y as u16);
+                let Some(target) = buf.cell_mut(position) else {
+                    continue;
+                };
+                target.set_char(cell.ch);
+                if self.mode != ColorMode::NoColor {
+                    if let Some(fg) = cell.fg {
+                        target.set_fg(tui_color(fg, self.mode));
+                    }
+                    if let Some(bg) = cell.bg {
+                        target.set_bg(tui_color(bg, self.mode));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Map banner alignment onto ratatui alignment.
+pub(crate) fn alignment(align: Align) -> Alignment {
+    match align {
+        Align::Left => Alignment::Left,
+        Align::Center => Alignment::Center,
+        Align::Right => Alignment::Right,
+    }
+}
+
+/// Convert a grid into styled ratatui text, one line per row.
+///
+/// Adjacent cells with the same color are merged into a single span.
+pub(crate) fn grid_to_text(grid: &Grid, mode: ColorMode) -> Text<'static> {
+    let mut lines = Vec::with_capacity(grid.height());
+    for row in grid.rows() {
+        let mut spans = Vec::new();
+        let mut run = String::new();
+        let mut run_colors: (Option<Color>, Option<Color>) = (None, None);
+        for cell in row {
+            let colors = if mode == ColorMode::NoColor {
+                (None, None)
+            } else {
+                (cell.fg, cell.bg)
+            };
+            if colors != run_colors && !run.is_empty() {
+                spans.push(span(std::mem::take(&mut run), run_colors, mode));
+            }
+            run_colors = colors;
+            run.push(cell.ch);
+        }
+        if !run.is_empty() {
+            spans.push(span(run, run_colors, mode));
+        }
+        lines.push(Line::from(spans));
+    }
+    Text::from(lines)
+}
+
+fn span(
+    content: String,
+    (fg, bg): (Option<Color>, Option<Color>),
+    mode: ColorMode,
+) -> Span<'static> {
+    if fg.is_none() && bg.is_none() {
+        return Span::raw(content);
+    }
+    let mut style = TuiStyle::new();
+    if let Some(color) = fg {
+        style = style.fg(tui_color(color, mode));
+    }
+    if let Some(color) = bg {
+        style = style.bg(tui_color(color, mode));
+    }
+    Span::styled(content, style)
+}
+
+fn tui_color(color: Color, mode: ColorMode) -> TuiColor {
+    match (color, mode) {
+        (color, ColorMode::Ansi256) => TuiColor::Indexed(color.to_ansi256()),
+        (color, ColorMode::Ansi16) => TuiColor::Indexed(color.ansi16_index()),
+        (Color::Rgb(r, g, b), _) => TuiColor::Rgb(r, g, b),
+        (Color::Ansi256(code), _) => TuiColor::Indexed(code),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn widget(align: Align) -> BannerWidget {
+        let mut grid = Grid::from_char_rows(vec!["ab c".chars().collect(); 2]);
+        grid.rows_mut()[0][0].fg = Some(Color::Rgb(255, 0, 0));
+        BannerWidget::new(grid, ColorMode::TrueColor, align)
+    }
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect()
+    }
+
+    #[test]
+    fn aligns_and_clips_to_the_area() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        widget(Align::Center).render(buf.area, &mut buf);
+        assert_eq!(row(&buf, 0), "  ab c  ");
+        assert_eq!(buf[(2, 0)].fg, TuiColor::Rgb(255, 0, 0));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        widget(Align::Center)
+            .alignment(Alignment::Right)
+            .render(buf.area, &mut buf);
+        assert_eq!(row(&buf, 0), "    ab c");
+
+        let mut narrow = Buffer::empty(Rect::new(0, 0, 2, 1));
+        widget(Align::Left).render(narrow.area, &mut narrow);
+        assert_eq!(row(&narrow, 0), "ab");
+        let mut narrow = Buffer::empty(Rect::new(0, 0, 2, 1));
+        widget(Align::Right).render(narrow.area, &mut narrow);
+        assert_eq!(row(&narrow, 0), " c");
+    }
+}
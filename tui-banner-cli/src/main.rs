@@ -12,11 +12,15 @@
 
 use std::env;
 use std::fs;
+use std::io::{self, IsTerminal, Read};
 use std::path::PathBuf;
+use std::time::Duration;
 
 use tui_banner::{
-    Align, Banner, Color, ColorMode, Dither, Fill, Font, Frame, FrameChars, FrameStyle, Gradient,
-    GradientDirection, LightSweep, Palette, Preset, Style, SweepDirection,
+    Align, Animation, AnimationOptions, Banner, Color, ColorMode, ColorSpace, Dither, Easing,
+    FadeDirection, Fill, Font, Frame, FrameChars, FrameStyle, GlitchConfig, Gradient,
+    GradientDirection, GradientSpread, LightSweep, LoopMode, Palette, Preset, Style,
+    SweepDirection, emit::RasterOptions,
 };
 
 const DEFAULT_PALETTE: [&str; 3] = ["#00E5FF", "#3A7BFF", "#E6F6FF"];
@@ -24,17 +28,39 @@ const DEFAULT_PALETTE: [&str; 3] = ["#00E5FF", "#3A7BFF", "#E6F6FF"];
 #[derive(Default)]
 struct CliOptions {
     text_flag: Option<String>,
+    text_file: Option<PathBuf>,
+    text_file_strip_comments: bool,
+    caption: Option<String>,
+    segments: Vec<(String, Color)>,
     font: Option<PathBuf>,
     style: Option<Style>,
-    preset: Option<Preset>,
+    preset: Option<Palette>,
     gradient: Option<GradientDirection>,
+    color_space: Option<ColorSpace>,
+    gradient_repeat: Option<f32>,
+    gradient_mirror: bool,
+    gradient_reverse: bool,
+    gradient_easing: Option<Easing>,
+    plasma: Option<(f32, u32)>,
     palette: Option<Vec<String>>,
+    palette_ramp: Option<(Vec<Color>, usize)>,
+    bg_palette: Option<Vec<String>>,
+    bg_color: Option<Color>,
+    canvas_color: Option<Color>,
+    grayscale: bool,
+    tint: Option<Color>,
     frame_style: Option<FrameStyle>,
     frame_chars: Option<String>,
     frame_color: Option<Color>,
     frame_gradient: Option<GradientDirection>,
     frame_palette: Option<Vec<String>>,
-    frame_preset: Option<Preset>,
+    frame_preset: Option<Palette>,
+    frame_title: Option<String>,
+    frame_padding: Option<tui_banner::Padding>,
+    frame_sides: Option<[bool; 4]>,
+    frame_dividers: Vec<usize>,
+    frame_thickness: Option<usize>,
+    frame_fill: Option<Color>,
     fill: Option<FillKind>,
     fill_char: Option<char>,
     pixel_dither: Option<DitherSpec>,
@@ -48,8 +74,10 @@ struct CliOptions {
     padding: Option<tui_banner::Padding>,
     width: Option<usize>,
     max_width: Option<usize>,
-    kerning: Option<usize>,
+    fit: bool,
+    kerning: Option<i32>,
     line_gap: Option<usize>,
+    wrap: bool,
     trim_vertical: Option<bool>,
     color_mode: Option<ColorMode>,
     light_sweep: bool,
@@ -61,9 +89,41 @@ struct CliOptions {
     animate_sweep: Option<u64>,
     animate_wave: Option<u64>,
     animate_roll: Option<u64>,
+    animate_gradient: Option<u64>,
+    animate_hue: Option<u64>,
+    animate_fade: Option<u64>,
+    animate_glitch: Option<u64>,
+    animate_matrix: Option<u64>,
+    glitch: Option<f32>,
+    fade_direction: Option<FadeDirection>,
+    loop_forever: bool,
+    frames: Option<usize>,
+    duration: Option<f32>,
+    alt_screen: bool,
+    in_place: bool,
     wave_dim: Option<f32>,
     wave_bright: Option<f32>,
     sweep_highlight: Option<Color>,
+    output: Option<PathBuf>,
+    format: Option<OutputFormat>,
+    list: Option<ListKind>,
+    json: bool,
+}
+
+#[derive(Clone, Copy)]
+enum ListKind {
+    Styles,
+    Presets,
+}
+
+#[derive(Clone, Copy)]
+enum OutputFormat {
+    Ansi,
+    Plain,
+    Html,
+    Svg,
+    Png,
+    Kitty,
 }
 
 #[derive(Clone, Copy)]
@@ -78,12 +138,14 @@ enum FillKind {
 enum DitherSpec {
     Checker { period: u8 },
     Noise { seed: u32, threshold: u8 },
+    Bayer { levels: u8 },
 }
 
 #[derive(Clone, Copy)]
 struct ShadowSpec {
     offset: (i32, i32),
     alpha: f32,
+    color: Option<Color>,
 }
 
 #[derive(Clone, Copy)]
@@ -99,15 +161,36 @@ fn main() {
     }
 }
 
+/// Exit like the shell does on Ctrl-C (128 + SIGINT) once the terminal is restored.
+fn playback_error(err: io::Error) -> String {
+    if err.kind() == io::ErrorKind::Interrupted {
+        std::process::exit(130);
+    }
+    err.to_string()
+}
+
 fn run() -> Result<(), String> {
     let opts = parse_args()?;
-    let text = resolve_text(&opts)?;
+    if let Some(kind) = opts.list {
+        print_list(kind, opts.json);
+        return Ok(());
+    }
+    let _ = tui_banner::terminal::enable_ansi_support();
+    let text = if opts.segments.is_empty() {
+        resolve_text(&opts)?
+    } else {
+        String::new()
+    };
     let mut banner = Banner::new(text).map_err(|err| err.to_string())?;
+    for (text, color) in &opts.segments {
+        banner = banner.segment(text.clone(), *color);
+    }
 
     if let Some(font_path) = opts.font.as_ref() {
-        let data = fs::read_to_string(font_path)
+        let data = fs::read(font_path)
             .map_err(|err| format!("failed to read font {:?}: {err}", font_path))?;
-        let font = Font::from_figlet_str(&data).map_err(|err| format!("{err:?}"))?;
+        let font = Font::from_bytes(&data)
+            .map_err(|err| format!("invalid font {:?}: {err}", font_path))?;
         banner = banner.font(font);
     }
 
@@ -115,7 +198,8 @@ fn run() -> Result<(), String> {
         banner = banner.style(style);
     }
 
-    let color_mode = opts.color_mode.unwrap_or(ColorMode::TrueColor);
+    // `Auto` honors NO_COLOR / CLICOLOR / FORCE_COLOR and drops color when piped.
+    let color_mode = opts.color_mode.unwrap_or(ColorMode::Auto);
     banner = banner.color_mode(color_mode);
 
     let fill = build_fill(
@@ -130,6 +214,9 @@ fn run() -> Result<(), String> {
 
     if let Some(shadow) = opts.shadow {
         banner = banner.shadow(shadow.offset, shadow.alpha);
+        if let Some(color) = shadow.color {
+            banner = banner.shadow_color(color);
+        }
     }
 
     if let Some(edge_shade) = opts.edge_shade {
@@ -139,12 +226,16 @@ fn run() -> Result<(), String> {
     let align = opts.align.unwrap_or(Align::Center);
     banner = banner.align(align);
 
+    if let Some(caption) = &opts.caption {
+        banner = banner.caption(caption.clone()).caption_align(align);
+    }
+
     let padding = opts
         .padding
         .unwrap_or_else(|| tui_banner::Padding::uniform(1));
     banner = banner.padding(padding);
 
-    if let Some(frame) = build_frame(&opts)? {
+    if let Some(frame) = build_frame(&opts, align)? {
         banner = banner.frame(frame);
     }
 
@@ -156,14 +247,22 @@ fn run() -> Result<(), String> {
         banner = banner.max_width(max_width);
     }
 
+    if opts.fit {
+        banner = banner.fit_terminal();
+    }
+
     if let Some(kerning) = opts.kerning {
-        banner = banner.kerning(kerning);
+        banner = banner.kerning_signed(kerning);
     }
 
     if let Some(line_gap) = opts.line_gap {
         banner = banner.line_gap(line_gap);
     }
 
+    if opts.wrap {
+        banner = banner.wrap(true);
+    }
+
     if opts.trim_vertical.unwrap_or(true) {
         banner = banner.trim_vertical(true);
     }
@@ -173,6 +272,25 @@ fn run() -> Result<(), String> {
         banner = banner.gradient(gradient);
     }
 
+    if let Some(background) = resolve_background(&opts)? {
+        banner = banner.background(background);
+    } else if let Some(color) = opts.bg_color {
+        banner = banner.background_color(color);
+    }
+    if let Some(color) = opts.canvas_color {
+        banner = banner.canvas_color(color);
+    }
+    if opts.grayscale {
+        banner = banner.grayscale(true);
+    }
+    if let Some(tint) = opts.tint {
+        banner = banner.tint(tint);
+    }
+
+    if let Some(intensity) = opts.glitch.filter(|_| opts.animate_glitch.is_none()) {
+        banner = banner.glitch(GlitchConfig::new(0, intensity));
+    }
+
     if should_apply_sweep(&opts) {
         let sweep = build_sweep(&opts)?;
         banner = banner.light_sweep(sweep);
@@ -180,27 +298,98 @@ fn run() -> Result<(), String> {
 
     banner = apply_dot_dither(banner, &opts)?;
 
+    let playback = build_playback(&opts);
+
     if let Some(speed) = opts.animate_sweep {
         let highlight = opts.sweep_highlight;
         banner
-            .animate_sweep(speed, highlight)
-            .map_err(|err| err.to_string())?;
+            .animate_sweep_with(speed, highlight, &playback)
+            .map_err(playback_error)?;
         return Ok(());
     }
 
     if let Some(speed) = opts.animate_wave {
         banner
-            .animate_wave(speed, opts.wave_dim, opts.wave_bright)
-            .map_err(|err| err.to_string())?;
+            .animate_wave_with(speed, opts.wave_dim, opts.wave_bright, &playback)
+            .map_err(playback_error)?;
+        return Ok(());
+    }
+
+    if let Some(speed) = opts.animate_gradient {
+        banner
+            .animate(Animation::Gradient { cycles: 1.0 }, speed, &playback)
+            .map_err(playback_error)?;
+        return Ok(());
+    }
+
+    if let Some(speed) = opts.animate_hue {
+        banner
+            .animate(Animation::Hue, speed, &playback)
+            .map_err(playback_error)?;
+        return Ok(());
+    }
+
+    if let Some(speed) = opts.animate_matrix {
+        banner
+            .animate_matrix_reveal(speed, &playback)
+            .map_err(playback_error)?;
+        return Ok(());
+    }
+
+    if let Some(speed) = opts.animate_glitch {
+        let intensity = opts.glitch.unwrap_or(0.6);
+        banner
+            .animate(Animation::Glitch { intensity }, speed, &playback)
+            .map_err(playback_error)?;
+        return Ok(());
+    }
+
+    if let Some(speed) = opts.animate_fade {
+        let direction = opts.fade_direction.unwrap_or(FadeDirection::In);
+        banner
+            .animate(Animation::Fade { direction }, speed, &playback)
+            .map_err(playback_error)?;
         return Ok(());
     }
 
     if let Some(speed) = opts.animate_roll {
-        banner.animate_roll(speed).map_err(|err| err.to_string())?;
+        banner
+            .animate_roll_with(speed, &playback)
+            .map_err(playback_error)?;
         return Ok(());
     }
 
-    println!("{}", banner.render());
+    let format = opts.format.unwrap_or(if opts.output.is_some() {
+        OutputFormat::Plain
+    } else {
+        OutputFormat::Ansi
+    });
+    let rendered = match format {
+        OutputFormat::Ansi => banner.render(),
+        OutputFormat::Plain => banner.render_plain(true),
+        OutputFormat::Html => banner.render_html(),
+        OutputFormat::Svg => banner.render_svg(),
+        OutputFormat::Kitty => banner
+            .render_kitty(&RasterOptions::default())
+            .map_err(|err| err.to_string())?,
+        OutputFormat::Png => {
+            // Validation guarantees an output path for binary formats.
+            let path = opts
+                .output
+                .as_ref()
+                .ok_or("`--format png` requires `--output`")?;
+            let png = banner
+                .render_png(&RasterOptions::default())
+                .map_err(|err| err.to_string())?;
+            return fs::write(path, png)
+                .map_err(|err| format!("failed to write output {:?}: {err}", path));
+        }
+    };
+    match &opts.output {
+        Some(path) => fs::write(path, format!("{rendered}\n"))
+            .map_err(|err| format!("failed to write output {:?}: {err}", path))?,
+        None => println!("{rendered}"),
+    }
     Ok(())
 }
 
@@ -209,7 +398,7 @@ fn parse_args() -> Result<CliOptions, String> {
     let args: Vec<String> = env::args().skip(1).collect();
     let mut index = 0;
 
-    if args.is_empty() {
+    if args.is_empty() && io::stdin().is_terminal() {
         print_help();
         std::process::exit(0);
     }
@@ -231,6 +420,21 @@ fn parse_args() -> Result<CliOptions, String> {
                     }
                     opts.text_flag = Some(value);
                 }
+                "--text-file" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.text_file = Some(PathBuf::from(value));
+                }
+                "--text-file-strip-comments" => {
+                    opts.text_file_strip_comments = true;
+                }
+                "--segment" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.segments.push(parse_segment(&value)?);
+                }
+                "--caption" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.caption = Some(value);
+                }
                 "--font" => {
                     let value = take_value(flag, inline, &args, &mut index)?;
                     opts.font = Some(PathBuf::from(value));
@@ -247,6 +451,28 @@ fn parse_args() -> Result<CliOptions, String> {
                     let value = take_value(flag, inline, &args, &mut index)?;
                     opts.gradient = Some(parse_gradient_dir(&value)?);
                 }
+                "--gradient-repeat" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.gradient_repeat = Some(parse_f32(&value, flag)?);
+                }
+                "--gradient-mirror" => {
+                    opts.gradient_mirror = true;
+                }
+                "--plasma" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.plasma = Some(parse_plasma(&value, flag)?);
+                }
+                "--gradient-reverse" => {
+                    opts.gradient_reverse = true;
+                }
+                "--gradient-easing" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.gradient_easing = Some(parse_easing(&value)?);
+                }
+                "--color-space" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.color_space = Some(parse_color_space(&value)?);
+                }
                 "--palette" => {
                     let value = take_value(flag, inline, &args, &mut index)?;
                     let entries = parse_list(&value);
@@ -255,6 +481,33 @@ fn parse_args() -> Result<CliOptions, String> {
                     }
                     opts.palette.get_or_insert_with(Vec::new).extend(entries);
                 }
+                "--palette-ramp" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.palette_ramp = Some(parse_palette_ramp(&value)?);
+                }
+                "--bg-palette" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    let entries = parse_list(&value);
+                    if entries.is_empty() {
+                        return Err("`--bg-palette` expects at least one color".to_string());
+                    }
+                    opts.bg_palette.get_or_insert_with(Vec::new).extend(entries);
+                }
+                "--bg-color" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.bg_color = Some(parse_color(&value)?);
+                }
+                "--grayscale" => {
+                    opts.grayscale = true;
+                }
+                "--tint" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.tint = Some(parse_color(&value)?);
+                }
+                "--canvas-color" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.canvas_color = Some(parse_color(&value)?);
+                }
                 "--frame" => {
                     let value = take_value(flag, inline, &args, &mut index)?;
                     opts.frame_style = Some(parse_frame_style(&value)?);
@@ -285,6 +538,30 @@ fn parse_args() -> Result<CliOptions, String> {
                     let value = take_value(flag, inline, &args, &mut index)?;
                     opts.frame_preset = Some(parse_preset(&value)?);
                 }
+                "--frame-padding" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.frame_padding = Some(parse_padding(&value, flag)?);
+                }
+                "--frame-sides" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.frame_sides = Some(parse_frame_sides(&value)?);
+                }
+                "--frame-fill" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.frame_fill = Some(parse_color(&value)?);
+                }
+                "--frame-thickness" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.frame_thickness = Some(parse_usize(&value, flag)?);
+                }
+                "--frame-divider" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.frame_dividers.push(parse_usize(&value, flag)?);
+                }
+                "--frame-title" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.frame_title = Some(value);
+                }
                 "--fill" => {
                     let value = take_value(flag, inline, &args, &mut index)?;
                     opts.fill = Some(parse_fill(&value)?);
@@ -330,6 +607,17 @@ fn parse_args() -> Result<CliOptions, String> {
                     }
                     opts.dither = Some(DitherSpec::Noise { seed, threshold });
                 }
+                "--dither-bayer" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    let levels = parse_u8(&value, flag)?;
+                    if levels > 16 {
+                        return Err("`--dither-bayer` expects a level between 0 and 16".to_string());
+                    }
+                    if opts.dither.is_some() {
+                        return Err("only one dither mode can be set".to_string());
+                    }
+                    opts.dither = Some(DitherSpec::Bayer { levels });
+                }
                 "--dither-targets" => {
                     let value = take_value(flag, inline, &args, &mut index)?;
                     opts.dither_targets = Some(value);
@@ -353,7 +641,7 @@ fn parse_args() -> Result<CliOptions, String> {
                 }
                 "--padding" => {
                     let value = take_value(flag, inline, &args, &mut index)?;
-                    opts.padding = Some(parse_padding(&value)?);
+                    opts.padding = Some(parse_padding(&value, flag)?);
                 }
                 "--width" => {
                     let value = take_value(flag, inline, &args, &mut index)?;
@@ -365,12 +653,22 @@ fn parse_args() -> Result<CliOptions, String> {
                 }
                 "--kerning" => {
                     let value = take_value(flag, inline, &args, &mut index)?;
-                    opts.kerning = Some(parse_usize(&value, flag)?);
+                    opts.kerning = Some(
+                        value
+                            .parse::<i32>()
+                            .map_err(|_| format!("{flag} must be an integer"))?,
+                    );
                 }
                 "--line-gap" => {
                     let value = take_value(flag, inline, &args, &mut index)?;
                     opts.line_gap = Some(parse_usize(&value, flag)?);
                 }
+                "--fit" => {
+                    opts.fit = true;
+                }
+                "--wrap" => {
+                    opts.wrap = true;
+                }
                 "--trim-vertical" => {
                     opts.trim_vertical = Some(true);
                 }
@@ -381,6 +679,23 @@ fn parse_args() -> Result<CliOptions, String> {
                     let value = take_value(flag, inline, &args, &mut index)?;
                     opts.color_mode = Some(parse_color_mode(&value)?);
                 }
+                "--list-styles" => {
+                    opts.list = Some(ListKind::Styles);
+                }
+                "--list-presets" => {
+                    opts.list = Some(ListKind::Presets);
+                }
+                "--json" => {
+                    opts.json = true;
+                }
+                "--output" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.output = Some(PathBuf::from(value));
+                }
+                "--format" | "--output-format" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.format = Some(parse_output_format(&value)?);
+                }
                 "--light-sweep" => {
                     opts.light_sweep = true;
                 }
@@ -412,10 +727,55 @@ fn parse_args() -> Result<CliOptions, String> {
                     let value = take_value(flag, inline, &args, &mut index)?;
                     opts.animate_wave = Some(parse_u64(&value, flag)?);
                 }
+                "--animate-gradient" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.animate_gradient = Some(parse_u64(&value, flag)?);
+                }
+                "--animate-hue" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.animate_hue = Some(parse_u64(&value, flag)?);
+                }
+                "--animate-fade" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.animate_fade = Some(parse_u64(&value, flag)?);
+                }
+                "--animate-glitch" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.animate_glitch = Some(parse_u64(&value, flag)?);
+                }
+                "--animate-matrix" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.animate_matrix = Some(parse_u64(&value, flag)?);
+                }
+                "--glitch" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.glitch = Some(parse_f32(&value, flag)?);
+                }
+                "--fade-direction" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.fade_direction = Some(parse_fade_direction(&value)?);
+                }
                 "--animate-roll" => {
                     let value = take_value(flag, inline, &args, &mut index)?;
                     opts.animate_roll = Some(parse_u64(&value, flag)?);
                 }
+                "--loop" => {
+                    opts.loop_forever = true;
+                }
+                "--alt-screen" => {
+                    opts.alt_screen = true;
+                }
+                "--in-place" => {
+                    opts.in_place = true;
+                }
+                "--frames" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.frames = Some(parse_usize(&value, flag)?);
+                }
+                "--duration" => {
+                    let value = take_value(flag, inline, &args, &mut index)?;
+                    opts.duration = Some(parse_f32(&value, flag)?);
+                }
                 "--wave-dim" => {
                     let value = take_value(flag, inline, &args, &mut index)?;
                     opts.wave_dim = Some(parse_f32(&value, flag)?);
@@ -443,15 +803,51 @@ fn parse_args() -> Result<CliOptions, String> {
 }
 
 fn resolve_text(opts: &CliOptions) -> Result<String, String> {
-    opts.text_flag
-        .clone()
-        .ok_or_else(|| "`--text` is required".to_string())
+    if let Some(text) = &opts.text_flag {
+        return Ok(text.clone());
+    }
+    if let Some(path) = &opts.text_file {
+        let data = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read text file {:?}: {err}", path))?;
+        if opts.text_file_strip_comments {
+            return Ok(strip_comments(&data));
+        }
+        return Ok(data);
+    }
+    if !io::stdin().is_terminal() {
+        let mut data = String::new();
+        io::stdin()
+            .read_to_string(&mut data)
+            .map_err(|err| format!("failed to read stdin: {err}"))?;
+        let text = data.trim_end_matches(['\n', '\r']);
+        if !text.is_empty() {
+            return Ok(text.to_string());
+        }
+    }
+    Err("`--text` or `--text-file` is required (or pipe text on stdin)".to_string())
+}
+
+fn strip_comments(data: &str) -> String {
+    data.lines()
+        .filter(|line| {
+            let trimmed = line.trim_start();
+            !trimmed.is_empty() && !trimmed.starts_with('#')
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 fn resolve_gradient(opts: &CliOptions) -> Result<Option<Gradient>, String> {
-    let mut gradient_dir = opts.gradient;
+    let mut gradient_dir = opts
+        .plasma
+        .map(|(scale, seed)| GradientDirection::Plasma { scale, seed })
+        .or(opts.gradient);
     if gradient_dir.is_none() {
-        if opts.style.is_some() && opts.palette.is_none() && opts.preset.is_none() {
+        if opts.style.is_some()
+            && opts.palette.is_none()
+            && opts.palette_ramp.is_none()
+            && opts.preset.is_none()
+        {
             return Ok(None);
         }
         gradient_dir = Some(GradientDirection::Diagonal);
@@ -460,26 +856,44 @@ fn resolve_gradient(opts: &CliOptions) -> Result<Option<Gradient>, String> {
     let direction = gradient_dir.unwrap_or(GradientDirection::Diagonal);
 
     let palette = if let Some(palette) = &opts.palette {
-        let list: Vec<&str> = palette.iter().map(String::as_str).collect();
-        let palette = Palette::from_hex(&list);
-        if palette.colors().is_empty() {
-            return Err("`--palette` did not contain any valid colors".to_string());
-        }
-        palette
-    } else if let Some(preset) = opts.preset {
-        Palette::preset(preset)
+        parse_palette(palette, "--palette")?
+    } else if let Some((anchors, steps)) = &opts.palette_ramp {
+        Palette::ramp_in(anchors, *steps, opts.color_space.unwrap_or_default())
+    } else if let Some(preset) = &opts.preset {
+        preset.clone()
     } else {
         Palette::from_hex(&DEFAULT_PALETTE)
     };
 
-    let gradient = match direction {
-        GradientDirection::Vertical => Gradient::vertical(palette),
-        GradientDirection::Horizontal => Gradient::horizontal(palette),
-        GradientDirection::Diagonal => Gradient::diagonal(palette),
-    };
+    let mut gradient = Gradient::new(palette.colors().to_vec(), direction)
+        .color_space(opts.color_space.unwrap_or_default());
+    if let Some(cycles) = opts.gradient_repeat {
+        gradient = gradient.repeat(cycles);
+    }
+    if opts.gradient_mirror {
+        gradient = gradient.spread(GradientSpread::Mirror);
+    }
+    if opts.gradient_reverse {
+        gradient = gradient.reversed();
+    }
+    if let Some(easing) = opts.gradient_easing {
+        gradient = gradient.easing(easing);
+    }
     Ok(Some(gradient))
 }
 
+fn resolve_background(opts: &CliOptions) -> Result<Option<Gradient>, String> {
+    let Some(palette) = &opts.bg_palette else {
+        return Ok(None);
+    };
+    let palette = parse_palette(palette, "--bg-palette")?;
+    let direction = opts.gradient.unwrap_or(GradientDirection::Diagonal);
+    let gradient = Gradient::new(palette.colors().to_vec(), direction);
+    Ok(Some(
+        gradient.color_space(opts.color_space.unwrap_or_default()),
+    ))
+}
+
 fn build_fill(
     fill: Option<FillKind>,
     fill_char: Option<char>,
@@ -519,6 +933,7 @@ fn build_dither(spec: DitherSpec, dots: &str) -> Result<Dither, String> {
     match spec {
         DitherSpec::Checker { period } => Ok(Dither::checker(period, dots)),
         DitherSpec::Noise { seed, threshold } => Ok(Dither::noise(seed, threshold, dots)),
+        DitherSpec::Bayer { levels } => Ok(Dither::bayer(levels, dots)),
     }
 }
 
@@ -526,7 +941,7 @@ fn apply_dot_dither(mut banner: Banner, opts: &CliOptions) -> Result<Banner, Str
     if opts.dither.is_none() {
         if opts.dither_targets.is_some() || opts.dither_dots.is_some() {
             return Err(
-                "`--dither-checker` or `--dither-noise` is required when setting dither options"
+                "`--dither-checker`, `--dither-noise` or `--dither-bayer` is required when setting dither options"
                     .to_string(),
             );
         }
@@ -546,6 +961,7 @@ fn apply_dot_dither(mut banner: Banner, opts: &CliOptions) -> Result<Banner, Str
     banner = match opts.dither.unwrap() {
         DitherSpec::Checker { period } => builder.checker(period),
         DitherSpec::Noise { seed, threshold } => builder.noise(seed, threshold),
+        DitherSpec::Bayer { levels } => builder.bayer(levels),
     };
 
     Ok(banner)
@@ -579,6 +995,12 @@ fn build_sweep(opts: &CliOptions) -> Result<LightSweep, String> {
 }
 
 fn validate_options(opts: &CliOptions) -> Result<(), String> {
+    if opts.json && opts.list.is_none() {
+        return Err("`--json` requires `--list-styles` or `--list-presets`".to_string());
+    }
+    if matches!(opts.format, Some(OutputFormat::Png)) && opts.output.is_none() {
+        return Err("`--format png` requires `--output`".to_string());
+    }
     if opts.sweep_highlight.is_some() && opts.animate_sweep.is_none() {
         return Err("`--sweep-highlight` requires `--animate-sweep`".to_string());
     }
@@ -586,13 +1008,59 @@ fn validate_options(opts: &CliOptions) -> Result<(), String> {
         opts.animate_sweep.is_some(),
         opts.animate_wave.is_some(),
         opts.animate_roll.is_some(),
+        opts.animate_gradient.is_some(),
+        opts.animate_hue.is_some(),
+        opts.animate_fade.is_some(),
+        opts.animate_glitch.is_some(),
+        opts.animate_matrix.is_some(),
     ];
     if animations.into_iter().filter(|enabled| *enabled).count() > 1 {
         return Err(
-            "`--animate-sweep`, `--animate-wave`, and `--animate-roll` cannot be used together"
+            "`--animate-sweep`, `--animate-wave`, `--animate-roll`, `--animate-gradient`, `--animate-hue`, `--animate-fade`, `--animate-glitch`, and `--animate-matrix` cannot be used together"
+                .to_string(),
+        );
+    }
+    if opts.fade_direction.is_some() && opts.animate_fade.is_none() {
+        return Err("`--fade-direction` requires `--animate-fade`".to_string());
+    }
+    if animations.into_iter().any(|enabled| enabled)
+        && (opts.output.is_some() || opts.format.is_some())
+    {
+        return Err("`--output` and `--format` cannot be used with animations".to_string());
+    }
+    let playback_flags = opts.loop_forever
+        || opts.frames.is_some()
+        || opts.duration.is_some()
+        || opts.alt_screen
+        || opts.in_place;
+    if playback_flags && !animations.into_iter().any(|enabled| enabled) {
+        return Err(
+            "`--loop`, `--frames`, `--duration`, `--alt-screen`, and `--in-place` require an animation"
                 .to_string(),
         );
     }
+    if opts.alt_screen && opts.in_place {
+        return Err("`--alt-screen` and `--in-place` cannot be used together".to_string());
+    }
+    if opts.frames == Some(0) {
+        return Err("`--frames` must be at least 1".to_string());
+    }
+    if opts
+        .frame_thickness
+        .is_some_and(|thickness| thickness > Frame::MAX_THICKNESS)
+    {
+        return Err(format!(
+            "`--frame-thickness` must be at most {}",
+            Frame::MAX_THICKNESS
+        ));
+    }
+    // `try_from_secs_f32` rejects NaN, negative and out-of-range values that would panic later.
+    if opts
+        .duration
+        .is_some_and(|secs| secs <= 0.0 || Duration::try_from_secs_f32(secs).is_err())
+    {
+        return Err("`--duration` must be a positive number of seconds".to_string());
+    }
     if (opts.wave_dim.is_some() || opts.wave_bright.is_some()) && opts.animate_wave.is_none() {
         return Err("`--wave-dim` and `--wave-bright` require `--animate-wave`".to_string());
     }
@@ -602,6 +1070,27 @@ fn validate_options(opts: &CliOptions) -> Result<(), String> {
     if opts.pixel_dither.is_none() && opts.pixel_dither_dots.is_some() {
         return Err("`--pixel-dither-dots` requires a pixel dither mode".to_string());
     }
+    if !opts.segments.is_empty() && (opts.text_flag.is_some() || opts.text_file.is_some()) {
+        return Err("`--segment` cannot be combined with `--text` or `--text-file`".to_string());
+    }
+    if opts.text_flag.is_some() && opts.text_file.is_some() {
+        return Err("`--text` and `--text-file` cannot be used together".to_string());
+    }
+    if opts.text_file_strip_comments && opts.text_file.is_none() {
+        return Err("`--text-file-strip-comments` requires `--text-file`".to_string());
+    }
+    if opts.wrap && opts.width.is_none() && opts.max_width.is_none() && !opts.fit {
+        return Err("`--wrap` requires `--width`, `--max-width`, or `--fit`".to_string());
+    }
+    if opts.plasma.is_some() && opts.gradient.is_some() {
+        return Err("`--plasma` and `--gradient` cannot be used together".to_string());
+    }
+    if opts.palette_ramp.is_some() && (opts.palette.is_some() || opts.preset.is_some()) {
+        return Err("`--palette-ramp` cannot be used with `--palette` or `--preset`".to_string());
+    }
+    if opts.bg_palette.is_some() && opts.bg_color.is_some() {
+        return Err("`--bg-palette` and `--bg-color` cannot be used together".to_string());
+    }
     if opts.frame_style.is_some() && opts.frame_chars.is_some() {
         return Err("`--frame` and `--frame-chars` cannot be used together".to_string());
     }
@@ -644,6 +1133,15 @@ fn parse_list(value: &str) -> Vec<String> {
         .collect()
 }
 
+fn parse_color_space(value: &str) -> Result<ColorSpace, String> {
+    match normalize(value).as_str() {
+        "rgb" => Ok(ColorSpace::Rgb),
+        "hsl" => Ok(ColorSpace::Hsl),
+        "oklab" => Ok(ColorSpace::Oklab),
+        _ => Err("`--color-space` must be rgb, hsl, or oklab".to_string()),
+    }
+}
+
 fn parse_fill(value: &str) -> Result<FillKind, String> {
     match normalize(value).as_str() {
         "keep" => Ok(FillKind::Keep),
@@ -654,24 +1152,39 @@ fn parse_fill(value: &str) -> Result<FillKind, String> {
     }
 }
 
+fn print_list(kind: ListKind, json: bool) {
+    let names: Vec<&str> = match kind {
+        ListKind::Styles => Style::ALL.iter().map(|style| style.name()).collect(),
+        ListKind::Presets => Preset::ALL.iter().map(|preset| preset.name()).collect(),
+    };
+    if json {
+        let quoted: Vec<String> = names.iter().map(|name| format!("\"{name}\"")).collect();
+        println!("[{}]", quoted.join(","));
+    } else {
+        for name in names {
+            println!("{name}");
+        }
+    }
+}
+
 fn parse_style(value: &str) -> Result<Style, String> {
-    match normalize(value).as_str() {
-        "neon-cyber" => Ok(Style::NeonCyber),
-        "arctic-tech" => Ok(Style::ArcticTech),
-        "sunset-neon" => Ok(Style::SunsetNeon),
-        "forest-sky" => Ok(Style::ForestSky),
-        "chrome" => Ok(Style::Chrome),
-        "crt-amber" => Ok(Style::CrtAmber),
-        "ocean-flow" => Ok(Style::OceanFlow),
-        "deep-space" => Ok(Style::DeepSpace),
-        "fire-warning" => Ok(Style::FireWarning),
-        "warm-luxury" => Ok(Style::WarmLuxury),
-        "earth-tone" => Ok(Style::EarthTone),
-        "royal-purple" => Ok(Style::RoyalPurple),
-        "matrix" => Ok(Style::Matrix),
-        "aurora-flux" => Ok(Style::AuroraFlux),
-        other => Err(format!("unknown style: {other}")),
+    let name = normalize(value);
+    Style::from_name(&name).ok_or_else(|| format!("unknown style: {name}"))
+}
+
+fn parse_frame_sides(value: &str) -> Result<[bool; 4], String> {
+    let mut sides = [false; 4];
+    for side in parse_list(value) {
+        let idx = match normalize(&side).as_str() {
+            "top" => 0,
+            "bottom" => 1,
+            "left" => 2,
+            "right" => 3,
+            other => return Err(format!("unknown frame side: {other}")),
+        };
+        sides[idx] = true;
     }
+    Ok(sides)
 }
 
 fn parse_frame_style(value: &str) -> Result<FrameStyle, String> {
@@ -685,24 +1198,8 @@ fn parse_frame_style(value: &str) -> Result<FrameStyle, String> {
     }
 }
 
-fn parse_preset(value: &str) -> Result<Preset, String> {
-    match normalize(value).as_str() {
-        "neon-cyber" => Ok(Preset::NeonCyber),
-        "arctic-tech" => Ok(Preset::ArcticTech),
-        "sunset-neon" => Ok(Preset::SunsetNeon),
-        "forest-sky" => Ok(Preset::ForestSky),
-        "chrome" => Ok(Preset::Chrome),
-        "crt-amber" => Ok(Preset::CrtAmber),
-        "ocean-flow" => Ok(Preset::OceanFlow),
-        "deep-space" => Ok(Preset::DeepSpace),
-        "fire-warning" => Ok(Preset::FireWarning),
-        "warm-luxury" => Ok(Preset::WarmLuxury),
-        "earth-tone" => Ok(Preset::EarthTone),
-        "royal-purple" => Ok(Preset::RoyalPurple),
-        "matrix" => Ok(Preset::Matrix),
-        "aurora-flux" => Ok(Preset::AuroraFlux),
-        other => Err(format!("unknown preset: {other}")),
-    }
+fn parse_preset(value: &str) -> Result<Palette, String> {
+    Palette::named(&normalize(value)).ok_or_else(|| format!("unknown preset: {}", value.trim()))
 }
 
 fn parse_gradient_dir(value: &str) -> Result<GradientDirection, String> {
@@ -710,7 +1207,13 @@ fn parse_gradient_dir(value: &str) -> Result<GradientDirection, String> {
         "vertical" => Ok(GradientDirection::Vertical),
         "horizontal" => Ok(GradientDirection::Horizontal),
         "diagonal" | "diag" => Ok(GradientDirection::Diagonal),
-        other => Err(format!("unknown gradient direction: {other}")),
+        other => other
+            .trim_end_matches("deg")
+            .parse::<f32>()
+            .ok()
+            .filter(|degrees| degrees.is_finite())
+            .map(GradientDirection::Angle)
+            .ok_or_else(|| format!("unknown gradient direction: {other}")),
     }
 }
 
@@ -728,11 +1231,34 @@ fn parse_color_mode(value: &str) -> Result<ColorMode, String> {
         "auto" => Ok(ColorMode::Auto),
         "truecolor" | "true-color" => Ok(ColorMode::TrueColor),
         "ansi256" | "ansi-256" => Ok(ColorMode::Ansi256),
+        "ansi16" | "ansi-16" => Ok(ColorMode::Ansi16),
         "no-color" | "nocolor" | "none" => Ok(ColorMode::NoColor),
         other => Err(format!("unknown color mode: {other}")),
     }
 }
 
+fn parse_easing(value: &str) -> Result<Easing, String> {
+    match normalize(value).as_str() {
+        "linear" => Ok(Easing::Linear),
+        "ease-in" => Ok(Easing::EaseIn),
+        "ease-out" => Ok(Easing::EaseOut),
+        "smoothstep" | "smooth-step" => Ok(Easing::SmoothStep),
+        other => Err(format!("unknown easing: {other}")),
+    }
+}
+
+fn parse_output_format(value: &str) -> Result<OutputFormat, String> {
+    match normalize(value).as_str() {
+        "ansi" => Ok(OutputFormat::Ansi),
+        "plain" | "text" => Ok(OutputFormat::Plain),
+        "html" => Ok(OutputFormat::Html),
+        "svg" => Ok(OutputFormat::Svg),
+        "png" => Ok(OutputFormat::Png),
+        "kitty" => Ok(OutputFormat::Kitty),
+        other => Err(format!("unknown output format: {other}")),
+    }
+}
+
 fn parse_sweep_direction(value: &str) -> Result<SweepDirection, String> {
     match normalize(value).as_str() {
         "horizontal" => Ok(SweepDirection::Horizontal),
@@ -743,6 +1269,15 @@ fn parse_sweep_direction(value: &str) -> Result<SweepDirection, String> {
     }
 }
 
+fn parse_fade_direction(value: &str) -> Result<FadeDirection, String> {
+    match normalize(value).as_str() {
+        "in" => Ok(FadeDirection::In),
+        "out" => Ok(FadeDirection::Out),
+        "in-out" | "inout" => Ok(FadeDirection::InOut),
+        other => Err(format!("unknown fade direction: {other}")),
+    }
+}
+
 fn parse_char(value: &str) -> Result<char, String> {
     let mut chars = value.chars();
     let ch = chars
@@ -764,8 +1299,8 @@ fn validate_dots(value: &str) -> Result<(), String> {
 
 fn parse_shadow(value: &str) -> Result<ShadowSpec, String> {
     let parts = parse_list(value);
-    if parts.len() != 3 {
-        return Err("`--shadow` expects dx,dy,alpha".to_string());
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err("`--shadow` expects dx,dy,alpha[,color]".to_string());
     }
     let dx = parts[0]
         .parse::<i32>()
@@ -776,9 +1311,18 @@ fn parse_shadow(value: &str) -> Result<ShadowSpec, String> {
     let alpha = parts[2]
         .parse::<f32>()
         .map_err(|_| "shadow alpha must be a float".to_string())?;
+    let color = parts
+        .get(3)
+        .map(|value| {
+            Color::from_hex(value)
+                .or_else(|| Color::from_name(value))
+                .ok_or_else(|| format!("shadow color {value:?} must be a hex color or CSS name"))
+        })
+        .transpose()?;
     Ok(ShadowSpec {
         offset: (dx, dy),
         alpha,
+        color,
     })
 }
 
@@ -794,7 +1338,7 @@ fn parse_edge_shade(value: &str) -> Result<EdgeShadeSpec, String> {
     Ok(EdgeShadeSpec { darken, ch })
 }
 
-fn parse_padding(value: &str) -> Result<tui_banner::Padding, String> {
+fn parse_padding(value: &str, flag: &str) -> Result<tui_banner::Padding, String> {
     let parts = parse_list(value);
     match parts.len() {
         1 => Ok(tui_banner::Padding::from(
@@ -803,12 +1347,12 @@ fn parse_padding(value: &str) -> Result<tui_banner::Padding, String> {
                 .map_err(|_| "padding must be a number".to_string())?,
         )),
         4 => Ok(tui_banner::Padding::from((
-            parse_usize(&parts[0], "--padding")?,
-            parse_usize(&parts[1], "--padding")?,
-            parse_usize(&parts[2], "--padding")?,
-            parse_usize(&parts[3], "--padding")?,
+            parse_usize(&parts[0], flag)?,
+            parse_usize(&parts[1], flag)?,
+            parse_usize(&parts[2], flag)?,
+            parse_usize(&parts[3], flag)?,
         ))),
-        _ => Err("`--padding` expects 1 or 4 comma-separated numbers".to_string()),
+        _ => Err(format!("`{flag}` expects 1 or 4 comma-separated numbers")),
     }
 }
 
@@ -829,6 +1373,21 @@ fn parse_seed_threshold(value: &str, flag: &str) -> Result<(u32, u8), String> {
     Ok((seed, threshold as u8))
 }
 
+fn parse_plasma(value: &str, flag: &str) -> Result<(f32, u32), String> {
+    let parts = parse_list(value);
+    if parts.len() != 2 {
+        return Err(format!("{flag} expects scale,seed"));
+    }
+    let scale = parse_f32(&parts[0], flag)?;
+    if scale <= 0.0 {
+        return Err(format!("{flag} scale must be positive"));
+    }
+    let seed = parts[1]
+        .parse::<u32>()
+        .map_err(|_| format!("{flag} seed must be a number"))?;
+    Ok((scale, seed))
+}
+
 fn parse_color(value: &str) -> Result<Color, String> {
     if value.contains(',') {
         let parts = parse_list(value);
@@ -841,14 +1400,46 @@ fn parse_color(value: &str) -> Result<Color, String> {
         return Ok(Color::Rgb(r, g, b));
     }
 
-    let hex = value.trim().trim_start_matches('#');
-    if hex.len() != 6 {
-        return Err("color expects #RRGGBB or r,g,b".to_string());
+    Color::from_hex(value)
+        .or_else(|| Color::from_name(value))
+        .ok_or_else(|| {
+            format!("invalid color {value:?}: expects #RRGGBB, a CSS color name, or r,g,b")
+        })
+}
+
+fn parse_palette(entries: &[String], flag: &str) -> Result<Palette, String> {
+    let list: Vec<&str> = entries.iter().map(String::as_str).collect();
+    Palette::try_from_hex(&list).map_err(|err| format!("`{flag}`: {err}"))
+}
+
+fn parse_palette_ramp(value: &str) -> Result<(Vec<Color>, usize), String> {
+    let parts: Vec<&str> = value.split(':').map(str::trim).collect();
+    let Some((steps, colors)) = parts
+        .split_last()
+        .filter(|(_, colors)| (2..=3).contains(&colors.len()))
+    else {
+        return Err("`--palette-ramp` expects FROM:TO:STEPS or FROM:VIA:TO:STEPS".to_string());
+    };
+    let steps = parse_usize(steps, "--palette-ramp")?;
+    if steps < 2 {
+        return Err("`--palette-ramp` needs at least 2 steps".to_string());
+    }
+    let colors = colors
+        .iter()
+        .map(|color| parse_color(color).map_err(|err| format!("`--palette-ramp` {err}")))
+        .collect::<Result<_, _>>()?;
+    Ok((colors, steps))
+}
+
+fn parse_segment(value: &str) -> Result<(String, Color), String> {
+    let (text, color) = value
+        .rsplit_once(':')
+        .ok_or("`--segment` expects TEXT:COLOR")?;
+    if text.is_empty() {
+        return Err("`--segment` text cannot be empty".to_string());
     }
-    let r = u8::from_str_radix(&hex[0..2], 16).map_err(|_| "invalid hex".to_string())?;
-    let g = u8::from_str_radix(&hex[2..4], 16).map_err(|_| "invalid hex".to_string())?;
-    let b = u8::from_str_radix(&hex[4..6], 16).map_err(|_| "invalid hex".to_string())?;
-    Ok(Color::Rgb(r, g, b))
+    let color = parse_color(color).map_err(|err| format!("`--segment` {err}"))?;
+    Ok((text.to_string(), color))
 }
 
 fn parse_frame_chars(value: &str) -> Result<FrameChars, String> {
@@ -876,8 +1467,30 @@ fn parse_frame_chars(value: &str) -> Result<FrameChars, String> {
     Ok(FrameChars::new(tl, tr, bl, br, h, v))
 }
 
-fn build_frame(opts: &CliOptions) -> Result<Option<Frame>, String> {
+fn build_playback(opts: &CliOptions) -> AnimationOptions {
+    let mut playback = AnimationOptions::new()
+        .alternate_screen(opts.alt_screen)
+        .in_place(opts.in_place);
+    if let Some(frames) = opts.frames {
+        playback = playback.frames(frames);
+    }
+    if opts.loop_forever {
+        playback = playback.loop_mode(LoopMode::Forever);
+    }
+    if let Some(secs) = opts.duration {
+        playback = playback.duration(Duration::from_secs_f32(secs));
+    }
+    playback
+}
+
+fn build_frame(opts: &CliOptions, align: Align) -> Result<Option<Frame>, String> {
     let has_frame = opts.frame_style.is_some()
+        || opts.frame_title.is_some()
+        || opts.frame_padding.is_some()
+        || opts.frame_sides.is_some()
+        || !opts.frame_dividers.is_empty()
+        || opts.frame_thickness.is_some()
+        || opts.frame_fill.is_some()
         || opts.frame_chars.is_some()
         || opts.frame_color.is_some()
         || opts.frame_gradient.is_some()
@@ -897,6 +1510,25 @@ fn build_frame(opts: &CliOptions) -> Result<Option<Frame>, String> {
 
     let mut frame = Frame::custom(chars);
 
+    if let Some(title) = &opts.frame_title {
+        frame = frame.title(title.clone(), align);
+    }
+    if let Some(padding) = opts.frame_padding {
+        frame = frame.inner_padding(padding);
+    }
+    if let Some([top, bottom, left, right]) = opts.frame_sides {
+        frame = frame.sides(top, bottom, left, right);
+    }
+    if let Some(color) = opts.frame_fill {
+        frame = frame.fill_background(color);
+    }
+    if let Some(thickness) = opts.frame_thickness {
+        frame = frame.thickness(thickness);
+    }
+    for row in &opts.frame_dividers {
+        frame = frame.divider_row(*row);
+    }
+
     if let Some(color) = opts.frame_color {
         frame = frame.color(color);
     }
@@ -907,23 +1539,14 @@ fn build_frame(opts: &CliOptions) -> Result<Option<Frame>, String> {
     if gradient_requested {
         let direction = opts.frame_gradient.unwrap_or(GradientDirection::Diagonal);
         let palette = if let Some(palette) = &opts.frame_palette {
-            let list: Vec<&str> = palette.iter().map(String::as_str).collect();
-            let palette = Palette::from_hex(&list);
-            if palette.colors().is_empty() {
-                return Err("`--frame-palette` did not contain any valid colors".to_string());
-            }
-            palette
-        } else if let Some(preset) = opts.frame_preset {
-            Palette::preset(preset)
+            parse_palette(palette, "--frame-palette")?
+        } else if let Some(preset) = &opts.frame_preset {
+            preset.clone()
         } else {
             Palette::from_hex(&DEFAULT_PALETTE)
         };
 
-        let gradient = match direction {
-            GradientDirection::Vertical => Gradient::vertical(palette),
-            GradientDirection::Horizontal => Gradient::horizontal(palette),
-            GradientDirection::Diagonal => Gradient::diagonal(palette),
-        };
+        let gradient = Gradient::new(palette.colors().to_vec(), direction);
         frame = frame.gradient(gradient);
     }
 
@@ -964,23 +1587,45 @@ fn normalize(value: &str) -> String {
 
 fn print_help() {
     println!(
-        r#"tui-banner --text <TEXT> [options]
+        r#"tui-banner (--text <TEXT> | --text-file <PATH> | --segment <TEXT:COLOR>...) [options]
 
 Options:
-  --text <TEXT>                 Banner text (required)
-  --font <PATH>                 Figlet .flf font file
+  --text <TEXT>                 Banner text (read from piped stdin when omitted)
+  --text-file <PATH>            Read banner text from a file (one banner line per line)
+  --text-file-strip-comments    Drop blank lines and `#` comment lines from --text-file
+  --segment <TEXT:COLOR>        Colored text segment (repeatable; replaces --text)
+  --caption <TEXT>              Plain-text tagline below the banner (follows --align)
+  --font <PATH>                 Figlet .flf/.tlf font file (gzip-compressed files are accepted)
   --style <STYLE>               neon-cyber | arctic-tech | sunset-neon | forest-sky | chrome
                                 crt-amber | ocean-flow | deep-space | fire-warning | warm-luxury
                                 earth-tone | royal-purple | matrix | aurora-flux
-  --gradient <DIR>              vertical | horizontal | diagonal (default: diagonal)
+  --gradient <DIR>              vertical | horizontal | diagonal | <DEGREES> (default: diagonal)
+  --plasma <SCALE,SEED>         Color with smooth value noise instead of a linear gradient
+  --gradient-repeat <N>         Cycle the gradient palette N times across the banner
+  --gradient-mirror             Bounce repeated gradient cycles back and forth
+  --gradient-reverse            Run the gradient in the opposite direction
+  --gradient-easing <EASING>    linear | ease-in | ease-out | smoothstep (default: linear)
+  --color-space <SPACE>         rgb | hsl | oklab gradient interpolation (default: rgb)
   --palette <HEXES>             Comma-separated hex colors (default: #00E5FF,#3A7BFF,#E6F6FF)
+  --palette-ramp <RAMP>         Interpolated palette FROM:[VIA:]TO:STEPS (follows --color-space)
   --preset <PRESET>             Palette preset (same names as styles)
+  --bg-palette <HEXES>          Background gradient colors (follows --gradient)
+  --bg-color <COLOR>            Solid background color (#RRGGBB, CSS name, or r,g,b)
+  --grayscale                   Render every color as gray of the same brightness
+  --tint <COLOR>                Recolor everything in the hue of COLOR, keeping lightness
+  --canvas-color <COLOR>        Background tint for blank cells only (#RRGGBB, CSS name, or r,g,b)
   --frame <STYLE>               single | double | rounded | heavy | ascii
   --frame-chars <CHARS>         6 chars (tltrblbrhv) or 6 comma-separated chars
-  --frame-color <COLOR>         Frame color (#RRGGBB or r,g,b)
-  --frame-gradient <DIR>        vertical | horizontal | diagonal (default: diagonal)
+  --frame-color <COLOR>         Frame color (#RRGGBB, CSS name, or r,g,b)
+  --frame-gradient <DIR>        vertical | horizontal | diagonal | <DEGREES> (default: diagonal)
   --frame-palette <HEXES>       Frame palette colors (default: #00E5FF,#3A7BFF,#E6F6FF)
   --frame-preset <PRESET>       Frame palette preset (same names as styles)
+  --frame-title <TEXT>          Label in the top border (follows --align)
+  --frame-padding <P>           Space inside the frame border (1 or 4 comma-separated values)
+  --frame-sides <SIDES>         Sides to draw, e.g. top,bottom (default: all four)
+  --frame-fill <COLOR>          Background color inside the frame
+  --frame-thickness <N>         Border depth in cells, 1-64 (default: 1)
+  --frame-divider <ROW>         Rule with tees above content row ROW (repeatable)
   --fill <FILL>                 keep | blocks | solid | pixel (default: keep)
   --fill-char <CHAR>            Character for solid/pixel fills
   --pixel-dither-checker <N>    Pixel dither checker period
@@ -988,19 +1633,27 @@ Options:
   --pixel-dither-dots <DOTS>    Pixel dither dots (1-2 chars)
   --dither-checker <N>          Dot dither checker period
   --dither-noise <S,T>          Dot dither noise (seed,threshold)
+  --dither-bayer <LEVELS>       Dot dither 4x4 Bayer ordered pattern (0-16 of every 16 cells)
   --dither-targets <STR>        Dither glyph targets (default: ░▒▓)
   --dither-dots <DOTS>          Dither dots (1-2 chars)
-  --shadow <DX,DY,A>            Drop shadow (offset + alpha)
+  --shadow <DX,DY,A[,COLOR]>    Drop shadow (offset + alpha, optional fixed color)
   --edge-shade <D,CH>           Edge shade (darken + char)
+  --glitch <F>                  Glitch strength (0..1), static or with --animate-glitch
   --align <ALIGN>               left | center | right (default: center)
   --padding <P>                 1 or 4 comma-separated values (default: 1)
   --width <N>                   Force output width
   --max-width <N>               Clamp output width
-  --kerning <N>                 Space between characters
+  --fit                         Clamp output width to the terminal (or $COLUMNS)
+  --kerning <N>                 Space between characters (negative overlaps glyphs)
   --line-gap <N>                Blank lines between text lines
+  --wrap                        Wrap text at word boundaries to fit the width limit
   --trim-vertical               Trim blank rows from top/bottom (default)
   --no-trim-vertical            Keep top/bottom blank rows
-  --color-mode <MODE>           auto | truecolor | ansi256 | no-color (default: truecolor)
+  --color-mode <MODE>           auto | truecolor | ansi256 | ansi16 | no-color (default: auto)
+  --output <PATH>               Write the banner to a file instead of stdout
+  --format <FORMAT>             ansi | plain | html | svg | png | kitty
+                                (default: ansi, or plain with --output; png needs --output)
+  --output-format <FORMAT>      Alias for --format
   --light-sweep                 Enable static sweep
   --sweep-direction <DIR>       horizontal | vertical | diagonal-down | diagonal-up
   --sweep-center <F>            Sweep center (0..1)
@@ -1010,9 +1663,23 @@ Options:
   --animate-sweep <MS>          Animate sweep (frame delay in ms)
   --animate-wave <MS>           Animate wave (frame delay in ms)
   --animate-roll <MS>           Animate roll (frame delay in ms)
+  --animate-gradient <MS>       Animate flowing gradient colors (frame delay in ms)
+  --animate-hue <MS>            Animate a rainbow hue cycle (frame delay in ms)
+  --animate-fade <MS>           Animate a color fade (frame delay in ms)
+  --fade-direction <DIR>        in | out | in-out (default: in)
+  --animate-glitch <MS>         Animate glitch bursts between clean frames (frame delay in ms)
+  --animate-matrix <MS>         Matrix rain that settles into the banner (frame delay in ms)
+  --loop                        Repeat the animation until Ctrl-C (or --duration)
+  --frames <N>                  Frames per animation pass (default: 180)
+  --duration <SECS>             Stop the animation after SECS seconds
+  --alt-screen                  Animate on the alternate screen, restoring the terminal after
+  --in-place                    Animate inline below the prompt without clearing the screen
   --wave-dim <F>                Wave dim strength (0..1, default: 0.35)
   --wave-bright <F>             Wave bright strength (0..1, default: 0.2)
   --sweep-highlight <COLOR>     Highlight color (#RRGGBB or r,g,b, default: white)
+  --list-styles                 Print every style name, one per line
+  --list-presets                Print every preset name, one per line
+  --json                        Print --list-styles/--list-presets as a JSON array
   --help, -h                    Show this help
 "#
     );
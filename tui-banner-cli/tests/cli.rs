@@ -0,0 +1,582 @@
+// Copyright (c) 2025 Lei Zhang
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+/// The CLI under test; stdout is a pipe here, so color is forced as if it were a terminal.
+fn cli() -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_tui-banner"));
+    command.env_remove("NO_COLOR").env("FORCE_COLOR", "3");
+    command
+}
+
+fn run(args: &[&str]) -> String {
+    let output = cli().args(args).output().expect("failed to run tui-banner");
+    assert!(
+        output.status.success(),
+        "tui-banner failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).expect("non-UTF-8 output")
+}
+
+fn temp_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("tui-banner-{}-{name}", std::process::id()));
+    fs::write(&path, contents).expect("failed to write temp file");
+    path
+}
+
+#[test]
+fn text_file_strips_comment_lines() {
+    let path = temp_file("comments.txt", "# greeting\nHI\n\n");
+    let path_str = path.to_str().unwrap();
+
+    let from_file = run(&[
+        "--text-file",
+        path_str,
+        "--text-file-strip-comments",
+        "--color-mode",
+        "no-color",
+    ]);
+    let from_flag = run(&["--text", "HI", "--color-mode", "no-color"]);
+    let _ = fs::remove_file(&path);
+
+    assert_eq!(from_file, from_flag);
+}
+
+#[test]
+fn caption_is_rendered_below_banner() {
+    let output = run(&[
+        "--text",
+        "HI",
+        "--caption",
+        "v1",
+        "--color-mode",
+        "no-color",
+    ]);
+    let lines: Vec<&str> = output.lines().collect();
+    let caption_row = lines.iter().rposition(|line| line.contains("v1")).unwrap();
+    assert!(lines[..caption_row].iter().any(|line| line.contains('█')));
+}
+
+#[test]
+fn segments_render_in_their_own_colors() {
+    let output = run(&[
+        "--segment",
+        "ERR:#ff0000",
+        "--segment",
+        "OK:#00ff00",
+        "--color-mode",
+        "truecolor",
+    ]);
+    let red = output.find("\x1b[38;2;255;0;0m").expect("red segment");
+    let green = output.find("\x1b[38;2;0;255;0m").expect("green segment");
+    assert!(red < green);
+}
+
+#[test]
+fn gradient_repeat_changes_colors() {
+    let base = ["--text", "HI", "--gradient", "horizontal"];
+    let plain = run(&base);
+    let repeated = run(&[&base[..], &["--gradient-repeat", "3"]].concat());
+    let mirrored = run(&[&base[..], &["--gradient-repeat", "3", "--gradient-mirror"]].concat());
+    assert_ne!(plain, repeated);
+    assert_ne!(repeated, mirrored);
+}
+
+#[test]
+fn bg_color_paints_cell_backgrounds() {
+    let output = run(&["--text", "HI", "--bg-color", "#000050"]);
+    assert!(output.contains("\x1b[48;2;0;0;80m"));
+
+    let plain = run(&[
+        "--text",
+        "HI",
+        "--bg-color",
+        "#000050",
+        "--color-mode",
+        "no-color",
+    ]);
+    assert!(!plain.contains('\x1b'));
+}
+
+#[test]
+fn gradient_reverse_matches_reversed_palette() {
+    let reversed = run(&[
+        "--text",
+        "HI",
+        "--palette",
+        "#ff0000,#0000ff",
+        "--gradient-reverse",
+    ]);
+    let swapped = run(&["--text", "HI", "--palette", "#0000ff,#ff0000"]);
+    assert_eq!(reversed, swapped);
+}
+
+#[test]
+fn plasma_is_stable_per_seed() {
+    let first = run(&["--text", "HI", "--plasma", "4,7"]);
+    assert_eq!(first, run(&["--text", "HI", "--plasma", "4,7"]));
+    assert_ne!(first, run(&["--text", "HI", "--plasma", "4,8"]));
+}
+
+#[test]
+fn invalid_palette_entry_is_reported() {
+    let output = Command::new(env!("CARGO_BIN_EXE_tui-banner"))
+        .args(["--text", "HI", "--palette", "#ff0000,#zzzzzz"])
+        .output()
+        .expect("failed to run tui-banner");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("\"#zzzzzz\" at position 2"), "{stderr}");
+
+    let named = run(&[
+        "--text",
+        "HI",
+        "--frame",
+        "single",
+        "--frame-color",
+        "tomato",
+    ]);
+    assert!(named.contains("\x1b[38;2;255;99;71m"));
+}
+
+#[test]
+fn shadow_accepts_optional_color() {
+    let plain = run(&["--text", "HI", "--shadow", "1,1,0.5"]);
+    let colored = run(&["--text", "HI", "--shadow", "1,1,0.5,#0000c8"]);
+    assert!(!plain.contains("\x1b[38;2;0;0;200m"));
+    assert!(colored.contains("\x1b[38;2;0;0;200m"));
+}
+
+#[test]
+fn palette_ramp_matches_explicit_palette() {
+    let ramp = run(&["--text", "HI", "--palette-ramp", "#000000:#ffffff:3"]);
+    let explicit = run(&["--text", "HI", "--palette", "#000000,#808080,#ffffff"]);
+    assert_eq!(ramp, explicit);
+}
+
+#[test]
+fn canvas_color_tints_blank_cells() {
+    let output = run(&["--text", "HI", "--canvas-color", "#140c00"]);
+    assert!(output.contains("\x1b[48;2;20;12;0m"));
+}
+
+#[test]
+fn fit_clamps_to_columns() {
+    let output = Command::new(env!("CARGO_BIN_EXE_tui-banner"))
+        .args(["--text", "HELLO WORLD", "--fit", "--color-mode", "no-color"])
+        .args(["--frame", "ascii"])
+        .env("COLUMNS", "40")
+        .output()
+        .expect("failed to run tui-banner");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let widest = stdout.lines().map(|line| line.chars().count()).max();
+    assert_eq!(widest, Some(40));
+}
+
+#[test]
+fn grayscale_and_tint_recolor_output() {
+    let gray = run(&["--text", "HI", "--palette", "#ff0000", "--grayscale"]);
+    assert!(gray.contains("\x1b[38;2;127;127;127m"));
+    assert!(!gray.contains("\x1b[38;2;255;0;0m"));
+
+    let tinted = run(&["--text", "HI", "--palette", "#0000ff", "--tint", "#ffb000"]);
+    assert!(tinted.contains("\x1b[38;2;255;176;0m"));
+}
+
+fn run_with_stdin(args: &[&str], input: &str) -> String {
+    let mut child = cli()
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run tui-banner");
+    let written = child.stdin.take().unwrap().write_all(input.as_bytes());
+    // With `--text` the CLI may exit without reading stdin at all.
+    if let Err(err) = written {
+        assert_eq!(err.kind(), std::io::ErrorKind::BrokenPipe, "{err}");
+    }
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).expect("non-UTF-8 output")
+}
+
+#[test]
+fn piped_output_has_no_color_unless_forced() {
+    let piped = |vars: &[(&str, &str)]| {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_tui-banner"));
+        for name in ["NO_COLOR", "FORCE_COLOR", "CLICOLOR", "CLICOLOR_FORCE"] {
+            command.env_remove(name);
+        }
+        let output = command
+            .args(["--text", "A"])
+            .envs(vars.iter().copied())
+            .output()
+            .expect("failed to run tui-banner");
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).expect("non-UTF-8 output")
+    };
+
+    assert!(!piped(&[]).contains('\x1b'));
+    assert!(!piped(&[("NO_COLOR", "1")]).contains('\x1b'));
+    assert!(piped(&[("FORCE_COLOR", "1")]).contains("\x1b[3"));
+    assert!(piped(&[("CLICOLOR_FORCE", "1")]).contains('\x1b'));
+    assert!(!piped(&[("CLICOLOR_FORCE", "1"), ("NO_COLOR", "1")]).contains('\x1b'));
+}
+
+#[test]
+fn text_is_read_from_piped_stdin() {
+    let piped = run_with_stdin(&["--color-mode", "no-color"], "HI\nYO\n");
+    let flag = run(&["--text", "HI\nYO", "--color-mode", "no-color"]);
+    assert_eq!(piped, flag);
+    assert_eq!(run_with_stdin(&[], "HI\n"), run(&["--text", "HI"]));
+
+    let text_wins = run_with_stdin(&["--text", "OK", "--color-mode", "no-color"], "HI\n");
+    assert_eq!(
+        text_wins,
+        run(&["--text", "OK", "--color-mode", "no-color"])
+    );
+}
+
+#[test]
+fn output_writes_plain_text_by_default() {
+    let path = temp_file("output.txt", "");
+    let path_str = path.to_str().unwrap();
+
+    let stdout = run(&["--text", "HI", "--output", path_str]);
+    let written = fs::read_to_string(&path).unwrap();
+    run(&["--text", "HI", "--output", path_str, "--format", "svg"]);
+    let svg = fs::read_to_string(&path).unwrap();
+    let _ = fs::remove_file(&path);
+
+    assert!(stdout.is_empty());
+    assert!(!written.contains('\x1b'));
+    assert!(written.contains('█'));
+    assert!(svg.starts_with("<svg"));
+
+    let html = run(&["--text", "HI", "--format", "html"]);
+    assert!(html.starts_with("<pre>"));
+}
+
+#[test]
+fn list_flags_print_names() {
+    let styles = run(&["--list-styles"]);
+    assert_eq!(styles.lines().next(), Some("neon-cyber"));
+    for style in styles.lines() {
+        run(&["--text", "A", "--style", style]);
+    }
+
+    for preset in run(&["--list-presets"]).lines() {
+        run(&["--text", "A", "--preset", preset]);
+    }
+
+    let presets = run(&["--list-presets", "--json"]);
+    assert!(presets.starts_with("[\"neon-cyber\","));
+    assert!(presets.trim_end().ends_with("\"aurora-flux\"]"));
+}
+
+#[test]
+fn html_matches_golden_file() {
+    let html = run(&[
+        "--text",
+        "HI",
+        "--palette",
+        "#ff0000,#0000ff",
+        "--gradient",
+        "vertical",
+        "--padding",
+        "0",
+        "--output-format",
+        "html",
+    ]);
+    assert_eq!(html, include_str!("golden/hi.html"));
+}
+
+#[test]
+fn gradient_accepts_angles() {
+    let base = ["--text", "HI", "--palette", "#ff0000,#0000ff"];
+    let horizontal = run(&[&base[..], &["--gradient", "horizontal"]].concat());
+    assert_eq!(horizontal, run(&[&base[..], &["--gradient", "0"]].concat()));
+    let vertical = run(&[&base[..], &["--gradient", "vertical"]].concat());
+    assert_eq!(
+        vertical,
+        run(&[&base[..], &["--gradient", "90deg"]].concat())
+    );
+    assert_ne!(
+        horizontal,
+        run(&[&base[..], &["--gradient", "30"]].concat())
+    );
+}
+
+#[test]
+fn negative_kerning_overlaps_glyphs() {
+    let width = |kerning: &str| {
+        let args = [
+            "--text",
+            "WAVE",
+            "--kerning",
+            kerning,
+            "--color-mode",
+            "no-color",
+        ];
+        let output = run(&args);
+        output.lines().map(|line| line.chars().count()).max()
+    };
+    assert!(width("-2") < width("0"));
+}
+
+#[test]
+fn frame_title_sits_in_top_border() {
+    let output = run(&[
+        "--text",
+        "HI",
+        "--frame-title",
+        "BUILD",
+        "--color-mode",
+        "no-color",
+    ]);
+    let top = output.lines().next().unwrap();
+    assert!(top.starts_with('┌') && top.contains("─ BUILD ─"), "{top}");
+}
+
+#[test]
+fn png_output_is_written_to_file() {
+    let path = temp_file("banner.png", "");
+    let path_str = path.to_str().unwrap();
+    run(&[
+        "--text",
+        "HI",
+        "--output-format",
+        "png",
+        "--output",
+        path_str,
+    ]);
+    let png = fs::read(&path).unwrap();
+    let _ = fs::remove_file(&path);
+
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    let width = u32::from_be_bytes(png[16..20].try_into().unwrap());
+    let height = u32::from_be_bytes(png[20..24].try_into().unwrap());
+    let plain = run(&["--text", "HI", "--color-mode", "no-color"]);
+    let columns = plain
+        .lines()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap();
+    assert_eq!(
+        (width, height),
+        (columns as u32 * 8, plain.lines().count() as u32 * 16)
+    );
+
+    let status = Command::new(env!("CARGO_BIN_EXE_tui-banner"))
+        .args(["--text", "HI", "--format", "png"])
+        .output()
+        .unwrap()
+        .status;
+    assert!(!status.success());
+}
+
+#[test]
+fn frame_sides_draw_rules_only() {
+    let output = run(&[
+        "--text",
+        "HI",
+        "--frame-sides",
+        "top,bottom",
+        "--padding",
+        "0",
+        "--color-mode",
+        "no-color",
+    ]);
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines[0].chars().all(|ch| ch == '─'));
+    assert!(lines[lines.len() - 1].chars().all(|ch| ch == '─'));
+    assert!(!output.contains('│'));
+}
+
+#[test]
+fn duration_stops_a_looping_animation() {
+    let start = std::time::Instant::now();
+    let output = run(&[
+        "--text",
+        "HI",
+        "--color-mode",
+        "no-color",
+        "--animate-roll",
+        "10",
+        "--frames",
+        "4",
+        "--loop",
+        "--duration",
+        "0.3",
+    ]);
+
+    assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    // Four frames per pass, looped for ~30 frames of 10 ms each.
+    assert!(output.matches("\x1b[H").count() > 4);
+}
+
+#[test]
+fn in_place_redraws_without_clearing_the_screen() {
+    let output = run(&[
+        "--text",
+        "HI",
+        "--color-mode",
+        "no-color",
+        "--animate-roll",
+        "10",
+        "--frames",
+        "4",
+        "--in-place",
+    ]);
+
+    assert_eq!(output.matches("\x1b[J").count(), 3);
+    assert!(!output.contains("\x1b[2J"));
+    assert!(!output.contains("\x1b[H"));
+}
+
+#[test]
+fn oversized_frame_thickness_is_rejected() {
+    let output = Command::new(env!("CARGO_BIN_EXE_tui-banner"))
+        .args(["--text", "HI", "--frame-thickness", "100000"])
+        .output()
+        .expect("failed to run tui-banner");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("`--frame-thickness` must be at most"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn out_of_range_duration_is_rejected() {
+    let output = Command::new(env!("CARGO_BIN_EXE_tui-banner"))
+        .args(["--text", "HI", "--animate-roll", "10", "--duration", "1e30"])
+        .output()
+        .expect("failed to run tui-banner");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("`--duration` must be"), "{stderr}");
+}
+
+#[cfg(unix)]
+#[test]
+fn ctrl_c_restores_the_cursor_and_exits_130() {
+    let child = Command::new(env!("CARGO_BIN_EXE_tui-banner"))
+        .args(["--text", "HI", "--animate-roll", "50", "--loop"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run tui-banner");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    let killed = Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .expect("failed to run kill");
+    assert!(killed.success());
+
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.code(), Some(130));
+    assert!(output.stdout.ends_with(b"\x1b[?25h\x1b[0m\n"));
+}
+
+#[test]
+fn frame_divider_splits_the_banner() {
+    let output = run(&[
+        "--text",
+        "HI",
+        "--color-mode",
+        "no-color",
+        "--frame",
+        "double",
+        "--frame-divider",
+        "1",
+    ]);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert!(lines[0].starts_with('╔'));
+    assert!(lines[2].starts_with('╠') && lines[2].ends_with('╣'));
+}
+
+#[test]
+fn fade_out_runs_to_completion() {
+    let output = run(&[
+        "--text",
+        "HI",
+        "--color-mode",
+        "truecolor",
+        "--animate-fade",
+        "0",
+        "--fade-direction",
+        "out",
+        "--frames",
+        "5",
+    ]);
+
+    assert_eq!(output.matches("\x1b[H").count(), 5);
+    assert!(output.contains("\x1b[38;2;0;0;0m"));
+}
+
+#[test]
+fn glitch_is_deterministic() {
+    let args = [
+        "--text",
+        "HI",
+        "--color-mode",
+        "truecolor",
+        "--glitch",
+        "0.9",
+    ];
+    let glitched = run(&args);
+
+    assert_eq!(glitched, run(&args));
+    assert_ne!(glitched, run(&args[..4]));
+}
+
+#[test]
+fn matrix_reveal_ends_on_the_banner_and_excludes_other_animations() {
+    let output = run(&[
+        "--text",
+        "HI",
+        "--color-mode",
+        "no-color",
+        "--animate-matrix",
+        "0",
+        "--frames",
+        "10",
+    ]);
+    let banner = run(&["--text", "HI", "--color-mode", "no-color"]);
+    let last = output.rsplit("\x1b[H").next().unwrap();
+    assert_eq!(
+        last.trim_end_matches("\x1b[?25h\x1b[0m\n").trim_end(),
+        banner.trim_end()
+    );
+
+    let clash = Command::new(env!("CARGO_BIN_EXE_tui-banner"))
+        .args([
+            "--text",
+            "HI",
+            "--animate-matrix",
+            "5",
+            "--animate-hue",
+            "5",
+        ])
+        .output()
+        .expect("failed to run tui-banner");
+    assert!(!clash.status.success());
+    assert!(String::from_utf8_lossy(&clash.stderr).contains("cannot be used together"));
+}